@@ -0,0 +1,77 @@
+//! Compares the factoring strategies on semiprimes of growing size.
+//!
+//! Run with `cargo bench --bench factorization`. Each row is the mean time per number over the same batch of
+//! products of two random primes of half the given size; `FactorStrategy::Auto` should match the best of the others.
+//! Strategies are left out (`-`) at sizes they take far too long for, or where they fall back to `Auto` anyway.
+#![allow(clippy::needless_return)]
+
+use std::time::{Duration, Instant};
+
+use prime_checker::{FactorStrategy, SeededRng};
+
+const SEED: u64 = 48;
+const SAMPLES: u32 = 50;
+const BITS: [u32; 12] = [16, 24, 32, 40, 48, 56, 62, 64, 80, 96, 112, 128];
+const STRATEGIES: [FactorStrategy; 6] = [
+    FactorStrategy::Auto,
+    FactorStrategy::PollardRho,
+    FactorStrategy::Squfof,
+    FactorStrategy::HartOneLine,
+    FactorStrategy::Ecm,
+    FactorStrategy::Siqs,
+];
+
+fn applies(strategy: FactorStrategy, bits: u32) -> bool {
+    return match strategy {
+        FactorStrategy::Auto => true,
+        FactorStrategy::PollardRho | FactorStrategy::Squfof | FactorStrategy::HartOneLine => {
+            bits <= 64
+        }
+        FactorStrategy::Ecm => bits <= 96,
+        FactorStrategy::Siqs => bits >= 40,
+    };
+}
+
+fn semiprimes(bits: u32, rng: &mut SeededRng) -> Vec<u128> {
+    let mut numbers: Vec<u128> = Vec::new();
+    while numbers.len() < SAMPLES as usize {
+        let p: u64 = prime_checker::random_prime(bits / 2, rng);
+        let q: u64 = prime_checker::random_prime(bits - bits / 2, rng);
+        if p != q {
+            numbers.push(p as u128 * q as u128);
+        }
+    }
+    return numbers;
+}
+
+fn time_per_number(numbers: &[u128], strategy: FactorStrategy) -> Duration {
+    let start: Instant = Instant::now();
+    for num in numbers {
+        let factors: Vec<(u128, u32)> = prime_checker::factorize_with(*num, strategy);
+        assert_eq!(factors.len(), 2, "{num} was not split into two primes");
+    }
+    return start.elapsed() / numbers.len() as u32;
+}
+
+fn main() {
+    let mut rng: SeededRng = SeededRng::new(SEED);
+    print!("{:>6}", "bits");
+    for strategy in STRATEGIES {
+        print!("{:>14}", format!("{strategy:?}"));
+    }
+    println!();
+
+    for bits in BITS {
+        let numbers: Vec<u128> = semiprimes(bits, &mut rng);
+        print!("{bits:>6}");
+        for strategy in STRATEGIES {
+            if !applies(strategy, bits) {
+                print!("{:>14}", "-");
+                continue;
+            }
+            let elapsed: Duration = time_per_number(&numbers, strategy);
+            print!("{:>14}", format!("{:.1?}", elapsed));
+        }
+        println!();
+    }
+}
@@ -1,8 +1,66 @@
-#![allow(warnings)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+// The crate's style: explicit `return`s, `x = x + 1`, and `assert_eq!` against booleans in the tests. The original
+// modules (`constants`, `primes` and `utils`) allow every warning themselves.
+#![allow(
+    clippy::needless_return,
+    clippy::assign_op_pattern,
+    clippy::bool_assert_comparison
+)]
 //! Rust library crate to hold simple functions to check the prime-ness of a given unsigned, 64-bit integer.
+//!
+//! __Features:__
+//!
+//! 1. `std` _(default)_ - Everything, including the floating-point functions such as `is_shcn` and `dickman_rho`. Implies `alloc`.
+//! 2. `alloc` - `#![no_std]` support for everything that only needs an allocator, such as `is_prime`, `get_primes` and
+//!    `get_hcn`.
+//! 3. Neither - The allocation-free subset, such as `miller_rabin`, for targets without an allocator.
+//! 4. `wasm` - JavaScript bindings for the `wasm32-unknown-unknown` target. Implies `std`.
+//! 5. `bignum` - `is_prime_big`, `next_prime_big` and `factorize_big` for `BigUint`, a built-in arbitrary-precision
+//!    integer. Implies `alloc`.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 mod libs;
 
+pub use libs::modular;
+
+#[cfg(feature = "bignum")]
+pub use libs::bigint::{BigUint, ParseBigUintError};
+#[cfg(feature = "bignum")]
+pub use libs::bignum::Primality;
+
+#[cfg(feature = "alloc")]
+pub use libs::certificate::Certificate;
+#[cfg(feature = "alloc")]
+pub use libs::constellations::PrimeTuples;
+#[cfg(feature = "alloc")]
+pub use libs::cunningham::{ChainKind, SafePrimes, SophieGermainPrimes};
+#[cfg(feature = "alloc")]
+pub use libs::ecm::EcmParams;
+#[cfg(feature = "alloc")]
+pub use libs::factorization::FactorStrategy;
+#[cfg(feature = "alloc")]
+pub use libs::gaps::{GapStats, PrimeGaps};
+#[cfg(feature = "alloc")]
+pub use libs::multiplicative::DiscreteLogError;
+#[cfg(feature = "alloc")]
+pub use libs::perfect::AbundantNumbers;
+pub use libs::prime_int::PrimeInt;
+pub use libs::quadratic::QuadraticResidues;
+pub use libs::random::{RandomSource, SeededRng};
+#[cfg(feature = "alloc")]
+pub use libs::smooth::SmoothNumbers;
+#[cfg(feature = "alloc")]
+pub use libs::spf::SpfTable;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(feature = "std"), allow(unused_variables))]
 pub fn description(show: bool) -> String {
     //! Prints a description of the crate to the console and returns the same.
     //!
@@ -15,37 +73,133 @@ pub fn description(show: bool) -> String {
     //! 1. `String` - A brief description of the library as defined in `libs::constants::DESCRIPTION`
     //!
     let description_str = String::from(libs::constants::DESCRIPTION);
-    if show == true {
+    #[cfg(feature = "std")]
+    if show {
         println!("{}", description_str);
     }
     return description_str;
 }
 
-pub fn is_prime(num: u64) -> (bool, Vec<u64>) {
+#[cfg(feature = "alloc")]
+pub fn is_prime<T: PrimeInt>(num: T) -> (bool, Vec<T>) {
     //! Checks to see if a given number is a prime number.
     //!
+    //! Works on any unsigned integer type; see `PrimeInt`. Since 0.3.0 the argument is generic, so an untyped literal
+    //! needs a suffix (`is_prime(7_u64)`), and `0` and `1` are no longer reported as prime: `0` gives `(false, [])`
+    //! and `1` gives `(false, [1])`, where 0.2 gave `(true, [0, 1])` and `(true, [1])`.
+    //!
     //! __Arguments:__
     //!
-    //! 1. `num: u64` - The number to check.
+    //! 1. `num: T` - The number to check.
     //!
     //! __Returns:__
     //!
     //! 1. `bool` - Is true if the number is prime, and false if it is not.
-    //! 2. `Vec<u64>` - The list of factors of the number.
+    //! 2. `Vec<T>` - The list of factors of the number.
     //!
     //! __Example:__
     //!
     //! ```rust
-    //! let (check,factors) = prime_checker::is_prime(7);
+    //! let (check,factors) = prime_checker::is_prime(7_u64);
     //!
     //! assert_eq!(factors, vec![1, 7]);
     //! assert_eq!(check, true);
     //! ```
     //!
-    let (check, factors) = libs::primes::check_if_prime(num);
+    let check: bool = num.is_prime();
+    let factors: Vec<T> = num.divisors();
     return (check, factors);
 }
 
+#[cfg(feature = "alloc")]
+pub fn factorize<T: PrimeInt>(num: T) -> Vec<(T, u32)> {
+    //! Finds the prime factorization of a given number.
+    //!
+    //! Works on any unsigned integer type; see `PrimeInt`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: T` - The number to factorize.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<(T, u32)>` - The `(prime, exponent)` pairs of the factorization, in increasing order; empty for `0` and `1`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let factors = prime_checker::factorize(360_u32);
+    //!
+    //! assert_eq!(factors, vec![(2, 3), (3, 2), (5, 1)]);
+    //! ```
+    //!
+    let factors: Vec<(T, u32)> = num.factorize();
+    return factors;
+}
+
+#[cfg(feature = "alloc")]
+pub fn factorize_with<T: PrimeInt>(num: T, strategy: FactorStrategy) -> Vec<(T, u32)> {
+    //! Finds the prime factorization of a given number, splitting its composite parts with a chosen method.
+    //!
+    //! `factorize` uses `FactorStrategy::Auto`, which picks the method by size; the others are mostly useful for
+    //! comparing the methods. A part the chosen method cannot split falls back to `Auto`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: T` - The number to factorize.
+    //! 2. `strategy: FactorStrategy` - The method: `Auto`, `PollardRho`, `Squfof`, `HartOneLine`, `Ecm` or `Siqs`.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<(T, u32)>` - The `(prime, exponent)` pairs of the factorization, in increasing order; empty for `0` and `1`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::FactorStrategy;
+    //!
+    //! let num: u64 = 4_294_967_291 * 4_294_967_279;
+    //!
+    //! assert_eq!(
+    //!     prime_checker::factorize_with(num, FactorStrategy::Squfof),
+    //!     vec![(4_294_967_279, 1), (4_294_967_291, 1)]
+    //! );
+    //! ```
+    //!
+    let factors: Vec<(T, u32)> = libs::factorization::factorize_with(num.to_u128(), strategy)
+        .into_iter()
+        .map(|(prime, exponent)| (T::from_u128(prime), exponent))
+        .collect();
+    return factors;
+}
+
+#[cfg(feature = "alloc")]
+pub fn divisors<T: PrimeInt>(num: T) -> Vec<T> {
+    //! Lists all the divisors of a given number.
+    //!
+    //! Works on any unsigned integer type; see `PrimeInt`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: T` - The number to find the divisors of.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<T>` - The divisors of the number, in increasing order; empty for `0`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let factors = prime_checker::divisors(12_u8);
+    //!
+    //! assert_eq!(factors, vec![1, 2, 3, 4, 6, 12]);
+    //! ```
+    //!
+    let factors: Vec<T> = num.divisors();
+    return factors;
+}
+
+#[cfg(feature = "alloc")]
 pub fn is_hcn(num: u64) -> (bool, Vec<u64>) {
     //! Checks to see if a given number is a highly-composite (anti-prime) number.
     //!
@@ -71,11 +225,10 @@ pub fn is_hcn(num: u64) -> (bool, Vec<u64>) {
     return (check, factors);
 }
 
+#[cfg(feature = "alloc")]
 pub fn get_hcn(num: u64) -> Vec<u64> {
     //! Find all highly composite numbers until a given value `num`.
     //!
-    //! __WARNING:__ Computationally Expensive [^1]
-    //!
     //! __Arguments:__
     //!
     //! 1. `num: u64` - The number to check till.
@@ -92,11 +245,11 @@ pub fn get_hcn(num: u64) -> Vec<u64> {
     //! assert_eq!(anti_primes, vec![1, 2, 4, 6, 12, 24, 36, 48, 60]);
     //! ```
     //!
-    //! [^1]: _Takes ~64 seconds to check ~6000 values beyond the last known anti-prime as defined in `libs::constants::KNOWN_ANTIPRIMES`_
     let anti_primes: Vec<u64> = libs::primes::find_anti_primes_till(num);
     return anti_primes;
 }
 
+#[cfg(feature = "alloc")]
 pub fn get_primes(num: u64) -> Vec<u64> {
     //! Finds all the prime numbers till a given number.
     //!
@@ -120,5 +273,2366 @@ pub fn get_primes(num: u64) -> Vec<u64> {
     return prime_numbers;
 }
 
+pub fn miller_rabin(num: u64) -> bool {
+    //! Checks to see if a given number is a prime number, using a deterministic Miller-Rabin test.
+    //!
+    //! Unlike `is_prime`, this does not list the factors of the number and never allocates, so it is available
+    //! without the `alloc` feature.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is prime, and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::miller_rabin(18_446_744_073_709_551_557), true);
+    //! assert_eq!(prime_checker::miller_rabin(3_215_031_751), false);
+    //! ```
+    //!
+    let check: bool = libs::primality::check_if_prime_mr(num);
+    return check;
+}
+
+pub const fn is_prime_const(num: u64) -> bool {
+    //! Checks to see if a given number is a prime number at compile time.
+    //!
+    //! Uses the same deterministic Miller-Rabin test as `miller_rabin`, but as a `const fn`, so it can be used in
+    //! constants and static assertions.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is prime, and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! const BUCKETS: u64 = 1_009;
+    //! const _: () = assert!(prime_checker::is_prime_const(BUCKETS));
+    //! ```
+    //!
+    let check: bool = libs::tables::is_prime_const(num);
+    return check;
+}
+
+pub const fn prime_table<const N: usize>() -> [u64; N] {
+    //! Generates a table of the first `N` prime numbers at compile time.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `[u64; N]` - The first `N` prime numbers, in increasing order.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! const PRIMES: [u64; 5] = prime_checker::prime_table::<5>();
+    //!
+    //! assert_eq!(PRIMES, [2, 3, 5, 7, 11]);
+    //! ```
+    //!
+    let table: [u64; N] = libs::tables::prime_table::<N>();
+    return table;
+}
+
+pub const fn hcn_table<const N: usize>() -> [u64; N] {
+    //! Generates a table of the first `N` highly-composite (anti-prime) numbers at compile time.
+    //!
+    //! __WARNING:__ Scans every number up to the last one, so keep `N` to a few dozen.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `[u64; N]` - The first `N` anti-prime numbers, in increasing order.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! const ANTI_PRIMES: [u64; 9] = prime_checker::hcn_table::<9>();
+    //!
+    //! assert_eq!(ANTI_PRIMES, [1, 2, 4, 6, 12, 24, 36, 48, 60]);
+    //! ```
+    //!
+    let table: [u64; N] = libs::tables::hcn_table::<N>();
+    return table;
+}
+
+#[cfg(feature = "alloc")]
+pub fn divisor_count(num: u64) -> u64 {
+    //! Counts the divisors of a given number, `tau(n)`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to count the divisors of.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - The number of divisors; `0` for `0`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::divisor_count(5_040), 60);
+    //! ```
+    //!
+    let n_divisors: u64 = libs::arithmetic::divisor_count(num);
+    return n_divisors;
+}
+
+#[cfg(feature = "alloc")]
+pub fn divisor_sum(num: u64) -> u128 {
+    //! Sums the divisors of a given number, `sigma(n)`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to sum the divisors of.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u128` - The sum of the divisors; `0` for `0`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::divisor_sum(12), 28);
+    //! ```
+    //!
+    let sum: u128 = libs::arithmetic::divisor_sum(num);
+    return sum;
+}
+
+#[cfg(feature = "alloc")]
+pub fn sigma_k(num: u64, k: u32) -> Option<u128> {
+    //! Sums the `k`-th powers of the divisors of a given number, `sigma_k(n)`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to sum the divisors of.
+    //! 2. `k: u32` - The power to raise each divisor to.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u128>` - The sum of the powers, or `None` if it does not fit in a `u128`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::sigma_k(12, 2), Some(210));
+    //! assert_eq!(prime_checker::sigma_k(u64::MAX, 3), None);
+    //! ```
+    //!
+    let sum: Option<u128> = libs::arithmetic::sigma_k(num, k);
+    return sum;
+}
+
+#[cfg(feature = "alloc")]
+pub fn omega(num: u64) -> u32 {
+    //! Counts the distinct prime factors of a given number, `omega(n)`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to count the prime factors of.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u32` - The number of distinct prime factors.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::omega(360), 3);
+    //! ```
+    //!
+    let n_factors: u32 = libs::arithmetic::omega(num);
+    return n_factors;
+}
+
+#[cfg(feature = "alloc")]
+pub fn big_omega(num: u64) -> u32 {
+    //! Counts the prime factors of a given number with multiplicity, `Omega(n)`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to count the prime factors of.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u32` - The number of prime factors, counted with multiplicity.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::big_omega(360), 6);
+    //! ```
+    //!
+    let n_factors: u32 = libs::arithmetic::big_omega(num);
+    return n_factors;
+}
+
+#[cfg(feature = "alloc")]
+pub fn divisor_count_sieve(limit: u64) -> Vec<u64> {
+    //! Builds a table of the divisor counts of every number till a given limit.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The number to build the table till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u64>` - The table, indexed by the number itself.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::divisor_count_sieve(6), vec![0, 1, 2, 2, 3, 2, 4]);
+    //! ```
+    //!
+    let table: Vec<u64> = libs::arithmetic::divisor_count_sieve(limit);
+    return table;
+}
+
+#[cfg(feature = "alloc")]
+pub fn divisor_sum_sieve(limit: u64) -> Vec<u128> {
+    //! Builds a table of the divisor sums of every number till a given limit.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The number to build the table till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u128>` - The table, indexed by the number itself.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::divisor_sum_sieve(6), vec![0, 1, 3, 4, 7, 6, 12]);
+    //! ```
+    //!
+    let table: Vec<u128> = libs::arithmetic::divisor_sum_sieve(limit);
+    return table;
+}
+
+#[cfg(feature = "alloc")]
+pub fn sigma_k_sieve(limit: u64, k: u32) -> Option<Vec<u128>> {
+    //! Builds a table of `sigma_k(n)` for every number till a given limit.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The number to build the table till.
+    //! 2. `k: u32` - The power to raise each divisor to.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<Vec<u128>>` - The table, indexed by the number itself, or `None` if any entry does not fit in a `u128`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::sigma_k_sieve(4, 2), Some(vec![0, 1, 5, 10, 21]));
+    //! ```
+    //!
+    let table: Option<Vec<u128>> = libs::arithmetic::sigma_k_sieve(limit, k);
+    return table;
+}
+
+#[cfg(feature = "alloc")]
+pub fn omega_sieve(limit: u64) -> Vec<u32> {
+    //! Builds a table of the number of distinct prime factors of every number till a given limit.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The number to build the table till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u32>` - The table, indexed by the number itself.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::omega_sieve(6), vec![0, 0, 1, 1, 1, 1, 2]);
+    //! ```
+    //!
+    let table: Vec<u32> = libs::arithmetic::omega_sieve(limit);
+    return table;
+}
+
+#[cfg(feature = "alloc")]
+pub fn big_omega_sieve(limit: u64) -> Vec<u32> {
+    //! Builds a table of the number of prime factors (with multiplicity) of every number till a given limit.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The number to build the table till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u32>` - The table, indexed by the number itself.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::big_omega_sieve(8), vec![0, 0, 1, 1, 2, 1, 2, 1, 3]);
+    //! ```
+    //!
+    let table: Vec<u32> = libs::arithmetic::big_omega_sieve(limit);
+    return table;
+}
+
+#[cfg(feature = "alloc")]
+pub fn totient(num: u64) -> u64 {
+    //! Counts the numbers till a given number that are coprime to it; Euler's totient, `phi(n)`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to find the totient of.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - The totient; `0` for `0`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::totient(36), 12);
+    //! ```
+    //!
+    let result: u64 = libs::arithmetic::totient(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn carmichael(num: u64) -> u64 {
+    //! Finds the smallest `m` such that `a^m = 1 (mod n)` for every `a` coprime to `n`; Carmichael's function, `lambda(n)`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to find the Carmichael function of.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - The value of `lambda(n)`; `0` for `0`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::carmichael(561), 80);
+    //! ```
+    //!
+    let result: u64 = libs::arithmetic::carmichael(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn mobius(num: u64) -> i8 {
+    //! Finds the Mobius function, `mu(n)`, of a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to find the Mobius function of.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `i8` - `0` if the number has a squared prime factor, otherwise `1` or `-1` for an even or odd number of prime factors.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::mobius(30), -1);
+    //! assert_eq!(prime_checker::mobius(12), 0);
+    //! ```
+    //!
+    let result: i8 = libs::arithmetic::mobius(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn totient_sieve(limit: u64) -> Vec<u64> {
+    //! Builds a table of the totients of every number till a given limit, with a linear sieve.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The number to build the table till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u64>` - The table, indexed by the number itself.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::totient_sieve(6), vec![0, 1, 1, 2, 2, 4, 2]);
+    //! ```
+    //!
+    let result: Vec<u64> = libs::arithmetic::totient_sieve(limit);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn carmichael_sieve(limit: u64) -> Vec<u64> {
+    //! Builds a table of the Carmichael function of every number till a given limit, with a linear sieve.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The number to build the table till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u64>` - The table, indexed by the number itself.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::carmichael_sieve(8), vec![0, 1, 1, 2, 2, 4, 2, 6, 2]);
+    //! ```
+    //!
+    let result: Vec<u64> = libs::arithmetic::carmichael_sieve(limit);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn mobius_sieve(limit: u64) -> Vec<i8> {
+    //! Builds a table of the Mobius function of every number till a given limit, with a linear sieve.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The number to build the table till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<i8>` - The table, indexed by the number itself.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::mobius_sieve(6), vec![0, 1, -1, -1, 0, -1, 1]);
+    //! ```
+    //!
+    let result: Vec<i8> = libs::arithmetic::mobius_sieve(limit);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn totient_summatory(num: u64) -> u128 {
+    //! Sums the totients of every number till a given number; the totient summatory function, `Phi(x)`.
+    //!
+    //! Runs in `O(x^(2/3))` time and memory, using a totient sieve and Dirichlet's hyperbola method.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to sum till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u128` - The sum of `phi(n)` for `n` in `1..=num`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::totient_summatory(1_000_000), 303_963_552_392);
+    //! ```
+    //!
+    let result: u128 = libs::arithmetic::totient_summatory(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_perfect(num: u64) -> bool {
+    //! Checks to see if a given number is a perfect number, i.e. the sum of its divisors is `2 * num`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is perfect, and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_perfect(28), true);
+    //! ```
+    //!
+    let result: bool = libs::perfect::check_if_perfect(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_abundant(num: u64) -> bool {
+    //! Checks to see if a given number is an abundant number, i.e. the sum of its divisors is more than `2 * num`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is abundant, and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_abundant(12), true);
+    //! ```
+    //!
+    let result: bool = libs::perfect::check_if_abundant(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_deficient(num: u64) -> bool {
+    //! Checks to see if a given number is a deficient number, i.e. the sum of its divisors is less than `2 * num`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is deficient, and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_deficient(10), true);
+    //! ```
+    //!
+    let result: bool = libs::perfect::check_if_deficient(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn abundancy(num: u64) -> (u128, u64) {
+    //! Finds the abundancy index, `sigma(n) / n`, of a given number as an exact fraction.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to find the abundancy index of.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `(u128, u64)` - The numerator and denominator of the index, in lowest terms; `(0, 1)` for `0`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::abundancy(12), (7, 3));
+    //! ```
+    //!
+    let result: (u128, u64) = libs::perfect::abundancy(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn get_perfect(num: u64) -> Vec<u64> {
+    //! Finds all the perfect numbers till a given number.
+    //!
+    //! Builds each even perfect number from a Mersenne prime instead of scanning, so this is instant even for `u64::MAX`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u64>` - The list of all perfect numbers till that number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::get_perfect(10_000), vec![6, 28, 496, 8_128]);
+    //! ```
+    //!
+    let result: Vec<u64> = libs::perfect::find_perfect_till(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn abundant_numbers(low: u64, high: u64) -> AbundantNumbers {
+    //! Iterates over the abundant numbers in a given range, in increasing order.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `AbundantNumbers` - An iterator that sieves the divisor sums of the range one segment at a time.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let abundant: Vec<u64> = prime_checker::abundant_numbers(10, 30).collect();
+    //!
+    //! assert_eq!(abundant, vec![12, 18, 20, 24, 30]);
+    //! ```
+    //!
+    let result: AbundantNumbers = libs::perfect::AbundantNumbers::new(low, high);
+    return result;
+}
+
+#[cfg(feature = "std")]
+pub fn is_shcn(num: u64) -> bool {
+    //! Checks to see if a given number is a superior highly composite number (OEIS A002201).
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if, for some `e > 0`, `d(num) / num^e` is at least `d(k) / k^e` for every `k`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_shcn(2_520), true);
+    //! assert_eq!(prime_checker::is_shcn(240), false);
+    //! ```
+    //!
+    let result: bool = libs::records::check_if_superior_highly_composite(num);
+    return result;
+}
+
+#[cfg(feature = "std")]
+pub fn get_shcn(num: u64) -> Vec<u64> {
+    //! Finds all superior highly composite numbers till a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u64>` - The list of all superior highly composite numbers till that number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::get_shcn(5_040), vec![2, 6, 12, 60, 120, 360, 2_520, 5_040]);
+    //! ```
+    //!
+    let result: Vec<u64> = libs::records::find_superior_highly_composite_till(num);
+    return result;
+}
+
+#[cfg(feature = "std")]
+pub fn is_colossally_abundant(num: u64) -> bool {
+    //! Checks to see if a given number is a colossally abundant number (OEIS A004490).
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if, for some `e > 0`, `sigma(num) / num^(1 + e)` is at least `sigma(k) / k^(1 + e)` for every `k`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_colossally_abundant(55_440), true);
+    //! ```
+    //!
+    let result: bool = libs::records::check_if_colossally_abundant(num);
+    return result;
+}
+
+#[cfg(feature = "std")]
+pub fn get_colossally_abundant(num: u64) -> Vec<u64> {
+    //! Finds all colossally abundant numbers till a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u64>` - The list of all colossally abundant numbers till that number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::get_colossally_abundant(360), vec![2, 6, 12, 60, 120, 360]);
+    //! ```
+    //!
+    let result: Vec<u64> = libs::records::find_colossally_abundant_till(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_largely_composite(num: u64) -> bool {
+    //! Checks to see if a given number is a largely composite number (OEIS A067128).
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number has at least as many divisors as every smaller number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_largely_composite(18), true);
+    //! assert_eq!(prime_checker::is_largely_composite(16), false);
+    //! ```
+    //!
+    let result: bool = libs::records::check_if_largely_composite(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn get_largely_composite(num: u64) -> Vec<u64> {
+    //! Finds all largely composite numbers till a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u64>` - The list of all largely composite numbers till that number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::get_largely_composite(20), vec![1, 2, 3, 4, 6, 8, 10, 12, 18, 20]);
+    //! ```
+    //!
+    let result: Vec<u64> = libs::records::find_largely_composite_till(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_highly_abundant(num: u64) -> bool {
+    //! Checks to see if a given number is a highly abundant number (OEIS A002093).
+    //!
+    //! Sieves the divisor sums of every smaller number, so this takes time linear in `num`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number has a larger divisor sum than every smaller number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_highly_abundant(42), true);
+    //! ```
+    //!
+    let result: bool = libs::records::check_if_highly_abundant(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn get_highly_abundant(num: u64) -> Vec<u64> {
+    //! Finds all highly abundant numbers till a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u64>` - The list of all highly abundant numbers till that number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::get_highly_abundant(20), vec![1, 2, 3, 4, 6, 8, 10, 12, 16, 18, 20]);
+    //! ```
+    //!
+    let result: Vec<u64> = libs::records::find_highly_abundant_till(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn prove_prime(num: u64) -> Option<Certificate> {
+    //! Builds a certificate proving that a given number is prime.
+    //!
+    //! The certificate can be checked with `verify_certificate` and stored with `Certificate::to_text`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to prove prime.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<Certificate>` - A Pocklington or Pratt certificate, or `None` if the number is not prime.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let proof = prime_checker::prove_prime(1_000_000_007).unwrap();
+    //!
+    //! assert_eq!(prime_checker::verify_certificate(&proof), true);
+    //! println!("{}", proof.to_text());
+    //! ```
+    //!
+    let result: Option<Certificate> = libs::certificate::prove_prime(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn verify_certificate(certificate: &Certificate) -> bool {
+    //! Checks a primality certificate, independently of the primality tests used to build it.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `certificate: &Certificate` - The certificate to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the certificate proves its number is prime, and false if it does not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::Certificate;
+    //!
+    //! let proof = Certificate::from_text("small 2\nsmall 3\npratt 7 3 2^1 3^1").unwrap();
+    //!
+    //! assert_eq!(prime_checker::verify_certificate(&proof), true);
+    //! ```
+    //!
+    let result: bool = libs::certificate::verify_certificate(certificate);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_mersenne_prime(p: u32) -> bool {
+    //! Checks whether the Mersenne number `2^p - 1` is prime, using the Lucas-Lehmer test.
+    //!
+    //! The residues are kept as arbitrary-precision integers, so this works far beyond `u128`, e.g. for `p = 4423` (a 1332-digit number).
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `p: u32` - The exponent of the Mersenne number.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if `2^p - 1` is prime, and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_mersenne_prime(127), true);
+    //! assert_eq!(prime_checker::is_mersenne_prime(11), false); // 2047 = 23 * 89
+    //! ```
+    //!
+    let result: bool = libs::mersenne::check_if_mersenne_prime(p);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn mersenne_exponents_till(p: u32) -> Vec<u32> {
+    //! Finds all the exponents `p` till a given number for which `2^p - 1` is prime.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `p: u32` - The exponent to check till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u32>` - The list of the Mersenne prime exponents till that number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::mersenne_exponents_till(130), vec![2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127]);
+    //! ```
+    //!
+    let result: Vec<u32> = libs::mersenne::find_mersenne_exponents_till(p);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn twin_primes(low: u64, high: u64) -> PrimeTuples {
+    //! Iterates over the twin primes `(p, p + 2)` with both members in a given range, yielding `p`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `PrimeTuples` - An iterator over the smaller member of each pair, in increasing order.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let pairs: Vec<u64> = prime_checker::twin_primes(0, 50).collect();
+    //!
+    //! assert_eq!(pairs, vec![3, 5, 11, 17, 29, 41]);
+    //! ```
+    //!
+    let result: PrimeTuples =
+        libs::constellations::PrimeTuples::new(&libs::constellations::TWIN_PATTERN, low, high)
+            .unwrap();
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn cousin_primes(low: u64, high: u64) -> PrimeTuples {
+    //! Iterates over the cousin primes `(p, p + 4)` with both members in a given range, yielding `p`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `PrimeTuples` - An iterator over the smaller member of each pair, in increasing order.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let pairs: Vec<u64> = prime_checker::cousin_primes(0, 50).collect();
+    //!
+    //! assert_eq!(pairs, vec![3, 7, 13, 19, 37, 43]);
+    //! ```
+    //!
+    let result: PrimeTuples =
+        libs::constellations::PrimeTuples::new(&libs::constellations::COUSIN_PATTERN, low, high)
+            .unwrap();
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn sexy_primes(low: u64, high: u64) -> PrimeTuples {
+    //! Iterates over the sexy primes `(p, p + 6)` with both members in a given range, yielding `p`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `PrimeTuples` - An iterator over the smaller member of each pair, in increasing order.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let pairs: Vec<u64> = prime_checker::sexy_primes(0, 50).collect();
+    //!
+    //! assert_eq!(pairs, vec![5, 7, 11, 13, 17, 23, 31, 37, 41]);
+    //! ```
+    //!
+    let result: PrimeTuples =
+        libs::constellations::PrimeTuples::new(&libs::constellations::SEXY_PATTERN, low, high)
+            .unwrap();
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn prime_tuples(pattern: &[u64], low: u64, high: u64) -> Option<PrimeTuples> {
+    //! Iterates over the prime k-tuples of a pattern with every member in a given range, yielding the first member.
+    //!
+    //! The primes come from a segmented sieve, so the range can be anywhere in `u64` and memory use stays constant.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `pattern: &[u64]` - The offsets of the tuple, starting at `0`, e.g. `[0, 2, 6, 8]` for prime quadruplets.
+    //! 2. `low: u64` - The start of the range.
+    //! 3. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<PrimeTuples>` - An iterator over each `p` for which every `p + offset` is prime, or `None` if the pattern is not admissible.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let quadruplets: Vec<u64> = prime_checker::prime_tuples(&[0, 2, 6, 8], 0, 1_000).unwrap().collect();
+    //!
+    //! assert_eq!(quadruplets, vec![5, 11, 101, 191, 821]);
+    //! assert!(prime_checker::prime_tuples(&[0, 2, 4], 0, 1_000).is_none());
+    //! ```
+    //!
+    let result: Option<PrimeTuples> = libs::constellations::PrimeTuples::new(pattern, low, high);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_admissible(pattern: &[u64]) -> bool {
+    //! Checks whether a prime k-tuple pattern is admissible.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `pattern: &[u64]` - The offsets of the tuple.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the offsets start at `0`, increase strictly, and miss a residue class modulo every prime; an inadmissible pattern can only match where one of its members is a small prime itself.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_admissible(&[0, 2, 6, 8]), true);
+    //! assert_eq!(prime_checker::is_admissible(&[0, 2, 4]), false); // One of p, p + 2, p + 4 is divisible by 3.
+    //! ```
+    //!
+    let result: bool = libs::constellations::check_if_admissible(pattern);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn twin_prime_count(num: u64) -> u64 {
+    //! Counts the twin prime pairs, `pi_2(num)` with both members till a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to count till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - The number of pairs.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::twin_prime_count(1_000), 35);
+    //! ```
+    //!
+    let result: u64 =
+        libs::constellations::count_prime_tuples(&libs::constellations::TWIN_PATTERN, num).unwrap();
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn cousin_prime_count(num: u64) -> u64 {
+    //! Counts the cousin prime pairs with both members till a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to count till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - The number of pairs.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::cousin_prime_count(1_000), 41);
+    //! ```
+    //!
+    let result: u64 =
+        libs::constellations::count_prime_tuples(&libs::constellations::COUSIN_PATTERN, num)
+            .unwrap();
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn sexy_prime_count(num: u64) -> u64 {
+    //! Counts the sexy prime pairs with both members till a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to count till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - The number of pairs.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::sexy_prime_count(1_000), 74);
+    //! ```
+    //!
+    let result: u64 =
+        libs::constellations::count_prime_tuples(&libs::constellations::SEXY_PATTERN, num).unwrap();
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn prime_tuple_count(pattern: &[u64], num: u64) -> Option<u64> {
+    //! Counts the prime k-tuples of a pattern with every member till a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `pattern: &[u64]` - The offsets of the tuple, starting at `0`.
+    //! 2. `num: u64` - The number to count till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - The number of tuples, or `None` if the pattern is not admissible.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::prime_tuple_count(&[0, 2, 6, 8], 1_000_000), Some(166));
+    //! ```
+    //!
+    let result: Option<u64> = libs::constellations::count_prime_tuples(pattern, num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn prime_gaps(low: u64, high: u64) -> PrimeGaps {
+    //! Iterates over the gaps between consecutive primes in a given range.
+    //!
+    //! The primes come from a segmented sieve and only the current one is kept, so this runs over ranges near
+    //! `10^12` and beyond in constant memory.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `PrimeGaps` - An iterator over `(p, gap)` pairs, where `p + gap` is the prime after `p`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let gaps: Vec<(u64, u64)> = prime_checker::prime_gaps(20, 40).collect();
+    //!
+    //! assert_eq!(gaps, vec![(23, 6), (29, 2), (31, 6)]);
+    //! ```
+    //!
+    let result: PrimeGaps = libs::gaps::PrimeGaps::new(low, high);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn maximal_gaps(num: u64) -> Vec<(u64, u64)> {
+    //! Finds the maximal prime gaps till a given number (OEIS A002386 and A005250).
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check till.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<(u64, u64)>` - The `(p, gap)` pairs whose gap is larger than every earlier gap, with `p + gap` till the given number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let records: Vec<(u64, u64)> = prime_checker::maximal_gaps(1_000);
+    //!
+    //! assert_eq!(records, vec![(2, 1), (3, 2), (7, 4), (23, 6), (89, 8), (113, 14), (523, 18), (887, 20)]);
+    //! ```
+    //!
+    let result: Vec<(u64, u64)> = libs::gaps::find_maximal_gaps_till(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn gap_stats(low: u64, high: u64) -> GapStats {
+    //! Summarizes the gaps between consecutive primes in a given range.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `GapStats` - The histogram of the gap sizes, their mean, the largest gap and, with the `std` feature, the largest merit `gap / ln(p)`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let stats = prime_checker::gap_stats(0, 100);
+    //!
+    //! assert_eq!(stats.count(), 24);
+    //! assert_eq!(stats.max_gap(), Some((89, 8)));
+    //! assert_eq!(stats.histogram()[&2], 8); // Eight twin prime pairs below 100.
+    //! ```
+    //!
+    let result: GapStats = libs::gaps::GapStats::new(low, high);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn goldbach_partitions(num: u64) -> Vec<(u64, u64)> {
+    //! Finds the ways of writing an even number as the sum of two primes.
+    //!
+    //! Sieves the primes till the number once, so this takes linear time rather than testing every pair.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The even number to split.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<(u64, u64)>` - The pairs of primes `(p, q)` with `p <= q` and `p + q = num`, in increasing order of `p`; empty for odd numbers and numbers below `4`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let partitions: Vec<(u64, u64)> = prime_checker::goldbach_partitions(28);
+    //!
+    //! assert_eq!(partitions, vec![(5, 23), (11, 17)]);
+    //! ```
+    //!
+    let result: Vec<(u64, u64)> = libs::goldbach::find_goldbach_partitions(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn goldbach_count(num: u64) -> u64 {
+    //! Counts the ways of writing an even number as the sum of two primes.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The even number to split.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - The number of pairs of primes `(p, q)` with `p <= q` and `p + q = num`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::goldbach_count(100), 6);
+    //! ```
+    //!
+    let result: u64 = libs::goldbach::count_goldbach_partitions(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn verify_goldbach(low: u64, high: u64) -> Result<Vec<(u64, u64)>, u64> {
+    //! Checks that every even number in a given range is the sum of two primes.
+    //!
+    //! Sieves the range one segment at a time, together with the small primes below it, so ranges near `10^12`
+    //! and beyond are checked in constant memory besides the result.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Result<Vec<(u64, u64)>, u64>` - The pairs `(n, p)` of each even number `n` from `4` on with the smallest prime `p` for which `n - p` is prime, or the first even number without a partition as the error.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let minimal_primes = prime_checker::verify_goldbach(90, 100).unwrap();
+    //!
+    //! assert_eq!(minimal_primes, vec![(90, 7), (92, 3), (94, 5), (96, 7), (98, 19), (100, 3)]);
+    //! ```
+    //!
+    let result: Result<Vec<(u64, u64)>, u64> = libs::goldbach::verify_goldbach_range(low, high);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_sophie_germain(num: u64) -> bool {
+    //! Checks to see if a given number is a Sophie Germain prime, i.e. both `num` and `2 * num + 1` are prime.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is a Sophie Germain prime, and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_sophie_germain(11), true); // 23 is prime.
+    //! assert_eq!(prime_checker::is_sophie_germain(13), false); // 27 is not.
+    //! ```
+    //!
+    let result: bool = libs::cunningham::check_if_sophie_germain(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_safe_prime(num: u64) -> bool {
+    //! Checks to see if a given number is a safe prime, i.e. both `num` and `(num - 1) / 2` are prime.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is a safe prime, and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_safe_prime(23), true); // 11 is prime.
+    //! assert_eq!(prime_checker::is_safe_prime(29), false); // 14 is not.
+    //! ```
+    //!
+    let result: bool = libs::cunningham::check_if_safe_prime(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn sophie_germain_primes(low: u64, high: u64) -> SophieGermainPrimes {
+    //! Iterates over the Sophie Germain primes in a given range, in increasing order.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `SophieGermainPrimes` - An iterator that sieves the primes of the range and tests `2p + 1` with Miller-Rabin.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let primes: Vec<u64> = prime_checker::sophie_germain_primes(0, 60).collect();
+    //!
+    //! assert_eq!(primes, vec![2, 3, 5, 11, 23, 29, 41, 53]);
+    //! ```
+    //!
+    let result: SophieGermainPrimes = libs::cunningham::SophieGermainPrimes::new(low, high);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn safe_primes(low: u64, high: u64) -> SafePrimes {
+    //! Iterates over the safe primes in a given range, in increasing order.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `SafePrimes` - An iterator that sieves the primes `p` of the halved range and tests `2p + 1` with Miller-Rabin.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let primes: Vec<u64> = prime_checker::safe_primes(0, 110).collect();
+    //!
+    //! assert_eq!(primes, vec![5, 7, 11, 23, 47, 59, 83, 107]);
+    //! ```
+    //!
+    let result: SafePrimes = libs::cunningham::SafePrimes::new(low, high);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn cunningham_chain_length(num: u64, kind: ChainKind) -> u32 {
+    //! Finds the length of the Cunningham chain that starts at a given number.
+    //!
+    //! Members past `u64` are tested with the `u128` Baillie-PSW test.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The first member of the chain.
+    //! 2. `kind: ChainKind` - `ChainKind::First` for `p, 2p + 1, ...` or `ChainKind::Second` for `p, 2p - 1, ...`.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u32` - The number of primes in the chain, or `0` if the number is not prime.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::ChainKind;
+    //!
+    //! assert_eq!(prime_checker::cunningham_chain_length(89, ChainKind::First), 6); // 89, 179, 359, 719, 1439, 2879
+    //! assert_eq!(prime_checker::cunningham_chain_length(1_531, ChainKind::Second), 5);
+    //! ```
+    //!
+    let result: u32 = libs::cunningham::find_chain_length(num, kind);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn cunningham_chains(low: u64, high: u64, kind: ChainKind, min_length: u32) -> Vec<(u64, u32)> {
+    //! Finds the complete Cunningham chains that start in a given range.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //! 3. `kind: ChainKind` - The kind of chain to look for.
+    //! 4. `min_length: u32` - The shortest chain to report.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<(u64, u32)>` - The `(first member, length)` pairs of the chains that cannot be extended backwards, in increasing order.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::ChainKind;
+    //!
+    //! let chains = prime_checker::cunningham_chains(0, 100, ChainKind::First, 3);
+    //!
+    //! assert_eq!(chains, vec![(2, 5), (41, 3), (89, 6)]);
+    //! ```
+    //!
+    let result: Vec<(u64, u32)> =
+        libs::cunningham::find_cunningham_chains(low, high, kind, min_length);
+    return result;
+}
+
+pub fn random_prime<R: RandomSource>(bits: u32, rng: &mut R) -> u64 {
+    //! Picks a prime with exactly a given number of bits, uniformly at random.
+    //!
+    //! Panics if there are no primes of that many bits in a `u64`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `bits: u32` - The bit length of the prime, from `2` till `64`.
+    //! 2. `rng: &mut R` - The random number generator, e.g. a `SeededRng` for reproducible fixtures.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - A prime from `2^(bits - 1)` till `2^bits - 1`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::SeededRng;
+    //!
+    //! let mut rng = SeededRng::new(42);
+    //! let prime: u64 = prime_checker::random_prime(32, &mut rng);
+    //!
+    //! assert_eq!(prime_checker::miller_rabin(prime), true);
+    //! assert_eq!(prime >> 31, 1);
+    //! assert_eq!(prime_checker::random_prime(32, &mut SeededRng::new(42)), prime);
+    //! ```
+    //!
+    let result: u64 = libs::random::random_prime(bits, rng);
+    return result;
+}
+
+pub fn random_prime_in<R: RandomSource>(low: u64, high: u64, rng: &mut R) -> Option<u64> {
+    //! Picks a prime in a given range, uniformly at random.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //! 3. `rng: &mut R` - The random number generator.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - A prime from the range, or `None` if the range holds no prime.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::SeededRng;
+    //!
+    //! let mut rng = SeededRng::new(7);
+    //!
+    //! assert_eq!(prime_checker::random_prime_in(24, 29, &mut rng), Some(29));
+    //! assert_eq!(prime_checker::random_prime_in(24, 28, &mut rng), None);
+    //! ```
+    //!
+    let result: Option<u64> = libs::random::random_prime_in(low, high, rng);
+    return result;
+}
+
+pub fn random_safe_prime<R: RandomSource>(bits: u32, rng: &mut R) -> u64 {
+    //! Picks a safe prime `p`, with `(p - 1) / 2` prime too, with exactly a given number of bits, uniformly at random.
+    //!
+    //! Panics if there are no safe primes of that many bits in a `u64`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `bits: u32` - The bit length of the prime, from `3` till `64`.
+    //! 2. `rng: &mut R` - The random number generator.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - A safe prime from `2^(bits - 1)` till `2^bits - 1`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::SeededRng;
+    //!
+    //! let prime: u64 = prime_checker::random_safe_prime(48, &mut SeededRng::new(1));
+    //!
+    //! assert_eq!(prime_checker::is_safe_prime(prime), true);
+    //! ```
+    //!
+    let result: u64 = libs::random::random_safe_prime(bits, rng);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn random_composite_with_factors<R: RandomSource>(
+    count: u32,
+    bits: u32,
+    rng: &mut R,
+) -> (u128, Vec<u64>) {
+    //! Builds a number from random primes of a given size, e.g. a semiprime to test factorization with.
+    //!
+    //! Panics unless `count` is positive and the product fits in a `u128`, i.e. `count * bits <= 128`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `count: u32` - The number of prime factors.
+    //! 2. `bits: u32` - The bit length of each prime factor.
+    //! 3. `rng: &mut R` - The random number generator.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `(u128, Vec<u64>)` - The product, and its prime factors in increasing order.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::SeededRng;
+    //!
+    //! let (semiprime, factors) = prime_checker::random_composite_with_factors(2, 40, &mut SeededRng::new(3));
+    //!
+    //! assert_eq!(semiprime, factors[0] as u128 * factors[1] as u128);
+    //! assert_eq!(prime_checker::factorize(semiprime), vec![(factors[0] as u128, 1), (factors[1] as u128, 1)]);
+    //! ```
+    //!
+    let result: (u128, Vec<u64>) = libs::random::random_composite_with_factors(count, bits, rng);
+    return result;
+}
+
+pub fn sqrt_mod_prime(a: u64, p: u64) -> Option<u64> {
+    //! Finds a square root of a number modulo a prime.
+    //!
+    //! Uses Tonelli-Shanks, or Cipolla when `p - 1` is divisible by a large power of two.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `a: u64` - The number to take the root of.
+    //! 2. `p: u64` - The prime modulus.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - The smaller root `r` of `r^2 = a (mod p)`, or `None` if `a` is not a square modulo `p` or `p` is not prime.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::sqrt_mod_prime(2, 7), Some(3)); // 3^2 = 9 = 2 (mod 7)
+    //! assert_eq!(prime_checker::sqrt_mod_prime(3, 7), None);
+    //! ```
+    //!
+    let result: Option<u64> = libs::quadratic::sqrt_mod_prime(a, p);
+    return result;
+}
+
+pub fn sqrt_mod_prime_power(a: u64, p: u64, k: u32) -> Option<u64> {
+    //! Finds a square root of a number modulo a prime power, by Hensel lifting a root modulo the prime.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `a: u64` - The number to take the root of.
+    //! 2. `p: u64` - The prime.
+    //! 3. `k: u32` - The exponent of the prime.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - A root `r` of `r^2 = a (mod p^k)`, or `None` if there is none, `p` is not prime, or `p^k` does not fit in `u64`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let root = prime_checker::sqrt_mod_prime_power(2, 7, 5).unwrap();
+    //!
+    //! assert_eq!(root * root % 16_807, 2);
+    //! ```
+    //!
+    let result: Option<u64> = libs::quadratic::sqrt_mod_prime_power(a, p, k);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn sqrt_mod(a: u64, n: u64) -> Option<u64> {
+    //! Finds a square root of a number modulo any modulus.
+    //!
+    //! Factorizes the modulus, takes a root modulo each prime power, and combines them with the Chinese Remainder
+    //! Theorem.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `a: u64` - The number to take the root of.
+    //! 2. `n: u64` - The modulus.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - A root `r` of `r^2 = a (mod n)`, or `None` if there is none or `n` is `0`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let root = prime_checker::sqrt_mod(4, 15).unwrap();
+    //!
+    //! assert_eq!(root * root % 15, 4);
+    //! assert_eq!(prime_checker::sqrt_mod(2, 15), None);
+    //! ```
+    //!
+    let result: Option<u64> = libs::quadratic::sqrt_mod(a, n);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_quadratic_residue(a: u64, n: u64) -> bool {
+    //! Checks to see if a number is a square modulo a given modulus.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `a: u64` - The number to check.
+    //! 2. `n: u64` - The modulus.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if `x^2 = a (mod n)` has a solution, and false if it does not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_quadratic_residue(4, 7), true);
+    //! assert_eq!(prime_checker::is_quadratic_residue(3, 7), false);
+    //! ```
+    //!
+    let result: bool = libs::quadratic::check_if_quadratic_residue(a, n);
+    return result;
+}
+
+pub fn quadratic_residues(p: u64) -> QuadraticResidues {
+    //! Iterates over the non-zero quadratic residues modulo a prime, in increasing order.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `p: u64` - The prime modulus.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `QuadraticResidues` - An iterator over the non-zero squares modulo `p`, which is empty if `p` is not prime.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let residues: Vec<u64> = prime_checker::quadratic_residues(11).collect();
+    //!
+    //! assert_eq!(residues, vec![1, 3, 4, 5, 9]);
+    //! ```
+    //!
+    let result: QuadraticResidues = libs::quadratic::QuadraticResidues::new(p);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn multiplicative_order(a: u64, n: u64) -> Option<u64> {
+    //! Finds the multiplicative order of a number modulo `n`, the smallest `k > 0` with `a^k = 1 (mod n)`.
+    //!
+    //! Starts from the Carmichael function `lambda(n)`, which every order divides, and strips its prime factors.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `a: u64` - The number whose order to find.
+    //! 2. `n: u64` - The modulus.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - The order, or `None` if `a` and `n` share a factor or `n` is `0`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::multiplicative_order(2, 7), Some(3));
+    //! assert_eq!(prime_checker::multiplicative_order(10, 49), Some(42));
+    //! assert_eq!(prime_checker::multiplicative_order(6, 9), None);
+    //! ```
+    //!
+    let result: Option<u64> = libs::multiplicative::multiplicative_order(a, n);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn primitive_root(n: u64) -> Option<u64> {
+    //! Finds the smallest primitive root modulo `n`, a number whose powers run through every unit modulo `n`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `n: u64` - The modulus.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - The smallest primitive root, or `None` unless `n` is `1`, `2`, `4`, `p^k` or `2p^k` for an odd prime `p`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::primitive_root(7), Some(3));
+    //! assert_eq!(prime_checker::primitive_root(998_244_353), Some(3));
+    //! assert_eq!(prime_checker::primitive_root(8), None);
+    //! ```
+    //!
+    let result: Option<u64> = libs::multiplicative::find_primitive_root(n);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_primitive_root(g: u64, n: u64) -> bool {
+    //! Checks whether a number is a primitive root modulo `n`, i.e. has order `phi(n)`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `g: u64` - The number to check.
+    //! 2. `n: u64` - The modulus.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if `g` generates the multiplicative group modulo `n`, and false if it does not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_primitive_root(3, 7), true);
+    //! assert_eq!(prime_checker::is_primitive_root(2, 7), false);
+    //! ```
+    //!
+    let result: bool = libs::multiplicative::check_if_primitive_root(g, n);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn discrete_log(g: u64, h: u64, n: u64) -> Result<Option<u64>, DiscreteLogError> {
+    //! Finds the discrete logarithm of `h` to the base `g` modulo `n`, the smallest `x >= 0` with `g^x = h (mod n)`.
+    //!
+    //! Uses baby-step giant-step, split with Pohlig-Hellman over the prime factors of the order of `g`, so it is
+    //! fast whenever that order is smooth; a large prime factor `q` costs `O(sqrt(q))` time and memory. Orders with a
+    //! prime factor above `2^42` would need a table of over `2^21` entries, so they give an error instead of a search.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `g: u64` - The base, which must be coprime to `n`.
+    //! 2. `h: u64` - The target.
+    //! 3. `n: u64` - The modulus.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Result<Option<u64>, DiscreteLogError>` - The smallest such `x`, or `Ok(None)` if there is none or `g` is not coprime to `n`;
+    //!    `Err(DiscreteLogError::TooLarge { prime })` if the order of `g` has a prime factor above `2^42`, and nothing was searched.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::DiscreteLogError;
+    //!
+    //! let p: u64 = 998_244_353;
+    //! let h: u64 = prime_checker::modular::mod_pow(3, 123_456_789, p);
+    //!
+    //! assert_eq!(prime_checker::discrete_log(3, h, p), Ok(Some(123_456_789)));
+    //! assert_eq!(prime_checker::discrete_log(2, 3, 7), Ok(None));
+    //!
+    //! // 8_796_093_022_967 = 2q + 1 for the prime q = 4_398_046_511_483, just past 2^42.
+    //! assert_eq!(
+    //!     prime_checker::discrete_log(5, 7, 8_796_093_022_967),
+    //!     Err(DiscreteLogError::TooLarge { prime: 4_398_046_511_483 })
+    //! );
+    //! ```
+    //!
+    let result: Result<Option<u64>, DiscreteLogError> = libs::multiplicative::discrete_log(g, h, n);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn pratt_certificate(num: u64) -> Option<Certificate> {
+    //! Builds a Pratt certificate for a given prime, with its smallest primitive root as the witness.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to prove prime.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<Certificate>` - A Pratt certificate, or `None` if the number is below `3` or not prime.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let proof = prime_checker::pratt_certificate(65_537).unwrap();
+    //!
+    //! assert_eq!(prime_checker::verify_certificate(&proof), true);
+    //! assert_eq!(prime_checker::pratt_certificate(561), None);
+    //! ```
+    //!
+    let result: Option<Certificate> = libs::certificate::pratt_certificate(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_b_smooth(num: u64, bound: u64) -> bool {
+    //! Checks whether every prime factor of a given number is at most a bound.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //! 2. `bound: u64` - The largest prime factor allowed.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is `bound`-smooth (`1` always is, `0` never is), and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_b_smooth(2 * 3 * 5 * 7 * 7, 7), true);
+    //! assert_eq!(prime_checker::is_b_smooth(2 * 11, 7), false);
+    //! ```
+    //!
+    let result: bool = libs::smooth::check_if_b_smooth(num, bound);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn smallest_prime_factor(num: u64) -> Option<u64> {
+    //! Finds the smallest prime factor of a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - The smallest prime factor, or `None` for `0` and `1`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::smallest_prime_factor(91), Some(7));
+    //! assert_eq!(prime_checker::smallest_prime_factor(1), None);
+    //! ```
+    //!
+    let result: Option<u64> = libs::smooth::find_smallest_prime_factor(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn largest_prime_factor(num: u64) -> Option<u64> {
+    //! Finds the largest prime factor of a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - The largest prime factor, or `None` for `0` and `1`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::largest_prime_factor(600_851_475_143), Some(6_857));
+    //! assert_eq!(prime_checker::largest_prime_factor(0), None);
+    //! ```
+    //!
+    let result: Option<u64> = libs::smooth::find_largest_prime_factor(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn smooth_numbers(bound: u64, limit: u64) -> SmoothNumbers {
+    //! Iterates over the numbers from `1` till a limit whose prime factors are all at most a bound, in increasing order.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `bound: u64` - The largest prime factor allowed.
+    //! 2. `limit: u64` - The largest number to yield.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `SmoothNumbers` - An iterator that merges the multiples of the primes till `bound` through a heap.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let hamming: Vec<u64> = prime_checker::smooth_numbers(5, 20).collect();
+    //!
+    //! assert_eq!(hamming, vec![1, 2, 3, 4, 5, 6, 8, 9, 10, 12, 15, 16, 18, 20]);
+    //! ```
+    //!
+    let result: SmoothNumbers = libs::smooth::SmoothNumbers::new(bound, limit);
+    return result;
+}
+
+#[cfg(feature = "std")]
+pub fn dickman_rho(u: f64) -> f64 {
+    //! Evaluates the Dickman rho function, the asymptotic density of the `x^(1/u)`-smooth numbers.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `u: f64` - The ratio `ln(x) / ln(y)`.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `f64` - The value of `rho(u)`; `1` for `u <= 1` and `1 - ln(u)` for `u <= 2`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let rho: f64 = prime_checker::dickman_rho(3.0);
+    //!
+    //! assert!((rho - 0.048_608_388).abs() < 1e-8);
+    //! ```
+    //!
+    let result: f64 = libs::smooth::dickman_rho(u);
+    return result;
+}
+
+#[cfg(feature = "std")]
+pub fn smooth_count_estimate(limit: u64, bound: u64) -> f64 {
+    //! Estimates `Psi(x, y)`, the count of `y`-smooth numbers from `1` till `x`, as `x rho(ln(x) / ln(y))`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The limit `x`.
+    //! 2. `bound: u64` - The largest prime factor allowed, `y`.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `f64` - The estimate, which is exact when `y >= x`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let estimate: f64 = prime_checker::smooth_count_estimate(1_000_000, 1_000);
+    //! let exact: usize = prime_checker::smooth_numbers(1_000, 1_000_000).count();
+    //!
+    //! assert!((estimate / exact as f64 - 1.0).abs() < 0.5);
+    //! ```
+    //!
+    let result: f64 = libs::smooth::estimate_smooth_count(limit, bound);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn spf_table(limit: u64) -> SpfTable {
+    //! Builds a table of the smallest prime factor of every number till a limit, for factorizing many numbers in bulk.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `limit: u64` - The largest number in the table.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `SpfTable` - A table whose `factorize(n)` takes `O(log n)` steps; it stores one `u32` per odd number.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let table = prime_checker::spf_table(1_000);
+    //!
+    //! assert_eq!(table.factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+    //! assert_eq!(table.smallest_prime_factor(391), Some(17));
+    //! assert_eq!(table.count_divisors(720), 30);
+    //! ```
+    //!
+    let result: SpfTable = libs::spf::SpfTable::new(limit);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_squarefree(num: u64) -> bool {
+    //! Checks whether no square of a prime divides a given number.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is squarefree (`1` is, `0` is not), and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_squarefree(30), true);
+    //! assert_eq!(prime_checker::is_squarefree(12), false);
+    //! ```
+    //!
+    let result: bool = libs::powers::check_if_squarefree(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn radical(num: u64) -> u64 {
+    //! Finds the radical of a given number, the product of its distinct prime factors.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - The radical; `1` for `1` and `0` for `0`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::radical(72), 6);
+    //! assert_eq!(prime_checker::radical(1), 1);
+    //! ```
+    //!
+    let result: u64 = libs::powers::find_radical(num);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn is_powerful(num: u64) -> bool {
+    //! Checks whether the square of every prime factor of a given number divides it too.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is powerful (`1` is, `0` is not), and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_powerful(72), true);
+    //! assert_eq!(prime_checker::is_powerful(24), false);
+    //! ```
+    //!
+    let result: bool = libs::powers::check_if_powerful(num);
+    return result;
+}
+
+pub fn is_perfect_power(num: u64) -> Option<(u64, u32)> {
+    //! Checks whether a given number is a perfect power `b^k` with `k >= 2`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<(u64, u32)>` - The pair `(b, k)` with the largest such `k`, or `None` if the number is not a perfect power (`0` and `1` included).
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::is_perfect_power(64), Some((2, 6)));
+    //! assert_eq!(prime_checker::is_perfect_power(36), Some((6, 2)));
+    //! assert_eq!(prime_checker::is_perfect_power(72), None);
+    //! ```
+    //!
+    let result: Option<(u64, u32)> = libs::powers::check_if_perfect_power(num);
+    return result;
+}
+
+pub fn integer_nth_root(num: u64, k: u32) -> u64 {
+    //! Computes the integer `k`-th root of a given number, `floor(num^(1/k))`, exactly.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The number to take the root of.
+    //! 2. `k: u32` - The degree of the root; panics if it is `0`.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `u64` - The largest `r` with `r^k <= num`.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::integer_nth_root(1_000_000_000_000, 3), 10_000);
+    //! assert_eq!(prime_checker::integer_nth_root(u64::MAX, 2), 4_294_967_295);
+    //! ```
+    //!
+    let result: u64 = libs::powers::integer_nth_root(num, k);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn squarefree_in_range(low: u64, high: u64) -> Vec<u64> {
+    //! Finds the squarefree numbers in a given range with a sieve.
+    //!
+    //! Crosses off the multiples of `p^2` for the primes `p` till `sqrt(high)`; past `2^40`, it only sieves with
+    //! the primes till `2^20` and checks the few large cofactors left over with the factorizer.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `low: u64` - The start of the range.
+    //! 2. `high: u64` - The end of the range, inclusive.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<u64>` - The squarefree numbers of the range, in increasing order.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! assert_eq!(prime_checker::squarefree_in_range(1, 12), vec![1, 2, 3, 5, 6, 7, 10, 11]);
+    //! ```
+    //!
+    let result: Vec<u64> = libs::powers::find_squarefree_in_range(low, high);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn ecm_factor(num: u128, params: &EcmParams) -> Option<u128> {
+    //! Looks for a non-trivial factor of a number with Lenstra's elliptic curve method.
+    //!
+    //! Finds factors of up to 20-30 digits, which Pollard's rho cannot reach; `factorize` runs a few curves on large
+    //! numbers before SIQS, and a full run on whatever SIQS cannot split.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u128` - The odd composite number to split.
+    //! 2. `params: &EcmParams` - The stage 1 and stage 2 bounds `b1` and `b2`, the number of curves and the seed that picks them; `EcmParams::for_digits` gives good defaults for a factor size.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u128>` - A factor, or `None` if none of the curves found one. The same parameters always give the same result.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::EcmParams;
+    //!
+    //! let num: u128 = 1_152_921_504_606_846_869 * 1_152_921_504_606_846_883;
+    //! let factor = prime_checker::ecm_factor(num, &EcmParams::for_digits(20)).unwrap();
+    //!
+    //! assert_eq!(num % factor, 0);
+    //! assert!(factor > 1 && factor < num);
+    //! ```
+    //!
+    let result: Option<u128> = libs::ecm::ecm(num, params);
+    return result;
+}
+
+pub fn squfof(num: u64) -> Option<u64> {
+    //! Looks for a factor of a given number with Shanks' square forms factorization (SQUFOF).
+    //!
+    //! Takes `O(n^(1/4))` steps whatever the factors are, using only `u128` arithmetic.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The composite number to split.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - A non-trivial factor, or `None` if every multiplier failed or the number is prime.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let factor = prime_checker::squfof(999_999_000_001 * 3).unwrap();
+    //!
+    //! assert!(factor == 3 || factor == 999_999_000_001);
+    //! ```
+    //!
+    let result: Option<u64> = libs::factor64::squfof(num);
+    return result;
+}
+
+pub fn hart_one_line(num: u64, max_iterations: u64) -> Option<u64> {
+    //! Looks for a factor of a given number with Hart's one-line factorization.
+    //!
+    //! Usually succeeds within `n^(1/3)` steps, which is as fast as SQUFOF below about `2^20`.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u64` - The composite number to split.
+    //! 2. `max_iterations: u64` - The number of steps to try before giving up.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u64>` - A non-trivial factor, or `None` if none turned up in time.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let factor = prime_checker::hart_one_line(1_000_003 * 999_983, 100_000).unwrap();
+    //!
+    //! assert!(factor == 1_000_003 || factor == 999_983);
+    //! ```
+    //!
+    let result: Option<u64> = libs::factor64::hart_one_line(num, max_iterations);
+    return result;
+}
+
+#[cfg(feature = "alloc")]
+pub fn siqs(num: u128) -> Option<u128> {
+    //! Looks for a factor of a given number with the self-initializing quadratic sieve (SIQS).
+    //!
+    //! The running time depends only on the size of the number, not on that of its factors, which makes it the fastest
+    //! method for products of two primes of similar size from about 25 till 38 digits; `factorize` runs it on its own for those.
+    //! `factorize_big` runs it up to 140 bits, about 42 digits.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: u128` - The odd composite number to split; it should not be a perfect power.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Option<u128>` - A non-trivial factor, or `None` if the number is below `2^40` or the sieve ran out of polynomials.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! let num: u128 = 1_000_000_007 * 998_244_353;
+    //! let factor = prime_checker::siqs(num).unwrap();
+    //!
+    //! assert!(factor == 1_000_000_007 || factor == 998_244_353);
+    //! ```
+    //!
+    let result: Option<u128> = libs::siqs::siqs(num);
+    return result;
+}
+
+#[cfg(feature = "bignum")]
+pub fn is_prime_big(num: &BigUint) -> bool {
+    //! Checks to see if a number of any size is a prime number.
+    //!
+    //! Numbers below `2^128` go to the same test as `is_prime`; larger ones get trial division by the primes below
+    //! `1000` and the Baillie-PSW test, which has no known counterexample.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: &BigUint` - The number to check.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number is prime, and false if it is not.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::BigUint;
+    //!
+    //! // 2^521 - 1 is a Mersenne prime, and 2^523 - 1 is not.
+    //! let one: BigUint = BigUint::from(1);
+    //! assert!(prime_checker::is_prime_big(&one.shl(521).checked_sub(&one).unwrap()));
+    //! assert!(!prime_checker::is_prime_big(&one.shl(523).checked_sub(&one).unwrap()));
+    //! ```
+    //!
+    let result: bool = libs::bignum::check_if_prime_big(num);
+    return result;
+}
+
+#[cfg(feature = "bignum")]
+pub fn is_prime_big_rounds<R: RandomSource>(num: &BigUint, rounds: u32, rng: &mut R) -> bool {
+    //! Checks to see if a number of any size is a prime number, with extra Miller-Rabin rounds on top of `is_prime_big`.
+    //!
+    //! Each round to a random base lets a composite through with a probability of at most `1/4`, so `rounds` of them
+    //! bound the error by `4^-rounds` even if Baillie-PSW had a counterexample.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: &BigUint` - The number to check.
+    //! 2. `rounds: u32` - The number of extra rounds; numbers below `2^64` are tested exactly and skip them.
+    //! 3. `rng: &mut R` - The generator that picks the bases.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `bool` - Is true if the number passed every test, and false if it is composite.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::{BigUint, SeededRng};
+    //!
+    //! let num: BigUint = "170141183460469231731687303715884105727".parse().unwrap();
+    //! let mut rng = SeededRng::new(7);
+    //!
+    //! assert!(prime_checker::is_prime_big_rounds(&num, 20, &mut rng));
+    //! ```
+    //!
+    let result: bool = libs::bignum::check_if_prime_big_rounds(num, rounds, rng);
+    return result;
+}
+
+#[cfg(feature = "bignum")]
+pub fn next_prime_big(num: &BigUint) -> BigUint {
+    //! Finds the smallest prime larger than a number of any size.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: &BigUint` - The number to start after.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `BigUint` - The next prime.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::BigUint;
+    //!
+    //! let num: BigUint = "100000000000000000000000000000000000000000".parse().unwrap();
+    //! let prime: BigUint = prime_checker::next_prime_big(&num);
+    //!
+    //! assert_eq!(prime.to_string(), "100000000000000000000000000000000000000109");
+    //! ```
+    //!
+    let result: BigUint = libs::bignum::find_next_prime_big(num);
+    return result;
+}
+
+#[cfg(feature = "bignum")]
+pub fn factorize_big(num: &BigUint) -> (Vec<(BigUint, u32)>, BigUint) {
+    //! Finds the prime factorization of a number of any size.
+    //!
+    //! Uses trial division, Brent's rho and ECM for factors of up to `20` digits, and SIQS for composites of up to 140
+    //! bits (about 42 digits) whatever the size of their factors. Pieces that fit in a `u128` go to `factorize()`
+    //! instead. A composite past 140 bits whose factors are all larger than 20 digits is not split, and is returned
+    //! as the cofactor instead.
+    //!
+    //! __Arguments:__
+    //!
+    //! 1. `num: &BigUint` - The number to factorize.
+    //!
+    //! __Returns:__
+    //!
+    //! 1. `Vec<(BigUint, u32)>` - The prime factors found, as `(prime, exponent)` pairs in increasing order; empty for `0` and `1`.
+    //! 2. `BigUint` - The part of the number that could not be split, which is `1` once the factorization is complete.
+    //!
+    //! __Example:__
+    //!
+    //! ```rust
+    //! use prime_checker::BigUint;
+    //!
+    //! // 2^128 + 1 = 59649589127497217 * 5704689200685129054721
+    //! let one: BigUint = BigUint::from(1);
+    //! let (factors, cofactor) = prime_checker::factorize_big(&one.shl(128).add(&one));
+    //!
+    //! assert_eq!(factors[0].0.to_string(), "59649589127497217");
+    //! assert_eq!(factors[1].0.to_string(), "5704689200685129054721");
+    //! assert_eq!(cofactor, one);
+    //! ```
+    //!
+    let result: (Vec<(BigUint, u32)>, BigUint) =
+        libs::bignum::factorize_big(num, libs::bignum::DEFAULT_ECM_DIGITS);
+    return result;
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,414 @@
+//! Arithmetic functions of a number, computed from its prime factorization.
+//!
+//! Each function has a single-value version, driven by `PrimeInt::factorize`, and a sieve version that builds a
+//! table for every number up to a limit in one pass. `0` has infinitely many divisors, so every function returns
+//! `0` for it.
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::libs::modular;
+use crate::libs::primality;
+use crate::libs::prime_int::PrimeInt;
+
+pub fn divisor_count(num: u64) -> u64 {
+    //! Counts the divisors of a given number, `tau(n) = sigma_0(n)`.
+    if num == 0 {
+        return 0;
+    }
+
+    let mut n_divisors: u64 = 1;
+    for (_, exponent) in num.factorize() {
+        n_divisors = n_divisors * (exponent as u64 + 1);
+    }
+    return n_divisors;
+}
+
+pub fn divisor_sum(num: u64) -> u128 {
+    //! Sums the divisors of a given number, `sigma(n) = sigma_1(n)`.
+    //!
+    //! `sigma(n) / n` grows like `e^gamma ln ln n` (Robin's bound `e^gamma ln ln n + 0.6483 / ln ln n`, for `n >= 3`),
+    //! which stays below `7` for every `u64`; the sum is therefore below `2^67`, so this cannot overflow a `u128`.
+    let sum: u128 = sigma_k(num, 1).unwrap();
+    return sum;
+}
+
+pub fn sigma_k(num: u64, k: u32) -> Option<u128> {
+    //! Sums the `k`-th powers of the divisors of a given number, `sigma_k(n)`.
+    //!
+    //! Returns `None` if the sum does not fit in a `u128`.
+    if num == 0 {
+        return Some(0);
+    }
+
+    let mut sum: u128 = 1;
+    for (prime, exponent) in num.factorize() {
+        // sigma_k(p^e) = 1 + p^k + p^2k + ... + p^ek
+        let prime_k: u128 = (prime as u128).checked_pow(k)?;
+        let mut power: u128 = 1;
+        let mut prime_sum: u128 = 1;
+        for _ in 0..exponent {
+            power = power.checked_mul(prime_k)?;
+            prime_sum = prime_sum.checked_add(power)?;
+        }
+        sum = sum.checked_mul(prime_sum)?;
+    }
+    return Some(sum);
+}
+
+pub fn omega(num: u64) -> u32 {
+    //! Counts the distinct prime factors of a given number, `omega(n)`.
+    return num.factorize().len() as u32;
+}
+
+pub fn big_omega(num: u64) -> u32 {
+    //! Counts the prime factors of a given number with multiplicity, `Omega(n)`.
+    let n_factors: u32 = num.factorize().iter().map(|(_, exponent)| exponent).sum();
+    return n_factors;
+}
+
+pub fn divisor_count_sieve(limit: u64) -> Vec<u64> {
+    //! Builds a table of `tau(n)` for every `n` from `0` till `limit`.
+    let limit: usize = limit as usize;
+    let mut table: Vec<u64> = vec![0; limit + 1];
+    for divisor in 1..=limit {
+        let mut multiple: usize = divisor;
+        while multiple <= limit {
+            table[multiple] = table[multiple] + 1;
+            multiple = multiple + divisor;
+        }
+    }
+    return table;
+}
+
+pub fn divisor_sum_sieve(limit: u64) -> Vec<u128> {
+    //! Builds a table of `sigma(n)` for every `n` from `0` till `limit`.
+    let table: Vec<u128> = sigma_k_sieve(limit, 1).unwrap();
+    return table;
+}
+
+pub fn sigma_k_sieve(limit: u64, k: u32) -> Option<Vec<u128>> {
+    //! Builds a table of `sigma_k(n)` for every `n` from `0` till `limit`.
+    //!
+    //! Returns `None` if any of the sums does not fit in a `u128`.
+    let limit: usize = limit as usize;
+    let mut table: Vec<u128> = vec![0; limit + 1];
+    for divisor in 1..=limit {
+        let divisor_k: u128 = (divisor as u128).checked_pow(k)?;
+        let mut multiple: usize = divisor;
+        while multiple <= limit {
+            table[multiple] = table[multiple].checked_add(divisor_k)?;
+            multiple = multiple + divisor;
+        }
+    }
+    return Some(table);
+}
+
+pub fn omega_sieve(limit: u64) -> Vec<u32> {
+    //! Builds a table of `omega(n)` for every `n` from `0` till `limit`.
+    let limit: usize = limit as usize;
+    let mut table: Vec<u32> = vec![0; limit + 1];
+    for prime in 2..=limit {
+        if table[prime] != 0 {
+            // Already marked by a smaller prime, so not prime.
+            continue;
+        }
+        let mut multiple: usize = prime;
+        while multiple <= limit {
+            table[multiple] = table[multiple] + 1;
+            multiple = multiple + prime;
+        }
+    }
+    return table;
+}
+
+pub fn big_omega_sieve(limit: u64) -> Vec<u32> {
+    //! Builds a table of `Omega(n)` for every `n` from `0` till `limit`.
+    let limit: usize = limit as usize;
+    let mut table: Vec<u32> = vec![0; limit + 1];
+    for prime in 2..=limit {
+        if table[prime] != 0 {
+            continue;
+        }
+        // Every power of the prime adds one more factor to its multiples.
+        let mut power: usize = prime;
+        loop {
+            let mut multiple: usize = power;
+            while multiple <= limit {
+                table[multiple] = table[multiple] + 1;
+                multiple = multiple + power;
+            }
+            match power.checked_mul(prime) {
+                Some(next) if next <= limit => power = next,
+                _ => break,
+            }
+        }
+    }
+    return table;
+}
+
+pub fn totient(num: u64) -> u64 {
+    //! Counts the numbers till `n` that are coprime to it; Euler's totient, `phi(n)`.
+    if num == 0 {
+        return 0;
+    }
+
+    let mut result: u64 = num;
+    for (prime, _) in num.factorize() {
+        result = result / prime * (prime - 1);
+    }
+    return result;
+}
+
+fn carmichael_prime_power(prime: u64, exponent: u32) -> u64 {
+    // lambda(p^e) is phi(p^e), except for powers of 2 above 4, where it is half of that.
+    let phi: u64 = prime.pow(exponent - 1) * (prime - 1);
+    if prime == 2 && exponent >= 3 {
+        return phi / 2;
+    }
+    return phi;
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    // Least common multiple; neither argument may be 0.
+    return a / modular::gcd_u128(a as u128, b as u128) as u64 * b;
+}
+
+pub fn carmichael(num: u64) -> u64 {
+    //! Finds the exponent of the multiplicative group modulo `n`; Carmichael's function, `lambda(n)`.
+    if num == 0 {
+        return 0;
+    }
+
+    let mut result: u64 = 1;
+    for (prime, exponent) in num.factorize() {
+        result = lcm(result, carmichael_prime_power(prime, exponent));
+    }
+    return result;
+}
+
+pub fn mobius(num: u64) -> i8 {
+    //! Finds the Mobius function `mu(n)`: `0` if `n` has a squared prime factor, otherwise `(-1)^omega(n)`.
+    if num == 0 {
+        return 0;
+    }
+
+    let mut result: i8 = 1;
+    for (_, exponent) in num.factorize() {
+        if exponent > 1 {
+            return 0;
+        }
+        result = -result;
+    }
+    return result;
+}
+
+fn linear_sieve(limit: usize) -> (Vec<u32>, Vec<u64>) {
+    // Linear sieve: returns the smallest prime factor of every number till `limit`, and the list of primes.
+    // Every composite is crossed off exactly once, by its smallest prime factor.
+    let mut smallest_factors: Vec<u32> = vec![0; limit + 1];
+    let mut primes: Vec<u64> = Vec::new();
+    for i in 2..=limit {
+        if smallest_factors[i] == 0 {
+            smallest_factors[i] = i as u32;
+            primes.push(i as u64);
+        }
+        for prime in primes.iter() {
+            let prime: usize = *prime as usize;
+            if prime > smallest_factors[i] as usize || i * prime > limit {
+                break;
+            }
+            smallest_factors[i * prime] = prime as u32;
+        }
+    }
+    return (smallest_factors, primes);
+}
+
+pub fn totient_sieve(limit: u64) -> Vec<u64> {
+    //! Builds a table of `phi(n)` for every `n` from `0` till `limit`, with a linear sieve.
+    let limit: usize = limit as usize;
+    let (smallest_factors, _) = linear_sieve(limit);
+    let mut table: Vec<u64> = vec![0; limit + 1];
+    if limit >= 1 {
+        table[1] = 1;
+    }
+    for i in 2..=limit {
+        let prime: usize = smallest_factors[i] as usize;
+        let rest: usize = i / prime;
+        // phi(p * m) is phi(m) * p if p divides m, and phi(m) * (p - 1) otherwise.
+        table[i] = if rest % prime == 0 {
+            table[rest] * prime as u64
+        } else {
+            table[rest] * (prime as u64 - 1)
+        };
+    }
+    return table;
+}
+
+pub fn carmichael_sieve(limit: u64) -> Vec<u64> {
+    //! Builds a table of `lambda(n)` for every `n` from `0` till `limit`, with a linear sieve.
+    let limit: usize = limit as usize;
+    let (smallest_factors, _) = linear_sieve(limit);
+    let mut table: Vec<u64> = vec![0; limit + 1];
+    if limit >= 1 {
+        table[1] = 1;
+    }
+    for i in 2..=limit {
+        // Split `i` into the full power of its smallest prime factor and the coprime rest.
+        let prime: usize = smallest_factors[i] as usize;
+        let mut rest: usize = i;
+        let mut exponent: u32 = 0;
+        while rest % prime == 0 {
+            rest = rest / prime;
+            exponent = exponent + 1;
+        }
+        table[i] = lcm(table[rest], carmichael_prime_power(prime as u64, exponent));
+    }
+    return table;
+}
+
+pub fn mobius_sieve(limit: u64) -> Vec<i8> {
+    //! Builds a table of `mu(n)` for every `n` from `0` till `limit`, with a linear sieve.
+    let limit: usize = limit as usize;
+    let (smallest_factors, _) = linear_sieve(limit);
+    let mut table: Vec<i8> = vec![0; limit + 1];
+    if limit >= 1 {
+        table[1] = 1;
+    }
+    for i in 2..=limit {
+        let prime: usize = smallest_factors[i] as usize;
+        let rest: usize = i / prime;
+        table[i] = if rest % prime == 0 { 0 } else { -table[rest] };
+    }
+    return table;
+}
+
+fn icbrt(num: u64) -> u64 {
+    // Integer cube root, `floor(cbrt(num))`.
+    let mut root: u64 = 0;
+    let mut bit: u64 = 1 << 21; // cbrt(2^64) < 2^22
+    while bit > 0 {
+        let candidate: u128 = (root | bit) as u128;
+        if candidate * candidate * candidate <= num as u128 {
+            root = root | bit;
+        }
+        bit = bit >> 1;
+    }
+    return root;
+}
+
+pub fn totient_summatory(num: u64) -> u128 {
+    //! Sums `phi(n)` for every `n` till `num`; the totient summatory function, `Phi(x)`.
+    //!
+    //! Uses the identity `Phi(x) = x(x + 1)/2 - sum(Phi(x / d) for d in 2..=x)`, grouping the `d`s that share a
+    //! quotient as in Dirichlet's hyperbola method. Values of `Phi` below `x^(2/3)` come from a totient sieve, so
+    //! the whole computation takes `O(x^(2/3))` time and memory.
+    if num == 0 {
+        return 0;
+    }
+
+    // Prefix sums of the totient, up to the sieve limit.
+    let sieve_limit: u64 =
+        core::cmp::max(icbrt(num).pow(2), primality::isqrt_u128(num as u128) as u64);
+    let mut small: Vec<u64> = totient_sieve(sieve_limit);
+    for i in 1..small.len() {
+        small[i] = small[i] + small[i - 1];
+    }
+
+    // `large[k]` holds `Phi(num / k)` for every `k` with `num / k` above the sieve limit.
+    let n_large: usize = (num / (sieve_limit + 1)) as usize;
+    let mut large: Vec<u128> = vec![0; n_large + 1];
+    for k in (1..=n_large).rev() {
+        let value: u64 = num / k as u64;
+        let mut result: u128 = value as u128 * (value as u128 + 1) / 2;
+        let mut d: u64 = 2;
+        while d <= value {
+            let quotient: u64 = value / d;
+            let last_d: u64 = value / quotient; // Last `d` with the same quotient.
+            let phi_quotient: u128 = if quotient <= sieve_limit {
+                small[quotient as usize] as u128
+            } else {
+                large[k * d as usize]
+            };
+            result = result - (last_d - d + 1) as u128 * phi_quotient;
+            d = last_d + 1;
+        }
+        large[k] = result;
+    }
+
+    if n_large >= 1 {
+        return large[1];
+    }
+    return small[num as usize] as u128;
+}
+
+pub fn divisor_sum_segment(low: u64, high: u64) -> Vec<u128> {
+    //! Builds a table of `sigma(n)` for every `n` from `low` till `high`, indexed by `n - low`.
+    //!
+    //! A segmented sieve: every divisor `d` up to `sqrt(high)` is added to its multiples `m >= d^2` in the range,
+    //! together with the paired divisor `m / d`, so the table never needs the numbers below `low`.
+    if low > high {
+        return Vec::new();
+    }
+
+    let mut table: Vec<u128> = vec![0; (high - low) as usize + 1];
+    let root: u64 = primality::isqrt_u128(high as u128) as u64;
+    for divisor in 1..=root {
+        let square: u64 = divisor * divisor;
+        // First multiple of `divisor` that is both in the range and at least `divisor^2`.
+        let mut multiple: u64 = if square >= low {
+            square
+        } else {
+            let quotient: u64 = low / divisor + if low % divisor == 0 { 0 } else { 1 };
+            match quotient.checked_mul(divisor) {
+                Some(multiple) => multiple,
+                None => continue,
+            }
+        };
+        while multiple <= high {
+            let index: usize = (multiple - low) as usize;
+            let pair: u64 = multiple / divisor;
+            table[index] = table[index] + divisor as u128;
+            if pair != divisor {
+                table[index] = table[index] + pair as u128;
+            }
+            multiple = match multiple.checked_add(divisor) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    }
+    return table;
+}
+
+pub fn divisor_count_segment(low: u64, high: u64) -> Vec<u64> {
+    //! Builds a table of `tau(n)` for every `n` from `low` till `high`, indexed by `n - low`.
+    //!
+    //! The counting counterpart of `divisor_sum_segment`.
+    if low > high {
+        return Vec::new();
+    }
+
+    let mut table: Vec<u64> = vec![0; (high - low) as usize + 1];
+    let root: u64 = primality::isqrt_u128(high as u128) as u64;
+    for divisor in 1..=root {
+        let square: u64 = divisor * divisor;
+        let mut multiple: u64 = if square >= low {
+            square
+        } else {
+            let quotient: u64 = low / divisor + if low % divisor == 0 { 0 } else { 1 };
+            match quotient.checked_mul(divisor) {
+                Some(multiple) => multiple,
+                None => continue,
+            }
+        };
+        while multiple <= high {
+            let index: usize = (multiple - low) as usize;
+            table[index] = table[index] + if multiple / divisor == divisor { 1 } else { 2 };
+            multiple = match multiple.checked_add(divisor) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    }
+    return table;
+}
@@ -0,0 +1,575 @@
+//! A small arbitrary-precision unsigned integer, for the computations that outgrow `u128`.
+//!
+//! Numbers are stored as little-endian `u64` limbs with no trailing zero limbs, so zero is the empty vector.
+//! Multiplication and squaring use the schoolbook method for short numbers and Karatsuba above
+//! `KARATSUBA_THRESHOLD` limbs; division uses Knuth's algorithm D.
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+/// Below this many limbs, schoolbook multiplication beats Karatsuba.
+const KARATSUBA_THRESHOLD: usize = 48;
+
+/// `10^19`, the largest power of ten that fits in a limb; decimal strings are converted 19 digits at a time.
+const DECIMAL_CHUNK: u64 = 10_000_000_000_000_000_000;
+
+/// The error returned when a string is not a decimal number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseBigUintError;
+
+impl fmt::Display for ParseBigUintError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return formatter.write_str("the string is not a decimal number");
+    }
+}
+
+/// An arbitrary-precision unsigned integer; parse one from a decimal string, or build it from a `u128` or from limbs.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        //! The number zero.
+        return BigUint { limbs: Vec::new() };
+    }
+
+    pub fn from_u128(num: u128) -> BigUint {
+        //! Converts a `u128` into a `BigUint`.
+        return BigUint::from_limbs(vec![num as u64, (num >> 64) as u64]);
+    }
+
+    pub fn from_limbs(limbs: Vec<u64>) -> BigUint {
+        //! Builds a `BigUint` from little-endian `u64` limbs.
+        let mut result: BigUint = BigUint { limbs };
+        result.normalize();
+        return result;
+    }
+
+    pub fn power_of_two_minus_one(bits: u32) -> BigUint {
+        //! Builds `2^bits - 1`, i.e. the number with its lowest `bits` bits set.
+        let mut limbs: Vec<u64> = vec![u64::MAX; (bits / 64) as usize];
+        if bits % 64 != 0 {
+            limbs.push((1_u64 << (bits % 64)) - 1);
+        }
+        return BigUint::from_limbs(limbs);
+    }
+
+    #[cfg(any(feature = "bignum", test))]
+    pub fn limbs(&self) -> &[u64] {
+        //! The little-endian `u64` limbs of the number.
+        return &self.limbs;
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.limbs.is_empty();
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        //! Converts back to a `u128`, if the number fits.
+        return match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0] as u128),
+            2 => Some(self.limbs[0] as u128 | (self.limbs[1] as u128) << 64),
+            _ => None,
+        };
+    }
+
+    pub fn bits(&self) -> u64 {
+        //! The number of significant bits, which is `0` for zero.
+        return match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * 64 - top.leading_zeros() as u64,
+            None => 0,
+        };
+    }
+
+    pub fn add(&self, other: &BigUint) -> BigUint {
+        //! Computes `self + other`.
+        return BigUint::from_limbs(add_slices(&self.limbs, &other.limbs));
+    }
+
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        //! Computes `self - other`, or `None` if `other` is larger.
+        if *self < *other {
+            return None;
+        }
+        let mut limbs: Vec<u64> = self.limbs.clone();
+        sub_assign(&mut limbs, &other.limbs);
+        return Some(BigUint::from_limbs(limbs));
+    }
+
+    pub fn mul(&self, other: &BigUint) -> BigUint {
+        //! Computes `self * other`.
+        return BigUint::from_limbs(mul_slices(&self.limbs, &other.limbs));
+    }
+
+    pub fn square(&self) -> BigUint {
+        //! Computes `self * self`, which is about twice as fast as a general multiplication.
+        return BigUint::from_limbs(square_slice(&self.limbs));
+    }
+
+    #[cfg(any(feature = "bignum", test))]
+    pub fn shl(&self, shift: u64) -> BigUint {
+        //! Computes `self << shift`.
+        if self.is_zero() {
+            return BigUint::zero();
+        }
+        let limb_shift: usize = (shift / 64) as usize;
+        let bit_shift: u32 = (shift % 64) as u32;
+        let mut limbs: Vec<u64> = vec![0; limb_shift];
+        if bit_shift == 0 {
+            limbs.extend_from_slice(&self.limbs);
+        } else {
+            let mut carry: u64 = 0;
+            for limb in self.limbs.iter() {
+                limbs.push(limb << bit_shift | carry);
+                carry = limb >> (64 - bit_shift);
+            }
+            limbs.push(carry);
+        }
+        return BigUint::from_limbs(limbs);
+    }
+
+    pub fn shr(&self, shift: u64) -> BigUint {
+        //! Computes `self >> shift`.
+        let limb_shift: usize = (shift / 64) as usize;
+        if limb_shift >= self.limbs.len() {
+            return BigUint::zero();
+        }
+        let bit_shift: u32 = (shift % 64) as u32;
+        let high: &[u64] = &self.limbs[limb_shift..];
+        if bit_shift == 0 {
+            return BigUint::from_limbs(high.to_vec());
+        }
+        let mut limbs: Vec<u64> = Vec::with_capacity(high.len());
+        for index in 0..high.len() {
+            let next: u64 = match high.get(index + 1) {
+                Some(next) => next << (64 - bit_shift),
+                None => 0,
+            };
+            limbs.push(high[index] >> bit_shift | next);
+        }
+        return BigUint::from_limbs(limbs);
+    }
+
+    pub fn low_bits(&self, bits: u64) -> BigUint {
+        //! Computes `self % 2^bits`.
+        let full_limbs: usize = (bits / 64) as usize;
+        if full_limbs >= self.limbs.len() {
+            return self.clone();
+        }
+        let mut limbs: Vec<u64> = self.limbs[..full_limbs].to_vec();
+        if bits % 64 != 0 {
+            limbs.push(self.limbs[full_limbs] & ((1_u64 << (bits % 64)) - 1));
+        }
+        return BigUint::from_limbs(limbs);
+    }
+
+    pub fn is_odd(&self) -> bool {
+        return self.limbs.first().map_or(false, |limb| limb % 2 == 1);
+    }
+
+    #[cfg(any(feature = "bignum", test))]
+    pub fn bit(&self, index: u64) -> bool {
+        //! Whether bit `index` (counting from the least significant) is set.
+        return match self.limbs.get((index / 64) as usize) {
+            Some(limb) => (limb >> (index % 64)) & 1 == 1,
+            None => false,
+        };
+    }
+
+    #[cfg(any(feature = "bignum", test))]
+    pub fn trailing_zeros(&self) -> u64 {
+        //! The number of trailing zero bits, which is `0` for zero.
+        for (index, limb) in self.limbs.iter().enumerate() {
+            if *limb != 0 {
+                return index as u64 * 64 + limb.trailing_zeros() as u64;
+            }
+        }
+        return 0;
+    }
+
+    pub fn div_rem(&self, divisor: &BigUint) -> (BigUint, BigUint) {
+        //! Computes `(self / divisor, self % divisor)`; panics if the divisor is zero.
+        if divisor.is_zero() {
+            panic!("Attempted to divide a `BigUint` by zero.");
+        }
+        if *self < *divisor {
+            return (BigUint::zero(), self.clone());
+        }
+        if divisor.limbs.len() == 1 {
+            let (quotient, remainder): (BigUint, u64) = self.div_rem_u64(divisor.limbs[0]);
+            return (quotient, BigUint::from_u128(remainder as u128));
+        }
+        let (quotient, remainder): (Vec<u64>, Vec<u64>) =
+            div_rem_slices(&self.limbs, &divisor.limbs);
+        return (
+            BigUint::from_limbs(quotient),
+            BigUint::from_limbs(remainder),
+        );
+    }
+
+    pub fn div_rem_u64(&self, divisor: u64) -> (BigUint, u64) {
+        //! Computes `(self / divisor, self % divisor)` for a single-limb divisor; panics if it is zero.
+        if divisor == 0 {
+            panic!("Attempted to divide a `BigUint` by zero.");
+        }
+        let mut quotient: Vec<u64> = vec![0; self.limbs.len()];
+        let mut remainder: u128 = 0;
+        for index in (0..self.limbs.len()).rev() {
+            let current: u128 = remainder << 64 | self.limbs[index] as u128;
+            quotient[index] = (current / divisor as u128) as u64;
+            remainder = current % divisor as u128;
+        }
+        return (BigUint::from_limbs(quotient), remainder as u64);
+    }
+
+    pub fn rem_u64(&self, divisor: u64) -> u64 {
+        //! Computes `self % divisor` for a single-limb divisor without building the quotient; panics if it is zero.
+        if divisor == 0 {
+            panic!("Attempted to divide a `BigUint` by zero.");
+        }
+        let mut remainder: u128 = 0;
+        for limb in self.limbs.iter().rev() {
+            remainder = (remainder << 64 | *limb as u128) % divisor as u128;
+        }
+        return remainder as u64;
+    }
+
+    pub fn gcd(&self, other: &BigUint) -> BigUint {
+        //! The greatest common divisor, with Euclid's algorithm; `gcd(0, 0) = 0`.
+        let mut a: BigUint = self.clone();
+        let mut b: BigUint = other.clone();
+        while !b.is_zero() {
+            let remainder: BigUint = a.div_rem(&b).1;
+            a = b;
+            b = remainder;
+        }
+        return a;
+    }
+
+    pub fn pow(&self, exponent: u32) -> BigUint {
+        //! Computes `self^exponent` by repeated squaring.
+        let mut result: BigUint = BigUint::from_u128(1);
+        let mut bit: u32 = 32 - exponent.leading_zeros();
+        while bit > 0 {
+            bit = bit - 1;
+            result = result.square();
+            if (exponent >> bit) & 1 == 1 {
+                result = result.mul(self);
+            }
+        }
+        return result;
+    }
+
+    fn normalize(&mut self) {
+        //! Drops the trailing zero limbs.
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        return self
+            .limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()));
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(num: u128) -> BigUint {
+        return BigUint::from_u128(num);
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        // Peels off 19 decimal digits at a time, from the bottom.
+        let mut chunks: Vec<u64> = Vec::new();
+        let mut rest: BigUint = self.clone();
+        while !rest.is_zero() {
+            let (quotient, chunk): (BigUint, u64) = rest.div_rem_u64(DECIMAL_CHUNK);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+        let mut digits: String = String::new();
+        match chunks.pop() {
+            Some(top) => digits.push_str(&alloc::format!("{top}")),
+            None => digits.push('0'),
+        }
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&alloc::format!("{chunk:019}"));
+        }
+        return formatter.pad_integral(true, "", &digits);
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(text: &str) -> Result<BigUint, ParseBigUintError> {
+        //! Parses a decimal number; digits may be separated by underscores, as in Rust literals.
+        let digits: Vec<u8> = text.bytes().filter(|byte| *byte != b'_').collect();
+        if digits.is_empty() || !digits.iter().all(|byte| byte.is_ascii_digit()) {
+            return Err(ParseBigUintError);
+        }
+        let mut result: BigUint = BigUint::zero();
+        for chunk in digits.chunks(19) {
+            let mut value: u64 = 0;
+            for digit in chunk {
+                value = value * 10 + (digit - b'0') as u64;
+            }
+            let scale: BigUint = BigUint::from_u128(10_u128.pow(chunk.len() as u32));
+            result = result.mul(&scale).add(&BigUint::from_u128(value as u128));
+        }
+        return Ok(result);
+    }
+}
+
+fn add_slices(a: &[u64], b: &[u64]) -> Vec<u64> {
+    //! Adds two limb slices, which need not be normalized.
+    let (long, short): (&[u64], &[u64]) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result: Vec<u64> = long.to_vec();
+    result.push(0);
+    add_assign_at(&mut result, short, 0);
+    return result;
+}
+
+fn add_assign_at(acc: &mut [u64], b: &[u64], offset: usize) {
+    //! Computes `acc += b << (64 * offset)`; `acc` must be long enough to hold the result.
+    let mut carry: bool = false;
+    let mut index: usize = offset;
+    for limb in b.iter() {
+        let (sum, overflow_1) = acc[index].overflowing_add(*limb);
+        let (sum, overflow_2) = sum.overflowing_add(carry as u64);
+        acc[index] = sum;
+        carry = overflow_1 || overflow_2;
+        index += 1;
+    }
+    while carry {
+        let (sum, overflow) = acc[index].overflowing_add(1);
+        acc[index] = sum;
+        carry = overflow;
+        index += 1;
+    }
+}
+
+fn sub_assign(acc: &mut [u64], b: &[u64]) {
+    //! Computes `acc -= b`; `acc` must be at least `b`.
+    let mut borrow: bool = false;
+    let mut index: usize = 0;
+    for limb in b.iter() {
+        let (difference, overflow_1) = acc[index].overflowing_sub(*limb);
+        let (difference, overflow_2) = difference.overflowing_sub(borrow as u64);
+        acc[index] = difference;
+        borrow = overflow_1 || overflow_2;
+        index += 1;
+    }
+    while borrow {
+        let (difference, overflow) = acc[index].overflowing_sub(1);
+        acc[index] = difference;
+        borrow = overflow;
+        index += 1;
+    }
+}
+
+fn mul_schoolbook(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut result: Vec<u64> = vec![0; a.len() + b.len()];
+    for (i, x) in a.iter().enumerate() {
+        let mut carry: u128 = 0;
+        for (j, y) in b.iter().enumerate() {
+            let product: u128 = (*x as u128) * (*y as u128) + result[i + j] as u128 + carry;
+            result[i + j] = product as u64;
+            carry = product >> 64;
+        }
+        result[i + b.len()] = carry as u64;
+    }
+    return result;
+}
+
+fn square_schoolbook(a: &[u64]) -> Vec<u64> {
+    //! Squares by computing each cross product `a[i] * a[j]` with `i < j` once, doubling, then adding the diagonal.
+    let mut result: Vec<u64> = vec![0; 2 * a.len()];
+    for i in 0..a.len() {
+        let mut carry: u128 = 0;
+        for j in (i + 1)..a.len() {
+            let product: u128 = (a[i] as u128) * (a[j] as u128) + result[i + j] as u128 + carry;
+            result[i + j] = product as u64;
+            carry = product >> 64;
+        }
+        result[i + a.len()] = carry as u64;
+    }
+
+    let mut carry: u64 = 0;
+    for limb in result.iter_mut() {
+        let next_carry: u64 = *limb >> 63;
+        *limb = *limb << 1 | carry;
+        carry = next_carry;
+    }
+
+    let mut carry: u128 = 0;
+    for i in 0..a.len() {
+        let square: u128 = (a[i] as u128) * (a[i] as u128);
+        let low: u128 = result[2 * i] as u128 + (square as u64) as u128 + carry;
+        result[2 * i] = low as u64;
+        let high: u128 = result[2 * i + 1] as u128 + (square >> 64) + (low >> 64);
+        result[2 * i + 1] = high as u64;
+        carry = high >> 64;
+    }
+    return result;
+}
+
+fn mul_slices(a: &[u64], b: &[u64]) -> Vec<u64> {
+    //! Multiplies two limb slices into a result of exactly `a.len() + b.len()` limbs.
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return mul_schoolbook(a, b);
+    }
+
+    // a * b = z2 * B^2 + z1 * B + z0, with z1 = (a0 + a1)(b0 + b1) - z0 - z2.
+    let half: usize = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(half.min(a.len()));
+    let (b0, b1) = b.split_at(half.min(b.len()));
+    let z0: Vec<u64> = mul_slices(a0, b0);
+    let z2: Vec<u64> = mul_slices(a1, b1);
+    let mut z1: Vec<u64> = mul_slices(&add_slices(a0, a1), &add_slices(b0, b1));
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    return combine(a.len() + b.len(), half, &z0, &z1, &z2);
+}
+
+fn square_slice(a: &[u64]) -> Vec<u64> {
+    //! Squares a limb slice into a result of exactly `2 * a.len()` limbs.
+    if a.len() < KARATSUBA_THRESHOLD {
+        return square_schoolbook(a);
+    }
+
+    let half: usize = a.len() / 2;
+    let (a0, a1) = a.split_at(half);
+    let z0: Vec<u64> = square_slice(a0);
+    let z2: Vec<u64> = square_slice(a1);
+    let mut z1: Vec<u64> = square_slice(&add_slices(a0, a1));
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    return combine(2 * a.len(), half, &z0, &z1, &z2);
+}
+
+fn combine(len: usize, half: usize, z0: &[u64], z1: &[u64], z2: &[u64]) -> Vec<u64> {
+    //! Computes `z2 * B^2 + z1 * B + z0` for `B = 2^(64 * half)`, into `len` limbs.
+    let mut result: Vec<u64> = vec![0; len + 1];
+    add_assign_at(&mut result, trim(z0), 0);
+    add_assign_at(&mut result, trim(z1), half);
+    add_assign_at(&mut result, trim(z2), 2 * half);
+    result.truncate(len);
+    return result;
+}
+
+fn div_rem_slices(a: &[u64], b: &[u64]) -> (Vec<u64>, Vec<u64>) {
+    //! Knuth's algorithm D, for a normalized divisor `b` of at least two limbs and `a >= b`: both are shifted so that
+    //! the top bit of `b` is set, which makes every estimated quotient limb at most two too large.
+    let shift: u32 = b[b.len() - 1].leading_zeros();
+    let divisor: Vec<u64> = shift_left_limbs(b, shift, b.len());
+    let mut remainder: Vec<u64> = shift_left_limbs(a, shift, a.len() + 1);
+    let n: usize = divisor.len();
+    let m: usize = a.len() - n;
+    let top: u128 = divisor[n - 1] as u128;
+    let next: u128 = divisor[n - 2] as u128;
+    let mut quotient: Vec<u64> = vec![0; m + 1];
+
+    for j in (0..=m).rev() {
+        // Estimate the quotient limb from the top two limbs, then correct it with the third.
+        let numerator: u128 = (remainder[j + n] as u128) << 64 | remainder[j + n - 1] as u128;
+        let mut estimate: u128 = numerator / top;
+        let mut rest: u128 = numerator % top;
+        while estimate > u64::MAX as u128
+            || estimate * next > (rest << 64 | remainder[j + n - 2] as u128)
+        {
+            estimate = estimate - 1;
+            rest = rest + top;
+            if rest > u64::MAX as u128 {
+                break;
+            }
+        }
+
+        // Subtract `estimate * divisor` at limb `j`; if that went below zero, add one `divisor` back.
+        let mut borrow: i128 = 0;
+        let mut carry: u128 = 0;
+        for i in 0..n {
+            let product: u128 = estimate * divisor[i] as u128 + carry;
+            carry = product >> 64;
+            let difference: i128 = remainder[i + j] as i128 - (product as u64) as i128 + borrow;
+            remainder[i + j] = difference as u64;
+            borrow = difference >> 64;
+        }
+        let difference: i128 = remainder[j + n] as i128 - carry as i128 + borrow;
+        remainder[j + n] = difference as u64;
+        if difference < 0 {
+            estimate = estimate - 1;
+            let mut carry: bool = false;
+            for i in 0..n {
+                let (sum, overflow_1) = remainder[i + j].overflowing_add(divisor[i]);
+                let (sum, overflow_2) = sum.overflowing_add(carry as u64);
+                remainder[i + j] = sum;
+                carry = overflow_1 || overflow_2;
+            }
+            remainder[j + n] = remainder[j + n].wrapping_add(carry as u64);
+        }
+        quotient[j] = estimate as u64;
+    }
+
+    // Undo the shift on the remainder.
+    remainder.truncate(n);
+    if shift > 0 {
+        for i in 0..n {
+            let high: u64 = if i + 1 < n {
+                remainder[i + 1] << (64 - shift)
+            } else {
+                0
+            };
+            remainder[i] = remainder[i] >> shift | high;
+        }
+    }
+    return (quotient, remainder);
+}
+
+fn shift_left_limbs(limbs: &[u64], shift: u32, len: usize) -> Vec<u64> {
+    //! Shifts a limb slice left by fewer than 64 bits, into exactly `len` limbs.
+    let mut result: Vec<u64> = vec![0; len];
+    let mut carry: u64 = 0;
+    for (index, limb) in limbs.iter().enumerate() {
+        result[index] = if shift == 0 {
+            *limb
+        } else {
+            limb << shift | carry
+        };
+        carry = if shift == 0 { 0 } else { limb >> (64 - shift) };
+    }
+    if limbs.len() < len {
+        result[limbs.len()] = carry;
+    }
+    return result;
+}
+
+fn trim(limbs: &[u64]) -> &[u64] {
+    //! Drops the trailing zero limbs of a slice, so it can be added into a shorter buffer.
+    let mut len: usize = limbs.len();
+    while len > 0 && limbs[len - 1] == 0 {
+        len -= 1;
+    }
+    return &limbs[..len];
+}
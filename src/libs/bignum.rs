@@ -0,0 +1,664 @@
+//! Primality tests, prime search and factorization for numbers of any size, on `BigUint`.
+//!
+//! Numbers that fit in a `u128` are handed to the fixed-width code. Larger ones get:
+//!
+//! 1. `check_if_prime_big` - Trial division by the primes below `1000`, then Baillie-PSW: a Miller-Rabin round to
+//!    base `2` and a strong Lucas test. No counterexample is known; `check_if_prime_big_rounds` adds Miller-Rabin rounds
+//!    to random bases on top for callers who want a bound on the error as well.
+//! 2. `find_next_prime_big` - Steps through the odd numbers past the start, skipping those with a prime factor below
+//!    `1000` by keeping their residues, and runs Baillie-PSW on the rest.
+//! 3. `factorize_big` - Trial division, perfect powers, a bounded Brent rho run, SIQS till `siqs::SIQS_MAXIMUM_BITS`
+//!    and ECM till `DEFAULT_ECM_DIGITS`. Rho and ECM are the `u128` implementations, run through `MontgomeryContext`,
+//!    and SIQS keeps `N` in a `BigUint` either way.
+//!
+//! All modular arithmetic is done in Montgomery form with the coarsely integrated operand scanning (CIOS) method.
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::libs::bigint::BigUint;
+use crate::libs::ecm::{self, EcmParams, MontgomeryContext, DIGIT_LEVELS};
+use crate::libs::factorization;
+use crate::libs::modular;
+use crate::libs::primality;
+use crate::libs::prime_int::PrimeInt;
+use crate::libs::primes;
+use crate::libs::random::RandomSource;
+use crate::libs::siqs;
+use crate::libs::tables;
+
+/// The primes below `1000`, for trial division and for sieving the candidates of `find_next_prime_big`.
+const SMALL_PRIMES: [u64; 168] = tables::prime_table::<168>();
+
+/// `factorize_big` divides out every prime below this bound before trying anything else.
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 16;
+
+/// Brent's rho gives up once its cycle length passes this, which finds most factors below about `2^36`.
+const RHO_CYCLE_LIMIT: u128 = 1 << 16;
+
+/// `factorize_big` runs ECM for factors of up to this many decimal digits; anything it cannot split by then is
+/// returned as the unfactored cofactor.
+pub const DEFAULT_ECM_DIGITS: u32 = 20;
+
+/// A number that can be tested for primality, stepped to the next prime and factorized.
+///
+/// Implemented for every `PrimeInt` type and for `BigUint`, so that code can be written once for both.
+pub trait Primality: Sized {
+    /// Checks to see if the number is a prime number.
+    fn check_prime(&self) -> bool;
+
+    /// Finds the smallest prime larger than the number, or `None` if it does not fit in the type.
+    fn next_prime(&self) -> Option<Self>;
+
+    /// Finds the prime factors of the number, as `(prime, exponent)` pairs in increasing order, together with the
+    /// part that could not be split (`1` once the factorization is complete).
+    fn prime_factors(&self) -> (Vec<(Self, u32)>, Self);
+}
+
+impl<T: PrimeInt> Primality for T {
+    fn check_prime(&self) -> bool {
+        return self.is_prime();
+    }
+
+    fn next_prime(&self) -> Option<T> {
+        let prime: u128 = find_next_prime_u128(self.to_u128())?;
+        if T::from_u128(prime).to_u128() != prime {
+            return None;
+        }
+        return Some(T::from_u128(prime));
+    }
+
+    fn prime_factors(&self) -> (Vec<(T, u32)>, T) {
+        return (self.factorize(), T::from_u128(1));
+    }
+}
+
+impl Primality for BigUint {
+    fn check_prime(&self) -> bool {
+        return check_if_prime_big(self);
+    }
+
+    fn next_prime(&self) -> Option<BigUint> {
+        return Some(find_next_prime_big(self));
+    }
+
+    fn prime_factors(&self) -> (Vec<(BigUint, u32)>, BigUint) {
+        return factorize_big(self, DEFAULT_ECM_DIGITS);
+    }
+}
+
+/// Arithmetic modulo an odd `n > 1` in Montgomery form, with `R = 2^(64k)` for a `k`-limb modulus.
+///
+/// Residues are kept as exactly `k` little-endian limbs, below `n`.
+struct Montgomery {
+    modulus: Vec<u64>,
+    /// `-n^(-1)` modulo `2^64`.
+    n_prime: u64,
+    /// `R^2` modulo `n`, for converting into Montgomery form.
+    r_squared: Vec<u64>,
+    /// `R` modulo `n`, the Montgomery form of `1`.
+    one: Vec<u64>,
+}
+
+impl Montgomery {
+    fn new(modulus: &BigUint) -> Option<Montgomery> {
+        if !modulus.is_odd() || modulus.bits() < 2 {
+            return None;
+        }
+        let limbs: usize = modulus.limbs().len();
+        // Newton's iteration doubles the correct low bits of the inverse each time: 1, 2, 4, ..., 64.
+        let low: u64 = modulus.limbs()[0];
+        let mut inverse: u64 = 1;
+        for _ in 0..6 {
+            inverse = inverse.wrapping_mul(2_u64.wrapping_sub(low.wrapping_mul(inverse)));
+        }
+
+        let one: BigUint = BigUint::from_u128(1);
+        let r: BigUint = one.shl(64 * limbs as u64).div_rem(modulus).1;
+        let r_squared: BigUint = one.shl(128 * limbs as u64).div_rem(modulus).1;
+        return Some(Montgomery {
+            modulus: modulus.limbs().to_vec(),
+            n_prime: inverse.wrapping_neg(),
+            r_squared: pad(&r_squared, limbs),
+            one: pad(&r, limbs),
+        });
+    }
+
+    fn modulus(&self) -> BigUint {
+        return BigUint::from_limbs(self.modulus.clone());
+    }
+
+    fn zero(&self) -> Vec<u64> {
+        return vec![0; self.modulus.len()];
+    }
+
+    fn to_montgomery(&self, a: &BigUint) -> Vec<u64> {
+        let reduced: BigUint = a.div_rem(&self.modulus()).1;
+        return self.mul(&pad(&reduced, self.modulus.len()), &self.r_squared);
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_montgomery(&self, a: &[u64]) -> BigUint {
+        let mut one: Vec<u64> = self.zero();
+        one[0] = 1;
+        return BigUint::from_limbs(self.mul(a, &one));
+    }
+
+    fn mul(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        // CIOS: interleaves adding `a * b[i]` with adding the multiple of `n` that clears the lowest limb, so the
+        // running total never grows past `k + 2` limbs.
+        let limbs: usize = self.modulus.len();
+        let mut t: Vec<u64> = vec![0; limbs + 2];
+        for b_limb in b.iter() {
+            let mut carry: u128 = 0;
+            for j in 0..limbs {
+                let sum: u128 = t[j] as u128 + a[j] as u128 * *b_limb as u128 + carry;
+                t[j] = sum as u64;
+                carry = sum >> 64;
+            }
+            let sum: u128 = t[limbs] as u128 + carry;
+            t[limbs] = sum as u64;
+            t[limbs + 1] = (sum >> 64) as u64;
+
+            let m: u64 = t[0].wrapping_mul(self.n_prime);
+            let mut carry: u128 = (t[0] as u128 + m as u128 * self.modulus[0] as u128) >> 64;
+            for j in 1..limbs {
+                let sum: u128 = t[j] as u128 + m as u128 * self.modulus[j] as u128 + carry;
+                t[j - 1] = sum as u64;
+                carry = sum >> 64;
+            }
+            let sum: u128 = t[limbs] as u128 + carry;
+            t[limbs - 1] = sum as u64;
+            t[limbs] = t[limbs + 1] + (sum >> 64) as u64;
+        }
+
+        let overflow: bool = t[limbs] != 0;
+        t.truncate(limbs);
+        if overflow || !is_below(&t, &self.modulus) {
+            sub_in_place(&mut t, &self.modulus);
+        }
+        return t;
+    }
+
+    fn add(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let mut sum: Vec<u64> = a.to_vec();
+        let carry: bool = add_in_place(&mut sum, b);
+        if carry || !is_below(&sum, &self.modulus) {
+            sub_in_place(&mut sum, &self.modulus);
+        }
+        return sum;
+    }
+
+    fn sub(&self, a: &[u64], b: &[u64]) -> Vec<u64> {
+        let mut difference: Vec<u64> = a.to_vec();
+        if sub_in_place(&mut difference, b) {
+            add_in_place(&mut difference, &self.modulus);
+        }
+        return difference;
+    }
+
+    fn half(&self, a: &[u64]) -> Vec<u64> {
+        // `a / 2` for even `a` and `(a + n) / 2` for odd `a`; halving commutes with the Montgomery form.
+        let mut result: Vec<u64> = a.to_vec();
+        let carry: bool = if a[0] % 2 == 1 {
+            add_in_place(&mut result, &self.modulus)
+        } else {
+            false
+        };
+        let limbs: usize = result.len();
+        for i in 0..limbs {
+            let high: u64 = if i + 1 < limbs {
+                result[i + 1] << 63
+            } else {
+                (carry as u64) << 63
+            };
+            result[i] = result[i] >> 1 | high;
+        }
+        return result;
+    }
+
+    fn pow(&self, base: &[u64], exponent: &BigUint) -> Vec<u64> {
+        let mut result: Vec<u64> = self.one.clone();
+        let mut bit: u64 = exponent.bits();
+        while bit > 0 {
+            bit = bit - 1;
+            result = self.mul(&result, &result);
+            if exponent.bit(bit) {
+                result = self.mul(&result, base);
+            }
+        }
+        return result;
+    }
+
+    fn gcd(&self, a: &[u64]) -> BigUint {
+        // `R` is coprime to `n`, so the gcd can be taken in Montgomery form.
+        return BigUint::from_limbs(a.to_vec()).gcd(&self.modulus());
+    }
+}
+
+impl MontgomeryContext for Montgomery {
+    type Residue = Vec<u64>;
+    type Int = BigUint;
+
+    fn modulus(&self) -> BigUint {
+        return Montgomery::modulus(self);
+    }
+
+    fn residue(&self, value: u64) -> Vec<u64> {
+        return self.to_montgomery(&BigUint::from_u128(value as u128));
+    }
+
+    fn add(&self, a: &Vec<u64>, b: &Vec<u64>) -> Vec<u64> {
+        return Montgomery::add(self, a, b);
+    }
+
+    fn sub(&self, a: &Vec<u64>, b: &Vec<u64>) -> Vec<u64> {
+        return Montgomery::sub(self, a, b);
+    }
+
+    fn mul(&self, a: &Vec<u64>, b: &Vec<u64>) -> Vec<u64> {
+        return Montgomery::mul(self, a, b);
+    }
+
+    fn gcd(&self, a: &Vec<u64>) -> BigUint {
+        return Montgomery::gcd(self, a);
+    }
+
+    fn inverse(&self, a: &Vec<u64>) -> Result<Vec<u64>, BigUint> {
+        let inverse: BigUint =
+            mod_inverse_big(&self.from_montgomery(a), &Montgomery::modulus(self))?;
+        return Ok(self.to_montgomery(&inverse));
+    }
+}
+
+fn pad(num: &BigUint, limbs: usize) -> Vec<u64> {
+    let mut padded: Vec<u64> = num.limbs().to_vec();
+    padded.resize(limbs, 0);
+    return padded;
+}
+
+fn is_below(a: &[u64], b: &[u64]) -> bool {
+    // Compares two slices of the same length.
+    for i in (0..a.len()).rev() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    return false;
+}
+
+fn add_in_place(a: &mut [u64], b: &[u64]) -> bool {
+    // Adds two slices of the same length, returning the carry out.
+    let mut carry: bool = false;
+    for i in 0..a.len() {
+        let (sum, overflow_1) = a[i].overflowing_add(b[i]);
+        let (sum, overflow_2) = sum.overflowing_add(carry as u64);
+        a[i] = sum;
+        carry = overflow_1 || overflow_2;
+    }
+    return carry;
+}
+
+fn sub_in_place(a: &mut [u64], b: &[u64]) -> bool {
+    // Subtracts two slices of the same length, returning the borrow out.
+    let mut borrow: bool = false;
+    for i in 0..a.len() {
+        let (difference, underflow_1) = a[i].overflowing_sub(b[i]);
+        let (difference, underflow_2) = difference.overflowing_sub(borrow as u64);
+        a[i] = difference;
+        borrow = underflow_1 || underflow_2;
+    }
+    return borrow;
+}
+
+pub fn check_if_prime_big(num: &BigUint) -> bool {
+    //! Checks to see if a given number is a prime number using trial division and the Baillie-PSW test.
+    if let Some(small) = num.to_u128() {
+        return primality::check_if_prime_u128(small);
+    }
+    for prime in SMALL_PRIMES {
+        if num.rem_u64(prime) == 0 {
+            return false;
+        }
+    }
+
+    let context: Montgomery = match Montgomery::new(num) {
+        Some(context) => context,
+        None => return false,
+    };
+    return is_strong_probable_prime(&context, &BigUint::from_u128(2))
+        && is_strong_lucas_probable_prime(&context);
+}
+
+pub fn check_if_prime_big_rounds<R: RandomSource>(num: &BigUint, rounds: u32, rng: &mut R) -> bool {
+    //! Runs `check_if_prime_big`, then `rounds` Miller-Rabin rounds to random bases on top.
+    //!
+    //! Each round lets a composite through with a probability of at most `1/4`, independently of Baillie-PSW.
+    //! Numbers below `2^64` are already tested exactly, so they skip the extra rounds.
+    if !check_if_prime_big(num) {
+        return false;
+    }
+    if num.bits() <= 64 {
+        return true;
+    }
+
+    let context: Montgomery = Montgomery::new(num).unwrap();
+    let three: BigUint = BigUint::from_u128(3);
+    let range: BigUint = num.checked_sub(&three).unwrap();
+    for _ in 0..rounds {
+        // A base from `2` till `n - 2`; the extra limb makes the modulo bias negligible.
+        let random: Vec<u64> = (0..=num.limbs().len()).map(|_| rng.next_u64()).collect();
+        let base: BigUint = BigUint::from_limbs(random)
+            .div_rem(&range)
+            .1
+            .add(&BigUint::from_u128(2));
+        if !is_strong_probable_prime(&context, &base) {
+            return false;
+        }
+    }
+    return true;
+}
+
+fn is_strong_probable_prime(context: &Montgomery, base: &BigUint) -> bool {
+    // A single Miller-Rabin round on an odd `n > 3`.
+    let num: BigUint = context.modulus();
+    let num_minus_one: BigUint = num.checked_sub(&BigUint::from_u128(1)).unwrap();
+    let s: u64 = num_minus_one.trailing_zeros();
+    let d: BigUint = num_minus_one.shr(s);
+
+    let minus_one: Vec<u64> = context.sub(&context.zero(), &context.one);
+    let mut x: Vec<u64> = context.pow(&context.to_montgomery(base), &d);
+    if x == context.one || x == minus_one {
+        return true;
+    }
+    for _ in 1..s {
+        x = context.mul(&x, &x);
+        if x == minus_one {
+            return true;
+        }
+    }
+    return false;
+}
+
+fn jacobi_small(d_abs: u64, d_negative: bool, num: &BigUint) -> i8 {
+    // The Jacobi symbol `(D/n)` for an odd `n` and a small odd `|D|`, by quadratic reciprocity:
+    // `(-1/n) = (-1)^((n - 1) / 2)` and `(|D|/n) = (n/|D|) (-1)^((|D| - 1) / 2 * (n - 1) / 2)`.
+    let n_mod_4: u64 = num.rem_u64(4);
+    let mut result: i8 = modular::jacobi(num.rem_u64(d_abs), d_abs);
+    if d_abs % 4 == 3 && n_mod_4 == 3 {
+        result = -result;
+    }
+    if d_negative && n_mod_4 == 3 {
+        result = -result;
+    }
+    return result;
+}
+
+fn is_strong_lucas_probable_prime(context: &Montgomery) -> bool {
+    // The strong Lucas test on an odd `n` with no factor below `1000`, with Selfridge's parameters; see
+    // `primality::is_strong_lucas_probable_prime` for the steps.
+    let num: BigUint = context.modulus();
+    let root: BigUint = integer_nth_root_big(&num, 2);
+    if root.square() == num {
+        return false;
+    }
+
+    let mut d_abs: u64 = 5;
+    let mut d_negative: bool = false;
+    loop {
+        match jacobi_small(d_abs, d_negative, &num) {
+            -1 => break,
+            0 => return false,
+            _ => {}
+        }
+        d_abs = d_abs + 2;
+        d_negative = !d_negative;
+    }
+
+    // `D` and `Q = (1 - D) / 4` in Montgomery form.
+    let d_positive: Vec<u64> = context.to_montgomery(&BigUint::from_u128(d_abs as u128));
+    let (d, q): (Vec<u64>, Vec<u64>) = if d_negative {
+        let q: BigUint = BigUint::from_u128(((d_abs + 1) / 4) as u128);
+        (
+            context.sub(&context.zero(), &d_positive),
+            context.to_montgomery(&q),
+        )
+    } else {
+        let q: BigUint = BigUint::from_u128(((d_abs - 1) / 4) as u128);
+        (
+            d_positive,
+            context.sub(&context.zero(), &context.to_montgomery(&q)),
+        )
+    };
+
+    // n + 1 = k * 2^s, with k odd
+    let num_plus_one: BigUint = num.add(&BigUint::from_u128(1));
+    let s: u64 = num_plus_one.trailing_zeros();
+    let k: BigUint = num_plus_one.shr(s);
+
+    let mut u: Vec<u64> = context.one.clone();
+    let mut v: Vec<u64> = context.one.clone();
+    let mut q_k: Vec<u64> = q.clone();
+    let mut bit: u64 = k.bits() - 1;
+    while bit > 0 {
+        bit = bit - 1;
+        u = context.mul(&u, &v);
+        v = context.sub(&context.mul(&v, &v), &context.add(&q_k, &q_k));
+        q_k = context.mul(&q_k, &q_k);
+        if k.bit(bit) {
+            let next_u: Vec<u64> = context.half(&context.add(&u, &v));
+            v = context.half(&context.add(&context.mul(&d, &u), &v));
+            u = next_u;
+            q_k = context.mul(&q_k, &q);
+        }
+    }
+
+    let zero: Vec<u64> = context.zero();
+    if u == zero || v == zero {
+        return true;
+    }
+    for _ in 1..s {
+        v = context.sub(&context.mul(&v, &v), &context.add(&q_k, &q_k));
+        if v == zero {
+            return true;
+        }
+        q_k = context.mul(&q_k, &q_k);
+    }
+    return false;
+}
+
+fn find_next_prime_u128(num: u128) -> Option<u128> {
+    // The smallest prime above `num`, or `None` if there is none below `2^128`.
+    if num < 2 {
+        return Some(2);
+    }
+    let mut candidate: u128 = num.checked_add(1 + num % 2)?;
+    loop {
+        if primality::check_if_prime_u128(candidate) {
+            return Some(candidate);
+        }
+        candidate = candidate.checked_add(2)?;
+    }
+}
+
+pub fn find_next_prime_big(num: &BigUint) -> BigUint {
+    //! Finds the smallest prime larger than a given number.
+    //!
+    //! The odd candidates are stepped through with their residues modulo every prime below `1000` kept alongside, so
+    //! about `88%` of them are thrown out without any big-number arithmetic.
+    if let Some(prime) = num.to_u128().and_then(find_next_prime_u128) {
+        return BigUint::from_u128(prime);
+    }
+
+    let start: BigUint = num.add(&BigUint::from_u128(1 + num.rem_u64(2) as u128));
+    let mut residues: Vec<u64> = SMALL_PRIMES
+        .iter()
+        .map(|prime| start.rem_u64(*prime))
+        .collect();
+    let mut offset: u64 = 0;
+    loop {
+        if residues.iter().all(|residue| *residue != 0) {
+            let candidate: BigUint = start.add(&BigUint::from_u128(offset as u128));
+            if check_if_prime_big(&candidate) {
+                return candidate;
+            }
+        }
+        for (residue, prime) in residues.iter_mut().zip(SMALL_PRIMES.iter()) {
+            *residue = (*residue + 2) % prime;
+        }
+        offset = offset + 2;
+    }
+}
+
+pub fn integer_nth_root_big(num: &BigUint, k: u32) -> BigUint {
+    //! Computes `floor(num^(1/k))` for `k >= 1`, using Newton's method from above.
+    if k == 1 || num.bits() < 2 {
+        return num.clone();
+    }
+    let k_big: BigUint = BigUint::from_u128(k as u128);
+    let k_minus_one: BigUint = BigUint::from_u128(k as u128 - 1);
+    let mut x: BigUint = BigUint::from_u128(1).shl((num.bits() + k as u64 - 1) / k as u64);
+    loop {
+        // y = ((k - 1) x + num / x^(k - 1)) / k
+        let y: BigUint = x
+            .mul(&k_minus_one)
+            .add(&num.div_rem(&x.pow(k - 1)).0)
+            .div_rem(&k_big)
+            .0;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+fn perfect_power(num: &BigUint) -> Option<(BigUint, u32)> {
+    // Finds `(root, k)` with `root^k = num` for a prime `k`, if there is one.
+    for k in primes::find_primes_till(num.bits()) {
+        let root: BigUint = integer_nth_root_big(num, k as u32);
+        if root.pow(k as u32) == *num {
+            return Some((root, k as u32));
+        }
+    }
+    return None;
+}
+
+pub fn factorize_big(num: &BigUint, ecm_digits: u32) -> (Vec<(BigUint, u32)>, BigUint) {
+    //! Finds the prime factorization of a given number, as `(prime, exponent)` pairs in increasing order, together
+    //! with the cofactor that could not be split (`1` once the factorization is complete).
+    //!
+    //! Composites are split with Brent's rho, SIQS while they have at most `siqs::SIQS_MAXIMUM_BITS` bits, and then
+    //! ECM for factors of up to `ecm_digits` decimal digits; a larger composite whose factors are all larger than that
+    //! is left in the cofactor. Pieces that fit in a `u128` go to `factorization::factorize_u128`. `0` and `1` have no
+    //! prime factors.
+    let one: BigUint = BigUint::from_u128(1);
+    if let Some(small) = num.to_u128() {
+        let factors: Vec<(BigUint, u32)> = factorization::factorize_u128(small)
+            .into_iter()
+            .map(|(prime, exponent)| (BigUint::from_u128(prime), exponent))
+            .collect();
+        return (factors, one);
+    }
+
+    let mut factors: Vec<(BigUint, u32)> = Vec::new();
+    let mut rest: BigUint = num.clone();
+    for prime in primes::find_primes_till(TRIAL_DIVISION_LIMIT) {
+        let mut exponent: u32 = 0;
+        while rest.rem_u64(prime) == 0 {
+            rest = rest.div_rem_u64(prime).0;
+            exponent = exponent + 1;
+        }
+        if exponent > 0 {
+            factors.push((BigUint::from_u128(prime as u128), exponent));
+        }
+    }
+
+    // Each entry is a factor still to be split, with the power it divides `num` to.
+    let mut cofactor: BigUint = one.clone();
+    let mut pending: Vec<(BigUint, u32)> = Vec::new();
+    if rest != one {
+        pending.push((rest, 1));
+    }
+    while let Some((value, multiplicity)) = pending.pop() {
+        if let Some(small) = value.to_u128() {
+            for (prime, exponent) in factorization::factorize_u128(small) {
+                factors.push((BigUint::from_u128(prime), exponent * multiplicity));
+            }
+        } else if check_if_prime_big(&value) {
+            factors.push((value, multiplicity));
+        } else if let Some((root, k)) = perfect_power(&value) {
+            pending.push((root, k * multiplicity));
+        } else if let Some(factor) = find_factor_big(&value, ecm_digits) {
+            let (quotient, _): (BigUint, BigUint) = value.div_rem(&factor);
+            pending.push((factor, multiplicity));
+            pending.push((quotient, multiplicity));
+        } else {
+            cofactor = cofactor.mul(&value.pow(multiplicity));
+        }
+    }
+
+    // Merge the repeated primes, which show up when the same prime comes out of different splits.
+    factors.sort();
+    let mut merged: Vec<(BigUint, u32)> = Vec::new();
+    for (prime, exponent) in factors {
+        match merged.last_mut() {
+            Some(last) if last.0 == prime => last.1 = last.1 + exponent,
+            _ => merged.push((prime, exponent)),
+        }
+    }
+    return (merged, cofactor);
+}
+
+fn find_factor_big(num: &BigUint, ecm_digits: u32) -> Option<BigUint> {
+    // A non-trivial factor of an odd composite that is not a perfect power: a short rho run first, then, for numbers
+    // SIQS can take, the same few ECM curves and SIQS as `factorization::factorize_u128`, and ECM one `DIGIT_LEVELS`
+    // entry at a time for the rest.
+    let context: Montgomery = Montgomery::new(num)?;
+    let start: Vec<u64> = context.residue(2);
+    let c: Vec<u64> = context.residue(1);
+    if let Some(factor) = factorization::brent_rho(&context, &start, &c, RHO_CYCLE_LIMIT) {
+        return Some(factor);
+    }
+    if num.bits() <= siqs::SIQS_MAXIMUM_BITS {
+        let (_, b1, _): (u32, u64, u32) = DIGIT_LEVELS[0];
+        // A curve costs about `11ms` here against `2ms` on a `u128`, so only a quarter of the curves keeps the pass
+        // at the same share of SIQS's time.
+        let curves: u32 = factorization::siqs_ecm_curves(num.bits() as u32) / 4;
+        let params: EcmParams = EcmParams::new(b1, 100 * b1, curves, ecm::DEFAULT_SEED);
+        if let Some(factor) = ecm::ecm_with(&context, &params, u64::MAX) {
+            return Some(factor);
+        }
+        if let Some(factor) = siqs::siqs_big(num) {
+            return Some(factor);
+        }
+    }
+    for (digits, _, _) in DIGIT_LEVELS {
+        if let Some(factor) = ecm::ecm_with(&context, &EcmParams::for_digits(digits), u64::MAX) {
+            return Some(factor);
+        }
+        if digits >= ecm_digits {
+            break;
+        }
+    }
+    return None;
+}
+
+fn mod_inverse_big(a: &BigUint, modulus: &BigUint) -> Result<BigUint, BigUint> {
+    // The inverse of `a` modulo `modulus` with the extended Euclidean algorithm, or the gcd if it is not `1`. Keeps
+    // `s_i * a = r_i` modulo `modulus` for both rows, with the `s_i` reduced as they go.
+    let mut r: (BigUint, BigUint) = (modulus.clone(), a.div_rem(modulus).1);
+    let mut s: (BigUint, BigUint) = (BigUint::zero(), BigUint::from_u128(1));
+    while !r.1.is_zero() {
+        let (quotient, remainder): (BigUint, BigUint) = r.0.div_rem(&r.1);
+        let subtrahend: BigUint = quotient.mul(&s.1).div_rem(modulus).1;
+        let next_s: BigUint = match s.0.checked_sub(&subtrahend) {
+            Some(difference) => difference,
+            None => s.0.add(modulus).checked_sub(&subtrahend).unwrap(),
+        };
+        r = (r.1, remainder);
+        s = (s.1, next_s);
+    }
+    if r.0 == BigUint::from_u128(1) {
+        return Ok(s.0);
+    }
+    return Err(r.0);
+}
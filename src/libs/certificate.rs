@@ -0,0 +1,301 @@
+//! Primality certificates: proofs that a number is prime which can be checked without trusting this crate.
+//!
+//! Each certificate proves its prime `n` from a witness `a` and the factorization of `n - 1`:
+//!
+//! 1. __Small:__ `n` is below `2^16` and is checked by trial division.
+//! 2. __Pratt:__ `n - 1` is fully factored and `a` has order `n - 1` modulo `n`, i.e. `a^(n-1) = 1` and
+//!    `a^((n-1)/q) != 1` for every prime `q` dividing `n - 1`.
+//! 3. __Pocklington:__ only a part `F > sqrt(n)` of `n - 1` is factored, and `a^(n-1) = 1` and
+//!    `gcd(a^((n-1)/q) - 1, n) = 1` for every prime `q` dividing `F`.
+//!
+//! Every prime `q` used is proved by its own certificate, recursively.
+//!
+//! __Text format:__ one certificate per line, smallest first, with each line only referring to primes proved by
+//! earlier lines. The last line is the number being proved.
+//!
+//! ```text
+//! small 2
+//! small 3
+//! pratt 7 3 2^1 3^1
+//! ```
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::libs::modular;
+use crate::libs::multiplicative;
+use crate::libs::primality;
+use crate::libs::prime_int::PrimeInt;
+
+/// Numbers below this are proved by trial division.
+pub const SMALL_LIMIT: u64 = 1 << 16;
+
+/// A proof that a number is prime; see the module documentation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Certificate {
+    /// A prime below `SMALL_LIMIT`.
+    Small(u64),
+    /// A prime `n`, a witness of order `n - 1`, and the full factorization of `n - 1` with a certificate per prime.
+    Pratt {
+        prime: u64,
+        witness: u64,
+        factors: Vec<(u64, u32, Certificate)>,
+    },
+    /// A prime `n`, a witness, and a factored part `F > sqrt(n)` of `n - 1` with a certificate per prime.
+    Pocklington {
+        prime: u64,
+        witness: u64,
+        factors: Vec<(u64, u32, Certificate)>,
+    },
+}
+
+impl Certificate {
+    pub fn prime(&self) -> u64 {
+        //! The number this certificate proves to be prime.
+        return match self {
+            Certificate::Small(prime) => *prime,
+            Certificate::Pratt { prime, .. } => *prime,
+            Certificate::Pocklington { prime, .. } => *prime,
+        };
+    }
+
+    pub fn to_text(&self) -> String {
+        //! Serializes the certificate to its text format.
+        let mut lines: Vec<String> = Vec::new();
+        self.write_lines(&mut lines);
+        return lines.join("\n");
+    }
+
+    fn write_lines(&self, lines: &mut Vec<String>) {
+        // Post-order: every prime's line comes after the lines of the primes it depends on.
+        let (kind, prime, witness, factors) = match self {
+            Certificate::Small(prime) => {
+                let line: String = alloc::format!("small {}", prime);
+                if !lines.contains(&line) {
+                    lines.push(line);
+                }
+                return;
+            }
+            Certificate::Pratt {
+                prime,
+                witness,
+                factors,
+            } => ("pratt", prime, witness, factors),
+            Certificate::Pocklington {
+                prime,
+                witness,
+                factors,
+            } => ("pocklington", prime, witness, factors),
+        };
+
+        let mut line: String = alloc::format!("{} {} {}", kind, prime, witness);
+        for (factor, exponent, certificate) in factors {
+            certificate.write_lines(lines);
+            line.push_str(&alloc::format!(" {}^{}", factor, exponent));
+        }
+        if !lines.contains(&line) {
+            lines.push(line);
+        }
+    }
+
+    pub fn from_text(text: &str) -> Option<Certificate> {
+        //! Parses a certificate from its text format.
+        //!
+        //! Returns `None` if the text is malformed or refers to a prime that no earlier line proves. Parsing does not
+        //! check the proof itself; use `verify_certificate` for that.
+        let mut proved: BTreeMap<u64, Certificate> = BTreeMap::new();
+        let mut last: Option<u64> = None;
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let mut tokens = line.split_whitespace();
+            let kind: &str = tokens.next()?;
+            let prime: u64 = tokens.next()?.parse().ok()?;
+            let certificate: Certificate = if kind == "small" {
+                Certificate::Small(prime)
+            } else {
+                let witness: u64 = tokens.next()?.parse().ok()?;
+                let mut factors: Vec<(u64, u32, Certificate)> = Vec::new();
+                for token in tokens.by_ref() {
+                    let (factor, exponent) = token.split_once('^')?;
+                    let factor: u64 = factor.parse().ok()?;
+                    let exponent: u32 = exponent.parse().ok()?;
+                    factors.push((factor, exponent, proved.get(&factor)?.clone()));
+                }
+                match kind {
+                    "pratt" => Certificate::Pratt {
+                        prime,
+                        witness,
+                        factors,
+                    },
+                    "pocklington" => Certificate::Pocklington {
+                        prime,
+                        witness,
+                        factors,
+                    },
+                    _ => return None,
+                }
+            };
+            if tokens.next().is_some() {
+                return None;
+            }
+            proved.insert(prime, certificate);
+            last = Some(prime);
+        }
+        return proved.remove(&last?);
+    }
+}
+
+impl fmt::Display for Certificate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", self.to_text());
+    }
+}
+
+pub fn verify_certificate(certificate: &Certificate) -> bool {
+    //! Checks a primality certificate.
+    //!
+    //! Kept deliberately short, and independent of the primality tests in the rest of the crate, so that it can be
+    //! reviewed by hand.
+    let (prime, witness, factors, is_pratt) = match certificate {
+        Certificate::Small(prime) => {
+            // Trial division.
+            let prime: u64 = *prime;
+            return (2..SMALL_LIMIT).contains(&prime)
+                && (2..prime)
+                    .take_while(|d| d * d <= prime)
+                    .all(|d| prime % d != 0);
+        }
+        Certificate::Pratt {
+            prime,
+            witness,
+            factors,
+        } => (*prime, *witness, factors, true),
+        Certificate::Pocklington {
+            prime,
+            witness,
+            factors,
+        } => (*prime, *witness, factors, false),
+    };
+    if prime < 3 {
+        return false;
+    }
+
+    // The factored part, F, must divide n - 1; Pratt needs all of n - 1, Pocklington needs F > sqrt(n).
+    let mut factored: u128 = 1;
+    for (factor, exponent, _) in factors {
+        factored = match (*factor as u128).checked_pow(*exponent) {
+            Some(power) if power <= u64::MAX as u128 => factored * power,
+            _ => return false,
+        };
+        if factored > prime as u128 {
+            return false;
+        }
+    }
+    let enough: bool = if is_pratt {
+        factored == (prime - 1) as u128
+    } else {
+        (prime - 1) as u128 % factored == 0 && factored * factored > prime as u128
+    };
+    if !enough || modular::mod_pow(witness, prime - 1, prime) != 1 {
+        return false;
+    }
+
+    for (factor, exponent, sub_certificate) in factors {
+        let power: u64 = modular::mod_pow(witness, (prime - 1) / factor, prime);
+        let holds: bool = if is_pratt {
+            power != 1
+        } else {
+            modular::gcd_u128(
+                (power as u128 + prime as u128 - 1) % prime as u128,
+                prime as u128,
+            ) == 1
+        };
+        if *exponent == 0
+            || !holds
+            || sub_certificate.prime() != *factor
+            || !verify_certificate(sub_certificate)
+        {
+            return false;
+        }
+    }
+    return true;
+}
+
+pub fn prove_prime(num: u64) -> Option<Certificate> {
+    //! Builds a primality certificate for a given number.
+    //!
+    //! Prefers a Pocklington certificate over the smallest prime factors of `n - 1`, which needs fewer sub-proofs,
+    //! and falls back to a Pratt certificate. Returns `None` if the number is not prime.
+    if !primality::check_if_prime_mr(num) {
+        return None;
+    } else if num < SMALL_LIMIT {
+        return Some(Certificate::Small(num));
+    }
+
+    // n - 1 factored from the smallest prime up.
+    let factors: Vec<(u64, u32)> = (num - 1).factorize();
+
+    // Pocklington: the smallest prime powers whose product F is above sqrt(n).
+    let mut factored: u128 = 1;
+    let mut partial: Vec<(u64, u32)> = Vec::new();
+    for (factor, exponent) in factors.iter() {
+        if factored * factored > num as u128 {
+            break;
+        }
+        factored = factored * (*factor as u128).pow(*exponent);
+        partial.push((*factor, *exponent));
+    }
+    if partial.len() < factors.len() {
+        if let Some(witness) = find_witness(num, &partial) {
+            return Some(Certificate::Pocklington {
+                prime: num,
+                witness,
+                factors: prove_factors(&partial)?,
+            });
+        }
+    }
+
+    return pratt_from_factors(num, &factors);
+}
+
+pub fn pratt_certificate(num: u64) -> Option<Certificate> {
+    //! Builds a Pratt certificate for a given prime, with its smallest primitive root as the witness, or `None` if
+    //! the number is not prime.
+    //!
+    //! Unlike `prove_prime`, this always gives a Pratt certificate at the top level (primes below `SMALL_LIMIT`
+    //! included), while the primes of `n - 1` are proved with `prove_prime`.
+    if num < 3 || !primality::check_if_prime_mr(num) {
+        return None;
+    }
+    return pratt_from_factors(num, &(num - 1).factorize());
+}
+
+fn pratt_from_factors(num: u64, factors: &[(u64, u32)]) -> Option<Certificate> {
+    // A witness of order `n - 1` is exactly a primitive root of the prime `n`.
+    let witness: u64 = multiplicative::find_primitive_root_from_factors(num, num - 1, factors)?;
+    return Some(Certificate::Pratt {
+        prime: num,
+        witness,
+        factors: prove_factors(factors)?,
+    });
+}
+
+fn prove_factors(factors: &[(u64, u32)]) -> Option<Vec<(u64, u32, Certificate)>> {
+    // Proves every prime in a factorization.
+    let mut proved: Vec<(u64, u32, Certificate)> = Vec::new();
+    for (factor, exponent) in factors {
+        proved.push((*factor, *exponent, prove_prime(*factor)?));
+    }
+    return Some(proved);
+}
+
+fn find_witness(num: u64, factors: &[(u64, u32)]) -> Option<u64> {
+    // Finds the smallest Pocklington witness, for which `a^((n-1)/q) != 1` for every prime `q` in `factors`. Any
+    // primitive root works, but the search is capped, since Pratt is the fallback.
+    let limit: u64 = core::cmp::min(num - 1, 1_000);
+    return (2..=limit).find(|witness| {
+        factors
+            .iter()
+            .all(|(factor, _)| modular::mod_pow(*witness, (num - 1) / factor, num) != 1)
+    });
+}
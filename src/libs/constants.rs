@@ -1,17 +1,20 @@
 //! Holds package-wide constants.
 #![allow(warnings)]
+use crate::libs::tables;
 
 // Declaring this as a string slice, otherwise the borrow-checker won't let it be declared.
 // Stackoverflow link: https://stackoverflow.com/a/45176487/11745092
 pub const DESCRIPTION: &str = "Rust library crate to hold simple functions to check the prime-ness of a given unsigned, 64-bit integer.";
 
 // List of known prime number; reduces processing time.
-pub const KNOWN_PRIMES: [u64; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+pub const KNOWN_PRIMES: [u64; 15] = tables::prime_table::<15>();
 // List of known anti-primes; reduces processing time.
-pub const KNOWN_ANTIPRIMES: [u64; 21] = [
-    1, 2, 4, 6, 12, 24, 36, 48, 60, 120, 180, 240, 360, 720, 840, 1_260, 1_680, 2_520, 5_040,
-    7_560, 10_080,
-];
+pub const KNOWN_ANTIPRIMES: [u64; 21] = tables::hcn_table::<21>();
+
+// Spot-check the generated tables against the values they used to be typed out as.
+const _: () = assert!(KNOWN_PRIMES[0] == 2 && KNOWN_PRIMES[14] == 47);
+const _: () = assert!(KNOWN_ANTIPRIMES[0] == 1 && KNOWN_ANTIPRIMES[20] == 10_080);
+
 pub const MIN_I: u64 = u64::MIN; // Minimum value of u64
 pub const MAX_I: u64 = u64::MAX; // Maximum value of u64
 
@@ -0,0 +1,118 @@
+//! Prime constellations: twin, cousin and sexy primes, and general prime k-tuples.
+//!
+//! A pattern is a list of offsets starting at `0`, such as `[0, 2, 6, 8]`; a prime `p` starts a tuple when every
+//! `p + offset` is prime.
+use alloc::collections::VecDeque;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::libs::primality;
+use crate::libs::sieve::PrimesInRange;
+
+pub const TWIN_PATTERN: [u64; 2] = [0, 2];
+pub const COUSIN_PATTERN: [u64; 2] = [0, 4];
+pub const SEXY_PATTERN: [u64; 2] = [0, 6];
+
+pub fn check_if_admissible(pattern: &[u64]) -> bool {
+    //! Checks whether a pattern can occur infinitely often, i.e. whether its offsets start at `0`, increase
+    //! strictly, and miss at least one residue class modulo every prime.
+    //!
+    //! Only primes till the length of the pattern need checking, since a shorter list cannot cover all the
+    //! residues of a larger prime. An inadmissible pattern such as `[0, 2, 4]` always has a member divisible by
+    //! `3`, so it only matches where that member is `3` itself.
+    if pattern.first() != Some(&0) {
+        return false;
+    }
+    for index in 1..pattern.len() {
+        if pattern[index] <= pattern[index - 1] {
+            return false;
+        }
+    }
+
+    for modulus in 2..=pattern.len() as u64 {
+        if !primality::check_if_prime_mr(modulus) {
+            continue;
+        }
+        let mut covered: Vec<bool> = vec![false; modulus as usize];
+        for offset in pattern.iter() {
+            covered[(offset % modulus) as usize] = true;
+        }
+        if covered.iter().all(|residue| *residue) {
+            return false;
+        }
+    }
+    return true;
+}
+
+pub fn count_prime_tuples(pattern: &[u64], num: u64) -> Option<u64> {
+    //! Counts the tuples of an admissible pattern whose members are all till a given number, or `None` if the
+    //! pattern is not admissible. For `[0, 2]` this is the twin-prime counting function `pi_2(num)`.
+    return PrimeTuples::new(pattern, 0, num).map(|tuples| tuples.count() as u64);
+}
+
+/// Iterator over the first members of the tuples of a pattern in a range, in increasing order.
+///
+/// Reads the primes from a segmented sieve and keeps a window of the primes within the span of the pattern, so
+/// memory use stays constant however long the range is.
+#[derive(Debug, Clone)]
+pub struct PrimeTuples {
+    pattern: Vec<u64>,
+    high: u64,
+    primes: PrimesInRange,
+    exhausted: bool,
+    window: VecDeque<u64>, // Primes from the current candidate till the end of its span.
+}
+
+impl PrimeTuples {
+    pub fn new(pattern: &[u64], low: u64, high: u64) -> Option<Self> {
+        //! Creates an iterator over the tuples of a pattern with every member from `low` till `high`, inclusive,
+        //! or `None` if the pattern is not admissible.
+        if !check_if_admissible(pattern) {
+            return None;
+        }
+        return Some(PrimeTuples {
+            pattern: pattern.to_vec(),
+            high,
+            primes: PrimesInRange::new(low, high),
+            exhausted: false,
+            window: VecDeque::new(),
+        });
+    }
+
+    pub fn pattern(&self) -> &[u64] {
+        //! The offsets of the pattern being searched for.
+        return &self.pattern;
+    }
+}
+
+impl Iterator for PrimeTuples {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let span: u64 = self.pattern[self.pattern.len() - 1];
+        loop {
+            while !self.exhausted {
+                match (self.window.front(), self.window.back()) {
+                    (Some(first), Some(last)) if last - first >= span => break,
+                    _ => {}
+                }
+                match self.primes.next() {
+                    Some(prime) => self.window.push_back(prime),
+                    None => self.exhausted = true,
+                }
+            }
+
+            let first: u64 = self.window.pop_front()?;
+            match first.checked_add(span) {
+                Some(last) if last <= self.high => {}
+                _ => return None,
+            }
+            let matched: bool = self.pattern[1..]
+                .iter()
+                .all(|offset| self.window.binary_search(&(first + offset)).is_ok());
+            if matched {
+                return Some(first);
+            }
+        }
+    }
+}
@@ -0,0 +1,153 @@
+//! Sophie Germain primes, safe primes and Cunningham chains.
+//!
+//! A prime `p` is a Sophie Germain prime when `2p + 1` is prime, and `2p + 1` is then a safe prime. A Cunningham
+//! chain of the first kind repeats this, `p, 2p + 1, 4p + 3, ...`; one of the second kind follows
+//! `p, 2p - 1, 4p - 3, ...` instead. Members past `u64` are tested with the `u128` Baillie-PSW test.
+use alloc::vec::Vec;
+
+use crate::libs::primality;
+use crate::libs::sieve::PrimesInRange;
+
+/// The two kinds of Cunningham chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChainKind {
+    /// Each member is `2p + 1` for the member `p` before it.
+    First,
+    /// Each member is `2p - 1` for the member `p` before it.
+    Second,
+}
+
+impl ChainKind {
+    fn successor(self, prime: u128) -> Option<u128> {
+        // The next candidate member after `prime`.
+        return match self {
+            ChainKind::First => prime.checked_mul(2)?.checked_add(1),
+            ChainKind::Second => prime.checked_mul(2)?.checked_sub(1),
+        };
+    }
+
+    fn predecessor(self, prime: u128) -> Option<u128> {
+        // The member that `prime` would follow, if there is one.
+        if prime % 2 == 0 {
+            return None;
+        }
+        return match self {
+            ChainKind::First => Some((prime - 1) / 2),
+            ChainKind::Second => Some((prime + 1) / 2),
+        };
+    }
+}
+
+pub fn check_if_sophie_germain(num: u64) -> bool {
+    //! Checks whether a number is a Sophie Germain prime, i.e. both `num` and `2 * num + 1` are prime.
+    return primality::check_if_prime_mr(num)
+        && primality::check_if_prime_u128(2 * num as u128 + 1);
+}
+
+pub fn check_if_safe_prime(num: u64) -> bool {
+    //! Checks whether a number is a safe prime, i.e. both `num` and `(num - 1) / 2` are prime.
+    return num % 2 == 1
+        && primality::check_if_prime_mr(num / 2)
+        && primality::check_if_prime_mr(num);
+}
+
+pub fn find_chain_length(num: u64, kind: ChainKind) -> u32 {
+    //! Finds the length of the Cunningham chain that starts at a given number, or `0` if it is not prime.
+    let mut length: u32 = 0;
+    let mut member: Option<u128> = Some(num as u128);
+    while let Some(prime) = member {
+        if !primality::check_if_prime_u128(prime) {
+            break;
+        }
+        length += 1;
+        member = kind.successor(prime);
+    }
+    return length;
+}
+
+pub fn find_cunningham_chains(
+    low: u64,
+    high: u64,
+    kind: ChainKind,
+    min_length: u32,
+) -> Vec<(u64, u32)> {
+    //! Finds the complete Cunningham chains that start from `low` till `high` and have at least `min_length`
+    //! members, as `(first member, length)` pairs.
+    //!
+    //! A chain is complete when it cannot be extended backwards, i.e. the member its first member would follow is
+    //! not prime.
+    let mut chains: Vec<(u64, u32)> = Vec::new();
+    for prime in PrimesInRange::new(low, high) {
+        let extends: bool = match kind.predecessor(prime as u128) {
+            Some(previous) => primality::check_if_prime_u128(previous),
+            None => false,
+        };
+        if extends {
+            continue;
+        }
+        let length: u32 = find_chain_length(prime, kind);
+        if length >= min_length {
+            chains.push((prime, length));
+        }
+    }
+    return chains;
+}
+
+/// Iterator over the Sophie Germain primes in a range, in increasing order.
+///
+/// Reads the primes `p` from a segmented sieve and tests `2p + 1` with Miller-Rabin.
+#[derive(Debug, Clone)]
+pub struct SophieGermainPrimes {
+    primes: PrimesInRange,
+}
+
+impl SophieGermainPrimes {
+    pub fn new(low: u64, high: u64) -> Self {
+        //! Creates an iterator over the Sophie Germain primes from `low` till `high`, inclusive.
+        return SophieGermainPrimes {
+            primes: PrimesInRange::new(low, high),
+        };
+    }
+}
+
+impl Iterator for SophieGermainPrimes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            let prime: u64 = self.primes.next()?;
+            // Above 3, `2p + 1` is divisible by 3 unless `p = 5 (mod 6)`.
+            if (prime <= 3 || prime % 6 == 5)
+                && primality::check_if_prime_u128(2 * prime as u128 + 1)
+            {
+                return Some(prime);
+            }
+        }
+    }
+}
+
+/// Iterator over the safe primes in a range, in increasing order.
+///
+/// Runs `SophieGermainPrimes` over the matching range of `(p - 1) / 2` and maps each to `2p + 1`.
+#[derive(Debug, Clone)]
+pub struct SafePrimes {
+    sophie_germain: SophieGermainPrimes,
+}
+
+impl SafePrimes {
+    pub fn new(low: u64, high: u64) -> Self {
+        //! Creates an iterator over the safe primes from `low` till `high`, inclusive.
+        // `2p + 1` is in the range exactly when `p` is from `low / 2` till `(high - 1) / 2`.
+        return SafePrimes {
+            sophie_germain: SophieGermainPrimes::new(low / 2, high.saturating_sub(1) / 2),
+        };
+    }
+}
+
+impl Iterator for SafePrimes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        return self.sophie_germain.next().map(|prime| 2 * prime + 1);
+    }
+}
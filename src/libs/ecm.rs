@@ -0,0 +1,408 @@
+//! Lenstra's elliptic curve method (ECM), for factors too large for Pollard's rho.
+//!
+//! Works on Montgomery curves `By^2 = x^3 + Ax^2 + x` from Suyama's parametrization, whose group orders are all
+//! divisible by `12`, with x-only arithmetic in projective `(X : Z)` coordinates. Stage 1 multiplies a point by every
+//! prime power till `B1`; stage 2 catches one more prime till `B2` with a baby-step giant-step continuation. A factor
+//! `p` is found once some curve has a group order modulo `p` that is smooth enough, so the work grows with the size
+//! of `p` rather than that of `n`.
+//!
+//! The curve arithmetic is written against `MontgomeryContext`, so the same code runs on `u128` residues here and on
+//! arbitrary-precision ones in `libs::bignum`.
+use alloc::vec::Vec;
+
+use crate::libs::modular::{self, Montgomery128};
+use crate::libs::primes;
+use crate::libs::random::{RandomSource, SeededRng};
+use crate::libs::sieve;
+
+/// The giant step of stage 2, `2 * 3 * 5 * 7 * 11`; only the `240` baby steps below `D / 2` that are coprime to it
+/// are needed.
+const GIANT_STEP: u64 = 2_310;
+
+/// Number of prime powers stage 1 multiplies by between gcds; a batch whose gcd overshoots to `n` is retraced one
+/// prime at a time.
+const STAGE_ONE_BATCH: usize = 64;
+
+/// The seed `EcmParams::for_digits` uses, so that factorizations are reproducible.
+pub const DEFAULT_SEED: u64 = 0;
+
+/// `(digits, B1, curves)` for factors of up to `15`, `20`, `25` and `30` decimal digits, after the GMP-ECM
+/// recommendations.
+pub const DIGIT_LEVELS: [(u32, u64, u32); 4] = [
+    (15, 2_000, 25),
+    (20, 11_000, 90),
+    (25, 50_000, 300),
+    (30, 250_000, 700),
+];
+
+/// The bounds and curve count of an ECM run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EcmParams {
+    /// Stage 1 bound: the point is multiplied by every prime power till `b1`.
+    pub b1: u64,
+    /// Stage 2 bound: one more prime from `b1` till `b2` may divide the group order.
+    pub b2: u64,
+    /// Number of curves to try before giving up.
+    pub curves: u32,
+    /// Seed for picking the curves; the same seed always gives the same curves.
+    pub seed: u64,
+}
+
+impl EcmParams {
+    pub fn new(b1: u64, b2: u64, curves: u32, seed: u64) -> Self {
+        //! Creates the parameters for a run with the given bounds, curve count and seed.
+        return EcmParams {
+            b1,
+            b2,
+            curves,
+            seed,
+        };
+    }
+
+    pub fn for_digits(digits: u32) -> Self {
+        //! Picks the parameters for factors of up to a given number of decimal digits from `DIGIT_LEVELS`, with
+        //! `B2 = 100 * B1` and `DEFAULT_SEED`.
+        let mut level: (u32, u64, u32) = DIGIT_LEVELS[DIGIT_LEVELS.len() - 1];
+        for candidate in DIGIT_LEVELS {
+            if candidate.0 >= digits {
+                level = candidate;
+                break;
+            }
+        }
+        return EcmParams::new(level.1, 100 * level.1, level.2, DEFAULT_SEED);
+    }
+}
+
+/// Arithmetic modulo an odd `n > 1` in Montgomery form: everything ECM and Pollard's rho need.
+///
+/// Implemented for `Montgomery128` here, and for the multi-limb context in `libs::bignum`.
+pub trait MontgomeryContext {
+    /// A residue modulo `n`, in Montgomery form.
+    type Residue: Clone + PartialEq;
+    /// A plain number, such as `n` itself or one of its factors.
+    type Int: Clone + PartialEq + From<u128>;
+
+    /// The modulus `n`.
+    fn modulus(&self) -> Self::Int;
+
+    /// Converts a small number into Montgomery form.
+    fn residue(&self, value: u64) -> Self::Residue;
+
+    fn add(&self, a: &Self::Residue, b: &Self::Residue) -> Self::Residue;
+
+    fn sub(&self, a: &Self::Residue, b: &Self::Residue) -> Self::Residue;
+
+    fn mul(&self, a: &Self::Residue, b: &Self::Residue) -> Self::Residue;
+
+    /// `gcd(a, n)`; `R` is coprime to `n`, so this is the same for `a` and its Montgomery form.
+    fn gcd(&self, a: &Self::Residue) -> Self::Int;
+
+    /// The inverse of `a` modulo `n`, or `gcd(a, n)` if `a` is not a unit.
+    fn inverse(&self, a: &Self::Residue) -> Result<Self::Residue, Self::Int>;
+}
+
+impl MontgomeryContext for Montgomery128 {
+    type Residue = u128;
+    type Int = u128;
+
+    fn modulus(&self) -> u128 {
+        return Montgomery128::modulus(self);
+    }
+
+    fn residue(&self, value: u64) -> u128 {
+        return self.to_montgomery(value as u128);
+    }
+
+    fn add(&self, a: &u128, b: &u128) -> u128 {
+        return modular::mod_add_u128(*a, *b, Montgomery128::modulus(self));
+    }
+
+    fn sub(&self, a: &u128, b: &u128) -> u128 {
+        return modular::mod_sub_u128(*a, *b, Montgomery128::modulus(self));
+    }
+
+    fn mul(&self, a: &u128, b: &u128) -> u128 {
+        return Montgomery128::mul(self, *a, *b);
+    }
+
+    fn gcd(&self, a: &u128) -> u128 {
+        return modular::gcd_u128(*a, Montgomery128::modulus(self));
+    }
+
+    fn inverse(&self, a: &u128) -> Result<u128, u128> {
+        let num: u128 = Montgomery128::modulus(self);
+        let plain: u128 = self.from_montgomery(*a);
+        return match modular::mod_inverse_u128(plain, num) {
+            Some(inverse) => Ok(self.to_montgomery(inverse)),
+            None => Err(modular::gcd_u128(plain, num)),
+        };
+    }
+}
+
+/// A point `(X : Z)` in Montgomery form, with the `y` coordinate dropped.
+#[derive(Debug, Clone)]
+struct Point<R> {
+    x: R,
+    z: R,
+}
+
+/// A curve modulo `n`, given by `(A + 2) / 4` in Montgomery form.
+struct Curve<'a, C: MontgomeryContext> {
+    context: &'a C,
+    a24: C::Residue,
+}
+
+impl<'a, C: MontgomeryContext> Curve<'a, C> {
+    fn double(&self, point: &Point<C::Residue>) -> Point<C::Residue> {
+        // `X' = (X + Z)^2 (X - Z)^2` and `Z' = 4XZ ((X - Z)^2 + a24 * 4XZ)`, with `4XZ = (X + Z)^2 - (X - Z)^2`.
+        let context: &C = self.context;
+        let sum: C::Residue = context.add(&point.x, &point.z);
+        let difference: C::Residue = context.sub(&point.x, &point.z);
+        let sum_squared: C::Residue = context.mul(&sum, &sum);
+        let difference_squared: C::Residue = context.mul(&difference, &difference);
+        let cross: C::Residue = context.sub(&sum_squared, &difference_squared);
+        let z: C::Residue = context.add(&difference_squared, &context.mul(&self.a24, &cross));
+        return Point {
+            x: context.mul(&sum_squared, &difference_squared),
+            z: context.mul(&cross, &z),
+        };
+    }
+
+    fn differential_add(
+        &self,
+        p: &Point<C::Residue>,
+        q: &Point<C::Residue>,
+        difference: &Point<C::Residue>,
+    ) -> Point<C::Residue> {
+        // `P + Q` from `P`, `Q` and `P - Q`, which x-only arithmetic needs to tell `P + Q` from `P - Q`.
+        let context: &C = self.context;
+        let u: C::Residue = context.mul(&context.sub(&p.x, &p.z), &context.add(&q.x, &q.z));
+        let v: C::Residue = context.mul(&context.add(&p.x, &p.z), &context.sub(&q.x, &q.z));
+        let sum: C::Residue = context.add(&u, &v);
+        let difference_uv: C::Residue = context.sub(&u, &v);
+        return Point {
+            x: context.mul(&difference.z, &context.mul(&sum, &sum)),
+            z: context.mul(&difference.x, &context.mul(&difference_uv, &difference_uv)),
+        };
+    }
+
+    fn multiply(&self, point: &Point<C::Residue>, k: u64) -> Point<C::Residue> {
+        // Montgomery's ladder: keeps `R1 - R0 = P`, so every addition knows its difference.
+        let mut r0: Point<C::Residue> = point.clone();
+        let mut r1: Point<C::Residue> = self.double(point);
+        let bits: u32 = 64 - k.leading_zeros();
+        for bit in (0..bits.saturating_sub(1)).rev() {
+            if (k >> bit) & 1 == 1 {
+                r0 = self.differential_add(&r1, &r0, point);
+                r1 = self.double(&r1);
+            } else {
+                r1 = self.differential_add(&r0, &r1, point);
+                r0 = self.double(&r0);
+            }
+        }
+        return r0;
+    }
+}
+
+pub fn ecm(num: u128, params: &EcmParams) -> Option<u128> {
+    //! Looks for a non-trivial factor of an odd composite number with up to `params.curves` curves, or returns
+    //! `None` if none of them finds one.
+    //!
+    //! Even numbers give `2` straight away. Each curve costs about `1.44 * B1` point doublings and additions in stage
+    //! 1 and `3` multiplications per prime till `B2` in stage 2.
+    if num % 2 == 0 {
+        return if num > 2 { Some(2) } else { None };
+    }
+    let context: Montgomery128 = Montgomery128::new(num)?;
+    let sigma_limit: u64 = core::cmp::min(num - 1, u64::MAX as u128) as u64;
+    if sigma_limit <= 6 {
+        return None;
+    }
+    return ecm_with(&context, params, sigma_limit);
+}
+
+pub fn ecm_with<C: MontgomeryContext>(
+    context: &C,
+    params: &EcmParams,
+    sigma_limit: u64,
+) -> Option<C::Int> {
+    //! Runs ECM modulo the odd composite of any `MontgomeryContext`, with the curve parameters `sigma` drawn from
+    //! `6` till `sigma_limit`, which must be below `n` and above `6`.
+    let num: C::Int = context.modulus();
+    let one: C::Int = C::Int::from(1);
+
+    // Every prime till `B1` with its largest power till `B1`, for stage 1.
+    let mut prime_powers: Vec<(u64, u64)> = Vec::new();
+    for prime in primes::find_primes_till(params.b1) {
+        let mut power: u64 = prime;
+        while power <= params.b1 / prime {
+            power = power * prime;
+        }
+        prime_powers.push((prime, power));
+    }
+    // The primes past `B1` till `B2`, for stage 2; entry `i` is `B1 + 1 + i`.
+    let stage_two_low: u64 = params.b1 + 1;
+    let stage_two_primes: Vec<bool> = if params.b2 > params.b1 {
+        sieve::sieve_flags(stage_two_low, params.b2, &sieve::base_primes(params.b2))
+    } else {
+        Vec::new()
+    };
+
+    let mut rng: SeededRng = SeededRng::new(params.seed);
+    for _ in 0..params.curves {
+        let sigma: u64 = rng.gen_range(6, sigma_limit);
+        let (a24, start): (C::Residue, Point<C::Residue>) = match suyama_curve(context, sigma) {
+            Ok(curve) => curve,
+            Err(factor) if factor != num => return Some(factor),
+            Err(_) => continue,
+        };
+        let curve: Curve<C> = Curve { context, a24 };
+
+        let point: Point<C::Residue> = match stage_one(&curve, start, &prime_powers) {
+            Ok(point) => point,
+            Err(factor) if factor != num => return Some(factor),
+            Err(_) => continue,
+        };
+
+        if stage_two_primes.is_empty() {
+            continue;
+        }
+        let factor: C::Int = stage_two(&curve, &point, params, stage_two_low, &stage_two_primes);
+        if factor != one && factor != num {
+            return Some(factor);
+        }
+    }
+    return None;
+}
+
+// `(A + 2) / 4` of a curve, and a point on it.
+type CurveStart<C> = (
+    <C as MontgomeryContext>::Residue,
+    Point<<C as MontgomeryContext>::Residue>,
+);
+
+fn suyama_curve<C: MontgomeryContext>(context: &C, sigma: u64) -> Result<CurveStart<C>, C::Int> {
+    // Suyama's curve for `sigma`: `u = sigma^2 - 5`, `v = 4 sigma`, the start point `(u^3 : v^3)`, and
+    // `(A + 2) / 4 = (v - u)^3 (3u + v) / (16 u^3 v)`. Gives the gcd instead when the denominator is not a unit.
+    let sigma: C::Residue = context.residue(sigma);
+    let u: C::Residue = context.sub(&context.mul(&sigma, &sigma), &context.residue(5));
+    let v: C::Residue = context.mul(&context.residue(4), &sigma);
+    let u_cubed: C::Residue = context.mul(&context.mul(&u, &u), &u);
+    let v_cubed: C::Residue = context.mul(&context.mul(&v, &v), &v);
+    let v_minus_u: C::Residue = context.sub(&v, &u);
+    let three_u_plus_v: C::Residue = context.add(&context.add(&context.add(&u, &u), &u), &v);
+    let numerator: C::Residue = context.mul(
+        &context.mul(&context.mul(&v_minus_u, &v_minus_u), &v_minus_u),
+        &three_u_plus_v,
+    );
+    let denominator: C::Residue = context.mul(&context.mul(&context.residue(16), &u_cubed), &v);
+
+    let inverse: C::Residue = context.inverse(&denominator)?;
+    let start: Point<C::Residue> = Point {
+        x: u_cubed,
+        z: v_cubed,
+    };
+    return Ok((context.mul(&numerator, &inverse), start));
+}
+
+fn stage_one<C: MontgomeryContext>(
+    curve: &Curve<C>,
+    start: Point<C::Residue>,
+    prime_powers: &[(u64, u64)],
+) -> Result<Point<C::Residue>, C::Int> {
+    // Multiplies `start` by every prime power, taking the gcd of `Z` with `n` after each batch. Gives the gcd instead
+    // once it is not `1`. For a small `n` the group orders modulo every prime factor are all smooth, so a batch often
+    // takes `Z` to `0` modulo all of them at once; that batch is then retraced from its start one prime at a time,
+    // which separates them unless a single prime finishes them together.
+    let num: C::Int = curve.context.modulus();
+    let one: C::Int = C::Int::from(1);
+    let mut point: Point<C::Residue> = start;
+    for batch in prime_powers.chunks(STAGE_ONE_BATCH) {
+        let checkpoint: Point<C::Residue> = point.clone();
+        for (_, power) in batch {
+            point = curve.multiply(&point, *power);
+        }
+        let factor: C::Int = curve.context.gcd(&point.z);
+        if factor == one {
+            continue;
+        } else if factor != num {
+            return Err(factor);
+        }
+
+        point = checkpoint;
+        for (prime, power) in batch {
+            let mut multiplied: u64 = 1;
+            while multiplied < *power {
+                point = curve.multiply(&point, *prime);
+                multiplied = multiplied * prime;
+                let factor: C::Int = curve.context.gcd(&point.z);
+                if factor != one {
+                    return Err(factor);
+                }
+            }
+        }
+    }
+    return Ok(point);
+}
+
+fn stage_two<C: MontgomeryContext>(
+    curve: &Curve<C>,
+    point: &Point<C::Residue>,
+    params: &EcmParams,
+    low: u64,
+    primes: &[bool],
+) -> C::Int {
+    // For every prime `q = mD +- j` from `B1` till `B2`, `qQ` is the identity modulo `p` exactly when `mDQ` and
+    // `jQ` have the same `x` there, i.e. `p` divides `X(mDQ) Z(jQ) - X(jQ) Z(mDQ)`. Returns the gcd of all these
+    // cross products with `n`.
+    let context: &C = curve.context;
+    let is_prime = |candidate: u64| -> bool {
+        return candidate >= low && candidate <= params.b2 && primes[(candidate - low) as usize];
+    };
+
+    // Baby steps: `jQ` for the odd `j` below `D / 2` that are coprime to `D`, built two at a time from `2Q`.
+    let doubled: Point<C::Residue> = curve.double(point);
+    let mut baby_steps: Vec<(u64, Point<C::Residue>)> = Vec::new();
+    let mut previous: Point<C::Residue> = point.clone();
+    let mut current: Point<C::Residue> = curve.differential_add(point, &doubled, point); // `3Q`
+    baby_steps.push((1, point.clone()));
+    let mut j: u64 = 3;
+    while j < GIANT_STEP / 2 {
+        if modular::gcd_u128(j as u128, GIANT_STEP as u128) == 1 {
+            baby_steps.push((j, current.clone()));
+        }
+        let next: Point<C::Residue> = curve.differential_add(&current, &doubled, &previous);
+        previous = current;
+        current = next;
+        j = j + 2;
+    }
+
+    // Giant steps: `mDQ` for `m = 1, 2, ...`, each from the two before it.
+    let giant: Point<C::Residue> = curve.multiply(point, GIANT_STEP);
+    let mut previous_giant: Point<C::Residue> = giant.clone();
+    let mut current_giant: Point<C::Residue> = giant.clone();
+    let mut product: C::Residue = context.residue(1);
+    let mut m: u64 = 1;
+    while (m * GIANT_STEP).saturating_sub(GIANT_STEP / 2) <= params.b2 {
+        let centre: u64 = m * GIANT_STEP;
+        for (j, baby) in baby_steps.iter() {
+            if is_prime(centre - j) || is_prime(centre + j) {
+                let cross: C::Residue = context.sub(
+                    &context.mul(&current_giant.x, &baby.z),
+                    &context.mul(&baby.x, &current_giant.z),
+                );
+                product = context.mul(&product, &cross);
+            }
+        }
+
+        let next: Point<C::Residue> = if m == 1 {
+            curve.double(&giant)
+        } else {
+            curve.differential_add(&current_giant, &giant, &previous_giant)
+        };
+        previous_giant = current_giant;
+        current_giant = next;
+        m = m + 1;
+    }
+    return context.gcd(&product);
+}
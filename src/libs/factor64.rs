@@ -0,0 +1,168 @@
+//! Factoring methods for composites that fit in 64 bits: Shanks' square forms factorization (SQUFOF) and Hart's
+//! one-line factorization.
+//!
+//! Neither needs more than `u128` intermediates, and both take a number of steps that depends only on the size of
+//! `n`, rather than on its smallest factor as Pollard's rho does, so they have no unlucky inputs.
+use crate::libs::modular;
+use crate::libs::primality;
+
+/// Shanks' square-free multipliers, after Gower and Wagstaff; `kN` is tried for each until one splits `N`.
+const SQUFOF_MULTIPLIERS: [u128; 16] = [
+    1,
+    3,
+    5,
+    7,
+    11,
+    3 * 5,
+    3 * 7,
+    3 * 11,
+    5 * 7,
+    5 * 11,
+    7 * 11,
+    3 * 5 * 7,
+    3 * 5 * 11,
+    3 * 7 * 11,
+    5 * 7 * 11,
+    3 * 5 * 7 * 11,
+];
+
+/// Bit `r` is set when `r` is a square modulo `64`.
+const SQUARES_MOD_64: u64 = 0x0202_0212_0203_0213;
+
+/// Hart's multiplier: trying `480 i n` instead of `i n` makes `s^2 mod n` a square far more often.
+const HART_MULTIPLIER: u128 = 480;
+
+pub fn squfof(num: u64) -> Option<u64> {
+    //! Looks for a non-trivial factor of a composite number with Shanks' square forms factorization, or returns
+    //! `None` if every multiplier fails (or the number is prime).
+    //!
+    //! Walks the continued fraction of `sqrt(kN)` until it reaches a square form, then walks back from its square
+    //! root to a form that shares a factor with `N`; both walks take `O(N^(1/4))` steps.
+    if num < 4 {
+        return None;
+    }
+    if num % 2 == 0 {
+        return Some(2);
+    }
+    let root: u64 = primality::isqrt_u128(num as u128) as u64;
+    if root * root == num {
+        return Some(root);
+    }
+
+    // Forms past this many steps are given up on; `3 * 2 sqrt(2 sqrt(N))`, as Shanks suggests.
+    let bound: u64 = 6 * primality::isqrt_u128(2 * root as u128) as u64;
+    for multiplier in SQUFOF_MULTIPLIERS {
+        if let Some(factor) = squfof_with(num, multiplier * num as u128, bound) {
+            return Some(factor);
+        }
+    }
+    return None;
+}
+
+fn squfof_with(num: u64, scaled: u128, bound: u64) -> Option<u64> {
+    // A single SQUFOF run on `kN`. `kN` is below `2^75`, so `P` and the `Q`s, which stay below `2 sqrt(kN)`, fit in
+    // `i64`; only the square root itself needs `u128`.
+    let root: i64 = primality::isqrt_u128(scaled) as i64;
+    let mut p: i64 = root;
+    let mut previous_q: i64 = 1;
+    let mut q: i64 = (scaled - (root as u128) * (root as u128)) as i64;
+    if q == 0 {
+        return None;
+    }
+
+    // Forward walk, till a square `Q` at an even step.
+    let mut square_root: i64 = 0;
+    let mut step: u64 = 2;
+    while step < bound {
+        let b: i64 = (root + p) / q;
+        let next_p: i64 = b * q - p;
+        let next_q: i64 = previous_q + b * (p - next_p);
+        previous_q = q;
+        q = next_q;
+        p = next_p;
+        if step % 2 == 0 {
+            if let Some(candidate) = square_root_of(q as u64) {
+                square_root = candidate as i64;
+                break;
+            }
+        }
+        step = step + 1;
+    }
+    if square_root == 0 {
+        return None;
+    }
+
+    // Reverse walk from the square root of that form, till `P` repeats.
+    let b: i64 = (root - p) / square_root;
+    p = b * square_root + p;
+    previous_q = square_root;
+    q = ((scaled - (p as u128) * (p as u128)) / previous_q as u128) as i64;
+    if q == 0 {
+        return None;
+    }
+    for _ in 0..bound {
+        let b: i64 = (root + p) / q;
+        let next_p: i64 = b * q - p;
+        let next_q: i64 = previous_q + b * (p - next_p);
+        previous_q = q;
+        q = next_q;
+        if next_p == p {
+            break;
+        }
+        p = next_p;
+    }
+
+    let factor: u64 = modular::gcd_u128(num as u128, previous_q as u128) as u64;
+    if factor > 1 && factor < num {
+        return Some(factor);
+    }
+    return None;
+}
+
+fn square_root_of(num: u64) -> Option<u64> {
+    // The square root of `num` if it is a perfect square. Only `12` of the `64` residues modulo `64` are squares, so
+    // most numbers are ruled out before taking a root.
+    if (SQUARES_MOD_64 >> (num & 63)) & 1 == 0 {
+        return None;
+    }
+    let root: u64 = primality::isqrt_u128(num as u128) as u64;
+    if root * root == num {
+        return Some(root);
+    }
+    return None;
+}
+
+pub fn hart_one_line(num: u64, max_iterations: u64) -> Option<u64> {
+    //! Looks for a non-trivial factor of a composite number with Hart's one-line factorization, or returns `None`
+    //! if none turns up within `max_iterations` steps.
+    //!
+    //! For `i = 1, 2, ...`, takes `s = ceil(sqrt(480 i n))`; once `s^2 mod n` is a square `t^2`, `gcd(s - t, n)` is
+    //! usually a factor. Takes `O(n^(1/3))` steps, but cheaper ones than SQUFOF, so it keeps up with it below about
+    //! `2^20`.
+    if num < 4 {
+        return None;
+    }
+    if num % 2 == 0 {
+        return Some(2);
+    }
+    let root: u64 = primality::isqrt_u128(num as u128) as u64;
+    if root * root == num {
+        return Some(root);
+    }
+
+    for i in 1..=max_iterations as u128 {
+        let product: u128 = HART_MULTIPLIER * i * num as u128;
+        let mut s: u128 = primality::isqrt_u128(product);
+        if s * s < product {
+            s = s + 1;
+        }
+        let residue: u64 = (s * s % num as u128) as u64;
+        if let Some(t) = square_root_of(residue) {
+            let factor: u64 = modular::gcd_u128(s - t as u128, num as u128) as u64;
+            if factor > 1 && factor < num {
+                return Some(factor);
+            }
+        }
+    }
+    return None;
+}
@@ -0,0 +1,316 @@
+//! Functions/methods to find the prime factorization of a number.
+//!
+//! Numbers are first trial-divided by the known primes; whatever is left is split until every piece passes the
+//! primality test. Perfect powers are split by taking their root; other composites are split with the method a
+//! `FactorStrategy` picks. By default that is Hart's one-line factorization or SQUFOF for pieces that fit in 64
+//! bits; larger ones get a short run of Pollard's rho (Brent's variant) for their small factors, then a few curves of
+//! the elliptic curve method for medium ones, then the self-initializing quadratic sieve. A full ECM run is the
+//! fallback.
+//!
+//! SIQS takes the same time whatever the factors are, while ECM's time grows with the smallest one, so the ECM pass
+//! before SIQS is sized to a fraction of what SIQS would cost (see `siqs_ecm_curves`): enough to find most factors
+//! of up to about 13 digits in numbers past `2^100`, without slowing down the products of two similar primes much.
+use alloc::vec::Vec;
+
+use crate::libs::constants;
+use crate::libs::ecm::{self, EcmParams, MontgomeryContext};
+use crate::libs::factor64;
+use crate::libs::modular::Montgomery128;
+use crate::libs::powers;
+use crate::libs::primality;
+use crate::libs::siqs;
+
+// Number of rho steps whose differences are multiplied together before taking a single gcd.
+const BRENT_BATCH_SIZE: u128 = 128;
+
+// Longest rho cycle tried before handing a number to SIQS; about `2^14` steps, enough for factors of up to ~28 bits.
+const BRENT_CYCLE_LIMIT: u128 = 1 << 13;
+
+/// Pieces past this many bits get `siqs_ecm_curves` ECM curves before SIQS; below it SIQS takes only a few
+/// milliseconds, less than a handful of curves.
+const SIQS_ECM_BITS: u32 = 96;
+
+/// Pieces below this are split with Hart's one-line factorization by `FactorStrategy::Auto`; past it SQUFOF is faster.
+const HART_LIMIT: u128 = 1 << 20;
+
+/// Pieces below this (and past `HART_LIMIT`) are split with SQUFOF by `FactorStrategy::Auto`.
+const SQUFOF_LIMIT: u128 = 1 << 62;
+
+/// The method used to split the composite pieces of a number, for `factorize_with`.
+///
+/// Whichever is picked, a piece it cannot split falls back to `Auto`, so the factorization is always complete.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FactorStrategy {
+    /// Picks a method by the size of each piece: Hart's one-line factorization below `2^20`, SQUFOF below `2^62`,
+    /// and beyond that a short run of Pollard's rho for small factors and, past `2^96`, a few ECM curves for medium
+    /// ones, followed by SIQS.
+    #[default]
+    Auto,
+    /// Brent's variant of Pollard's rho; `O(sqrt(p))` steps for the smallest prime factor `p`.
+    PollardRho,
+    /// Shanks' square forms factorization, for pieces below `2^64`; `O(n^(1/4))` steps.
+    Squfof,
+    /// Hart's one-line factorization, for pieces below `2^64`; `O(n^(1/3))` steps.
+    HartOneLine,
+    /// Lenstra's elliptic curve method, at growing factor sizes; the number of steps depends on the smallest
+    /// prime factor only.
+    Ecm,
+    /// The self-initializing quadratic sieve, for pieces of `2^40` and over; the number of steps depends on the size
+    /// of the piece only.
+    Siqs,
+}
+
+pub fn factorize_u128(num: u128) -> Vec<(u128, u32)> {
+    //! Finds the prime factorization of a given number, as `(prime, exponent)` pairs in increasing order.
+    //!
+    //! `0` and `1` have no prime factors, so the list is empty for them.
+    return factorize_with(num, FactorStrategy::Auto);
+}
+
+pub fn factorize_with(num: u128, strategy: FactorStrategy) -> Vec<(u128, u32)> {
+    //! Finds the prime factorization of a given number like `factorize_u128`, splitting its composite pieces with
+    //! the given strategy.
+    let mut prime_factors: Vec<u128> = Vec::new();
+    if num < 2 {
+        return Vec::new();
+    }
+
+    let mut rest: u128 = num;
+    for prime in constants::KNOWN_PRIMES {
+        while rest % prime as u128 == 0 {
+            prime_factors.push(prime as u128);
+            rest = rest / prime as u128;
+        }
+    }
+    split_into_primes(rest, strategy, &mut prime_factors);
+
+    prime_factors.sort();
+    return group_factors(&prime_factors);
+}
+
+pub fn factorize_trial(num: u64) -> Vec<(u64, u32)> {
+    //! Finds the prime factorization of a given number by plain trial division.
+    //!
+    //! Only meant for small numbers, where it beats Pollard's rho.
+    let mut prime_factors: Vec<u128> = Vec::new();
+    let mut rest: u64 = num;
+    let mut divisor: u64 = 2;
+    while num > 1 && divisor * divisor <= rest {
+        while rest % divisor == 0 {
+            prime_factors.push(divisor as u128);
+            rest = rest / divisor;
+        }
+        divisor = divisor + if divisor == 2 { 1 } else { 2 };
+    }
+    if rest > 1 {
+        prime_factors.push(rest as u128);
+    }
+
+    let factors: Vec<(u64, u32)> = group_factors(&prime_factors)
+        .into_iter()
+        .map(|(prime, exponent)| (prime as u64, exponent))
+        .collect();
+    return factors;
+}
+
+fn split_into_primes(num: u128, strategy: FactorStrategy, prime_factors: &mut Vec<u128>) {
+    // Recursively splits `num` (which has no factors among the known primes) into its prime factors.
+    if num == 1 {
+        return;
+    } else if primality::check_if_prime_u128(num) {
+        prime_factors.push(num);
+        return;
+    }
+
+    // Rho finds `p` in `p^k` only after `O(sqrt(p))` steps like any other factor; a root is much cheaper.
+    if let Some((base, exponent)) = powers::check_if_perfect_power_u128(num) {
+        let mut base_factors: Vec<u128> = Vec::new();
+        split_into_primes(base, strategy, &mut base_factors);
+        for _ in 0..exponent {
+            prime_factors.extend_from_slice(&base_factors);
+        }
+        return;
+    }
+
+    let factor: u128 = find_factor(num, strategy);
+    split_into_primes(factor, strategy, prime_factors);
+    split_into_primes(num / factor, strategy, prime_factors);
+}
+
+fn group_factors(sorted_primes: &[u128]) -> Vec<(u128, u32)> {
+    // Turns a sorted list of primes into `(prime, exponent)` pairs.
+    let mut factors: Vec<(u128, u32)> = Vec::new();
+    for prime in sorted_primes {
+        match factors.last_mut() {
+            Some((last, exponent)) if last == prime => *exponent = *exponent + 1,
+            _ => factors.push((*prime, 1)),
+        }
+    }
+    return factors;
+}
+
+fn find_factor(num: u128, strategy: FactorStrategy) -> u128 {
+    // A non-trivial factor of an odd composite that is not a perfect power.
+    let factor: Option<u128> = match strategy {
+        FactorStrategy::Auto => None,
+        FactorStrategy::PollardRho => Some(pollard_brent(num)),
+        FactorStrategy::Squfof => u64::try_from(num)
+            .ok()
+            .and_then(factor64::squfof)
+            .map(|factor| factor as u128),
+        FactorStrategy::HartOneLine => u64::try_from(num)
+            .ok()
+            .and_then(|num| factor64::hart_one_line(num, hart_iterations(num)))
+            .map(|factor| factor as u128),
+        FactorStrategy::Ecm => find_factor_ecm(num),
+        FactorStrategy::Siqs => siqs::siqs(num),
+    };
+    if let Some(factor) = factor {
+        return factor;
+    }
+
+    if num < HART_LIMIT {
+        if let Some(factor) = factor64::hart_one_line(num as u64, hart_iterations(num as u64)) {
+            return factor as u128;
+        }
+    }
+    if num < SQUFOF_LIMIT {
+        if let Some(factor) = factor64::squfof(num as u64) {
+            return factor as u128;
+        }
+    }
+    // A short rho run catches small factors far sooner than SIQS, whose time depends only on the size of `num`, and
+    // a few ECM curves catch most medium ones; SIQS then splits whatever is left, even a product of two primes of the
+    // same size. A full ECM run and unbounded rho are the fallbacks if even it fails.
+    if let Some(factor) = pollard_brent_with(num, 2, 1, BRENT_CYCLE_LIMIT) {
+        return factor;
+    }
+    let curves: u32 = siqs_ecm_curves(128 - num.leading_zeros());
+    if curves > 0 {
+        let (_, b1, _): (u32, u64, u32) = ecm::DIGIT_LEVELS[0];
+        let params: EcmParams = EcmParams::new(b1, 100 * b1, curves, ecm::DEFAULT_SEED);
+        if let Some(factor) = ecm::ecm(num, &params) {
+            return factor;
+        }
+    }
+    if let Some(factor) = siqs::siqs(num) {
+        return factor;
+    }
+    if let Some(factor) = find_factor_ecm(num) {
+        return factor;
+    }
+    return pollard_brent(num);
+}
+
+fn find_factor_ecm(num: u128) -> Option<u128> {
+    // ECM at each of its digit levels in turn.
+    for (digits, _, _) in ecm::DIGIT_LEVELS {
+        if let Some(factor) = ecm::ecm(num, &EcmParams::for_digits(digits)) {
+            return Some(factor);
+        }
+    }
+    return None;
+}
+
+pub fn siqs_ecm_curves(bits: u32) -> u32 {
+    //! The number of ECM curves at the `15`-digit bound worth running on a number of `bits` bits before SIQS.
+    //
+    // One curve at the 15-digit bound takes about 1.7ms at 128 bits, and SIQS about 80ms there, 25ms at 112 bits and
+    // 7ms at 96 bits; one curve per 2 bits past `SIQS_ECM_BITS` keeps the pass at a fifth to a third of SIQS's time.
+    // Each curve finds a 40-bit factor with a probability of about `0.18`, so 128-bit numbers give up on one after 16
+    // curves only `4%` of the time.
+    return bits.saturating_sub(SIQS_ECM_BITS) / 2;
+}
+
+fn hart_iterations(num: u64) -> u64 {
+    // Hart's method usually succeeds within `n^(1/3)` steps; a few times that is allowed before giving up.
+    return 4 * powers::integer_nth_root(num, 3) + 1_000;
+}
+
+pub fn pollard_brent(num: u128) -> u128 {
+    //! Finds a non-trivial factor of an odd composite number using Brent's variant of Pollard's rho.
+    //!
+    //! Tries the polynomials `x^2 + c` for `c = 1, 2, 3, ...` until one of them splits `num`. The expected number of
+    //! steps is `O(sqrt(p))` for the smallest prime factor `p`, which is fine till about 40 bits; use
+    //! `ecm::ecm` for larger factors.
+    if num % 2 == 0 {
+        return 2;
+    }
+    let mut c: u128 = 1;
+    loop {
+        if let Some(factor) = pollard_brent_with(num, 2, c, u128::MAX) {
+            return factor;
+        }
+        c = c + 1;
+    }
+}
+
+fn pollard_brent_with(num: u128, start: u128, c: u128, max_cycle: u128) -> Option<u128> {
+    // A single run of `brent_rho` modulo `num`.
+    let context: Montgomery128 = Montgomery128::new(num)?;
+    return brent_rho(
+        &context,
+        &(start % num),
+        &context.to_montgomery(c),
+        max_cycle,
+    );
+}
+
+pub fn brent_rho<C: MontgomeryContext>(
+    context: &C,
+    start: &C::Residue,
+    c: &C::Residue,
+    max_cycle: u128,
+) -> Option<C::Int> {
+    //! A single run of Brent's cycle-finding with the polynomial `x^2 + c` in Montgomery form, starting from
+    //! `start`; gives up once the cycle length passes `max_cycle`. Montgomery form maps `x^2 + c` to another
+    //! quadratic polynomial and scales every difference by a unit, so the gcds are unaffected.
+    let num: C::Int = context.modulus();
+    let one: C::Int = C::Int::from(1);
+    let step = |x: &C::Residue| -> C::Residue { context.add(&context.mul(x, x), c) };
+
+    let mut y: C::Residue = start.clone();
+    let mut x: C::Residue = y.clone();
+    let mut saved_y: C::Residue = y.clone();
+    let mut product: C::Residue = context.residue(1);
+    let mut g: C::Int = one.clone();
+    let mut cycle_length: u128 = 1;
+    while g == one {
+        if cycle_length > max_cycle {
+            return None;
+        }
+        x = y.clone();
+        for _ in 0..cycle_length {
+            y = step(&y);
+        }
+
+        let mut k: u128 = 0;
+        while k < cycle_length && g == one {
+            saved_y = y.clone();
+            let batch: u128 = core::cmp::min(BRENT_BATCH_SIZE, cycle_length - k);
+            for _ in 0..batch {
+                y = step(&y);
+                product = context.mul(&product, &context.sub(&x, &y));
+            }
+            g = context.gcd(&product);
+            k = k + BRENT_BATCH_SIZE;
+        }
+        cycle_length = cycle_length * 2;
+    }
+
+    if g == num {
+        // The batch overshot; retrace it one step at a time.
+        loop {
+            saved_y = step(&saved_y);
+            g = context.gcd(&context.sub(&x, &saved_y));
+            if g != one {
+                break;
+            }
+        }
+    }
+
+    if g == num {
+        return None;
+    }
+    return Some(g);
+}
@@ -0,0 +1,127 @@
+//! Gaps between consecutive primes, streamed from the segmented sieve.
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::libs::sieve::PrimesInRange;
+
+/// Iterator over the gaps between consecutive primes in a range, as `(p, gap)` pairs where `p + gap` is the next
+/// prime.
+///
+/// Only the current prime is kept between segments, so memory use stays constant however long the range is.
+#[derive(Debug, Clone)]
+pub struct PrimeGaps {
+    primes: PrimesInRange,
+    previous: Option<u64>,
+}
+
+impl PrimeGaps {
+    pub fn new(low: u64, high: u64) -> Self {
+        //! Creates an iterator over the gaps between consecutive primes that are both from `low` till `high`,
+        //! inclusive.
+        let mut primes: PrimesInRange = PrimesInRange::new(low, high);
+        let previous: Option<u64> = primes.next();
+        return PrimeGaps { primes, previous };
+    }
+}
+
+impl Iterator for PrimeGaps {
+    type Item = (u64, u64);
+
+    fn next(&mut self) -> Option<(u64, u64)> {
+        let previous: u64 = self.previous?;
+        self.previous = self.primes.next();
+        return self.previous.map(|prime| (previous, prime - previous));
+    }
+}
+
+pub fn find_maximal_gaps_till(num: u64) -> Vec<(u64, u64)> {
+    //! Finds the maximal prime gaps till a given number (OEIS A002386 and A005250): the `(p, gap)` pairs whose
+    //! gap is larger than every gap before them, with `p + gap` till the given number.
+    let mut maximal_gaps: Vec<(u64, u64)> = Vec::new();
+    let mut record: u64 = 0;
+    for (prime, gap) in PrimeGaps::new(0, num) {
+        if gap > record {
+            record = gap;
+            maximal_gaps.push((prime, gap));
+        }
+    }
+    return maximal_gaps;
+}
+
+/// Summary of the prime gaps in a range: a histogram of the gap sizes, their mean, the largest gap, and (with
+/// `std`) the largest merit `gap / ln(p)`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GapStats {
+    count: u64,
+    total: u64,
+    histogram: BTreeMap<u64, u64>,
+    max_gap: Option<(u64, u64)>,
+    #[cfg(feature = "std")]
+    max_merit: Option<(u64, u64, f64)>,
+}
+
+impl GapStats {
+    pub fn new(low: u64, high: u64) -> Self {
+        //! Collects the statistics of the gaps between consecutive primes from `low` till `high`, inclusive.
+        let mut stats: GapStats = GapStats::default();
+        for (prime, gap) in PrimeGaps::new(low, high) {
+            stats.add(prime, gap);
+        }
+        return stats;
+    }
+
+    pub fn add(&mut self, prime: u64, gap: u64) {
+        //! Adds the gap from `prime` to the next prime, so statistics can also be merged across ranges.
+        self.count += 1;
+        self.total += gap;
+        *self.histogram.entry(gap).or_insert(0) += 1;
+        match self.max_gap {
+            Some((_, max_gap)) if max_gap >= gap => {}
+            _ => self.max_gap = Some((prime, gap)),
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let merit: f64 = merit(prime, gap);
+            match self.max_merit {
+                Some((_, _, max_merit)) if max_merit >= merit => {}
+                _ => self.max_merit = Some((prime, gap, merit)),
+            }
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        //! The number of gaps.
+        return self.count;
+    }
+
+    pub fn histogram(&self) -> &BTreeMap<u64, u64> {
+        //! The number of gaps of each size.
+        return &self.histogram;
+    }
+
+    pub fn mean(&self) -> f64 {
+        //! The mean gap, or `0.0` if there are no gaps.
+        if self.count == 0 {
+            return 0.0;
+        }
+        return self.total as f64 / self.count as f64;
+    }
+
+    pub fn max_gap(&self) -> Option<(u64, u64)> {
+        //! The first largest gap, as `(p, gap)`.
+        return self.max_gap;
+    }
+
+    #[cfg(feature = "std")]
+    pub fn max_merit(&self) -> Option<(u64, u64, f64)> {
+        //! The first gap with the largest merit, as `(p, gap, merit)`.
+        return self.max_merit;
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn merit(prime: u64, gap: u64) -> f64 {
+    //! The merit of a gap, `gap / ln(p)`: how many times larger it is than the average gap near `p`.
+    return gap as f64 / (prime as f64).ln();
+}
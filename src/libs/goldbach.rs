@@ -0,0 +1,101 @@
+//! Goldbach partitions: ways of writing an even number as the sum of two primes.
+use alloc::vec::Vec;
+
+use crate::libs::primality;
+use crate::libs::primes;
+use crate::libs::sieve;
+
+/// Even numbers checked at a time by `verify_goldbach_range`.
+const SEGMENT_SIZE: u64 = 1 << 16;
+
+/// The smallest primes of the partitions are searched for below this first, using the sieve; the minimal prime
+/// stays below a few thousand for every even number tested so far, so this is almost never exceeded.
+const SMALL_PRIME_LIMIT: u64 = 1 << 16;
+
+pub fn find_goldbach_partitions(num: u64) -> Vec<(u64, u64)> {
+    //! Finds the pairs of primes `(p, q)` with `p <= q` and `p + q = num`, in increasing order of `p`.
+    //!
+    //! Sieves the primes till `num` once and pairs each `p <= num / 2` with `num - p`; odd numbers and numbers
+    //! below `4` have no partitions here.
+    return partitions(num).collect();
+}
+
+pub fn count_goldbach_partitions(num: u64) -> u64 {
+    //! Counts the pairs of primes `(p, q)` with `p <= q` and `p + q = num`.
+    return partitions(num).count() as u64;
+}
+
+fn partitions(num: u64) -> impl Iterator<Item = (u64, u64)> {
+    // The partitions of `num` in increasing order of `p`, from one sieve of the primes till `num`.
+    let (last, flags): (u64, Vec<bool>) = if num < 4 || num % 2 == 1 {
+        (1, Vec::new())
+    } else {
+        (
+            num / 2,
+            sieve::sieve_flags(0, num, &sieve::base_primes(num)),
+        )
+    };
+    return (2..=last)
+        .filter(move |small| flags[*small as usize] && flags[(num - small) as usize])
+        .map(move |small| (small, num - small));
+}
+
+pub fn verify_goldbach_range(low: u64, high: u64) -> Result<Vec<(u64, u64)>, u64> {
+    //! Checks that every even number from `low` till `high` (and from `4` on) is the sum of two primes.
+    //!
+    //! Returns the pairs `(n, p)` of each even number `n` with the smallest prime `p` for which `n - p` is
+    //! prime, or the first even number without a partition as the error. Each segment of numbers is sieved
+    //! together with the `SMALL_PRIME_LIMIT` numbers below it, so `n - p` is a table lookup for every small
+    //! `p`; larger `p` fall back to Miller-Rabin.
+    let small_primes: Vec<u64> = primes::find_primes_till(SMALL_PRIME_LIMIT);
+    let base_primes: Vec<u64> = sieve::base_primes(high);
+    let mut minimal_primes: Vec<(u64, u64)> = Vec::new();
+    let mut segment_low: u64 = core::cmp::max(low, 4);
+    while segment_low <= high {
+        let segment_high: u64 = core::cmp::min(high, segment_low.saturating_add(SEGMENT_SIZE - 1));
+        let window_low: u64 = segment_low.saturating_sub(SMALL_PRIME_LIMIT);
+        let flags: Vec<bool> = sieve::sieve_flags(window_low, segment_high, &base_primes);
+
+        let mut num: u64 = match segment_low.checked_add(segment_low % 2) {
+            Some(num) => num,
+            None => break,
+        };
+        while num <= segment_high {
+            let minimal: Option<u64> = match small_primes
+                .iter()
+                .take_while(|prime| **prime <= num / 2)
+                .find(|prime| flags[(num - **prime - window_low) as usize])
+            {
+                Some(prime) => Some(*prime),
+                None => find_large_partition(num),
+            };
+            match minimal {
+                Some(prime) => minimal_primes.push((num, prime)),
+                None => return Err(num),
+            }
+            num = match num.checked_add(2) {
+                Some(num) => num,
+                None => break,
+            };
+        }
+
+        segment_low = match segment_high.checked_add(1) {
+            Some(segment_low) => segment_low,
+            None => break,
+        };
+    }
+    return Ok(minimal_primes);
+}
+
+fn find_large_partition(num: u64) -> Option<u64> {
+    //! Finds the smallest prime `p > SMALL_PRIME_LIMIT` for which `num - p` is prime, with Miller-Rabin.
+    let mut candidate: u64 = SMALL_PRIME_LIMIT + 1;
+    while candidate <= num / 2 {
+        if primality::check_if_prime_mr(candidate) && primality::check_if_prime_mr(num - candidate)
+        {
+            return Some(candidate);
+        }
+        candidate += 2;
+    }
+    return None;
+}
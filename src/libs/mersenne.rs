@@ -0,0 +1,78 @@
+//! Mersenne primes `2^p - 1`, tested with Lucas-Lehmer on arbitrary-precision residues.
+use alloc::vec::Vec;
+
+use crate::libs::bigint::BigUint;
+use crate::libs::modular;
+use crate::libs::primality;
+
+/// Candidate factors `2kp + 1` of `2^p - 1` are tried below this bound before running Lucas-Lehmer.
+const TRIAL_FACTOR_LIMIT: u64 = 1 << 20;
+
+pub fn check_if_mersenne_prime(exponent: u32) -> bool {
+    //! Checks whether `2^exponent - 1` is prime.
+    //!
+    //! `2^p - 1` can only be prime for prime `p`, and any factor of it has the form `2kp + 1` and is `1` or `7`
+    //! modulo `8`; those factors are tried first since most composite candidates have a small one. What is left
+    //! goes to the Lucas-Lehmer test: with `s(0) = 4` and `s(i+1) = s(i)^2 - 2`, the number `M = 2^p - 1` is prime
+    //! iff `s(p-2) = 0` modulo `M`. Each reduction modulo `M` is a shift and an add, since `2^p = 1` modulo `M`.
+    if exponent == 2 {
+        return true;
+    }
+    if !primality::check_if_prime_u32(exponent) {
+        return false;
+    }
+    if has_small_factor(exponent) {
+        return false;
+    }
+
+    let mersenne: BigUint = BigUint::power_of_two_minus_one(exponent);
+    let two: BigUint = BigUint::from_u128(2);
+    let mut residue: BigUint = BigUint::from_u128(4);
+    for _ in 0..(exponent - 2) {
+        residue = reduce(&residue.square(), exponent, &mersenne);
+        residue = match residue.checked_sub(&two) {
+            Some(residue) => residue,
+            None => residue.add(&mersenne).checked_sub(&two).unwrap(),
+        };
+    }
+
+    return residue.is_zero() || residue == mersenne;
+}
+
+pub fn find_mersenne_exponents_till(limit: u32) -> Vec<u32> {
+    //! Finds all the exponents `p` till a given number for which `2^p - 1` is prime.
+    let mut exponents: Vec<u32> = Vec::new();
+    for exponent in 2..=limit {
+        if check_if_mersenne_prime(exponent) {
+            exponents.push(exponent);
+        }
+    }
+    return exponents;
+}
+
+fn has_small_factor(exponent: u32) -> bool {
+    //! Looks for a factor `q = 2kp + 1 < TRIAL_FACTOR_LIMIT` of `2^p - 1`, other than `2^p - 1` itself.
+    let step: u64 = 2 * exponent as u64;
+    let mut factor: u64 = step + 1;
+    while factor < TRIAL_FACTOR_LIMIT {
+        if exponent < 64 && factor >= (1_u64 << exponent) - 1 {
+            return false;
+        }
+        if (factor % 8 == 1 || factor % 8 == 7) && modular::mod_pow(2, exponent as u64, factor) == 1
+        {
+            return true;
+        }
+        factor += step;
+    }
+    return false;
+}
+
+fn reduce(num: &BigUint, exponent: u32, mersenne: &BigUint) -> BigUint {
+    //! Reduces a number modulo `2^p - 1` by folding the bits above `p` back onto the bottom, leaving a value in
+    //! `0..=2^p - 1`.
+    let mut num: BigUint = num.clone();
+    while num > *mersenne {
+        num = num.low_bits(exponent as u64).add(&num.shr(exponent as u64));
+    }
+    return num;
+}
@@ -1,6 +1,56 @@
 //! Holds the libary files for the package.
+//!
+//! Modules that need `std` (or an allocator) are gated behind the `std` (or `alloc`) feature; the arbitrary-precision
+//! primality and factorization functions are gated behind `bignum`.
 
-pub mod cache_map;
+#[cfg(feature = "alloc")]
+pub mod arithmetic;
+#[cfg(feature = "alloc")]
+pub mod bigint;
+#[cfg(feature = "bignum")]
+pub mod bignum;
+#[cfg(feature = "alloc")]
+pub mod certificate;
 pub mod constants;
+#[cfg(feature = "alloc")]
+pub mod constellations;
+#[cfg(feature = "alloc")]
+pub mod cunningham;
+#[cfg(feature = "alloc")]
+pub mod ecm;
+pub mod factor64;
+#[cfg(feature = "alloc")]
+pub mod factorization;
+#[cfg(feature = "alloc")]
+pub mod gaps;
+#[cfg(feature = "alloc")]
+pub mod goldbach;
+#[cfg(feature = "alloc")]
+pub mod mersenne;
+pub mod modular;
+#[cfg(feature = "alloc")]
+pub mod multiplicative;
+#[cfg(feature = "alloc")]
+pub mod perfect;
+pub mod powers;
+pub mod primality;
+pub mod prime_int;
+#[cfg(feature = "alloc")]
 pub mod primes;
+pub mod quadratic;
+pub mod random;
+#[cfg(feature = "alloc")]
+pub mod records;
+#[cfg(feature = "alloc")]
+pub mod sieve;
+#[cfg(feature = "alloc")]
+pub mod siqs;
+#[cfg(feature = "alloc")]
+pub mod smooth;
+#[cfg(feature = "alloc")]
+pub mod spf;
+pub mod tables;
+#[cfg(feature = "alloc")]
 pub mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,427 @@
+//! Allocation-free modular arithmetic helpers.
+//!
+//! All of these are `const fn`s, so they can be used both at runtime and at compile time. The module is public as
+//! `prime_checker::modular`:
+//!
+//! ```rust
+//! use prime_checker::modular::{self, Montgomery64};
+//!
+//! assert_eq!(modular::mod_pow(3, 200, 1_000_000_007), 136_318_165);
+//! assert_eq!(modular::mod_inverse(3, 7), Some(5));
+//! assert_eq!(modular::crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+//! assert_eq!(modular::legendre(2, 7), 1);
+//!
+//! let context = Montgomery64::new(1_000_000_007).unwrap();
+//! let three = context.to_montgomery(3);
+//! assert_eq!(context.from_montgomery(context.pow(three, 200)), 136_318_165);
+//! ```
+
+pub const fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    //! Computes `(a * b) % modulus` without overflowing, by widening to `u128`.
+    return ((a as u128 * b as u128) % modulus as u128) as u64;
+}
+
+pub const fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    //! Computes `base.pow(exponent) % modulus` by square-and-multiply.
+    if modulus == 1 {
+        return 0;
+    }
+
+    let mut result: u64 = 1;
+    let mut base: u64 = base % modulus;
+    let mut exponent: u64 = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent = exponent >> 1;
+    }
+
+    return result;
+}
+
+pub const fn mod_add_u128(a: u128, b: u128, modulus: u128) -> u128 {
+    //! Computes `(a + b) % modulus` for `a, b < modulus` without overflowing.
+    if a >= modulus - b {
+        return a - (modulus - b);
+    }
+    return a + b;
+}
+
+pub const fn mod_sub_u128(a: u128, b: u128, modulus: u128) -> u128 {
+    //! Computes `(a - b) % modulus` for `a, b < modulus` without underflowing.
+    if a >= b {
+        return a - b;
+    }
+    return modulus - (b - a);
+}
+
+pub const fn mod_mul_u128(a: u128, b: u128, modulus: u128) -> u128 {
+    //! Computes `(a * b) % modulus` without overflowing.
+    //!
+    //! Moduli that fit in 64 bits use a single native multiplication; larger ones fall back to double-and-add.
+    let a: u128 = a % modulus;
+    let b: u128 = b % modulus;
+    if modulus <= u64::MAX as u128 {
+        return (a * b) % modulus;
+    }
+
+    let mut result: u128 = 0;
+    let mut bit: u32 = 128 - b.leading_zeros();
+    while bit > 0 {
+        bit = bit - 1;
+        result = mod_add_u128(result, result, modulus);
+        if (b >> bit) & 1 == 1 {
+            result = mod_add_u128(result, a, modulus);
+        }
+    }
+
+    return result;
+}
+
+pub const fn mod_pow_u128(base: u128, exponent: u128, modulus: u128) -> u128 {
+    //! Computes `base.pow(exponent) % modulus` by square-and-multiply.
+    if modulus == 1 {
+        return 0;
+    }
+
+    let mut result: u128 = 1;
+    let mut base: u128 = base % modulus;
+    let mut exponent: u128 = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul_u128(result, base, modulus);
+        }
+        base = mod_mul_u128(base, base, modulus);
+        exponent = exponent >> 1;
+    }
+
+    return result;
+}
+
+pub const fn gcd_u128(a: u128, b: u128) -> u128 {
+    //! Computes the greatest common divisor of two numbers with Euclid's algorithm.
+    let mut a: u128 = a;
+    let mut b: u128 = b;
+    while b != 0 {
+        let remainder: u128 = a % b;
+        a = b;
+        b = remainder;
+    }
+    return a;
+}
+
+pub const fn jacobi_u128(a: u128, n: u128) -> i8 {
+    //! Computes the Jacobi symbol `(a/n)` for an odd `n`.
+    let mut a: u128 = a % n;
+    let mut n: u128 = n;
+    let mut result: i8 = 1;
+    while a != 0 {
+        while a % 2 == 0 {
+            a = a / 2;
+            let n_mod_8: u128 = n % 8;
+            if n_mod_8 == 3 || n_mod_8 == 5 {
+                result = -result;
+            }
+        }
+        let swap: u128 = a;
+        a = n;
+        n = swap;
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a = a % n;
+    }
+
+    if n == 1 {
+        return result;
+    }
+    return 0;
+}
+
+pub const fn jacobi(a: u64, n: u64) -> i8 {
+    //! Computes the Jacobi symbol `(a/n)` for an odd `n`: `0` if they share a factor, otherwise `1` or `-1`.
+    return jacobi_u128(a as u128, n as u128);
+}
+
+pub const fn legendre(a: u64, prime: u64) -> i8 {
+    //! Computes the Legendre symbol `(a/p)` for an odd prime `p`: `1` if `a` is a non-zero square modulo `p`, `-1`
+    //! if it is not a square, and `0` if `p` divides `a`.
+    //!
+    //! For a prime this is the same as the Jacobi symbol, which is cheaper than Euler's criterion.
+    return jacobi(a, prime);
+}
+
+pub const fn mod_inverse_u128(a: u128, modulus: u128) -> Option<u128> {
+    //! Computes the inverse of `a` modulo `modulus` with the extended Euclidean algorithm, or `None` if they share
+    //! a factor.
+    //!
+    //! The Bezout coefficient is kept reduced modulo `modulus`, so nothing overflows or goes negative.
+    if modulus == 0 {
+        return None;
+    }
+    if modulus == 1 {
+        return Some(0);
+    }
+
+    let mut old_remainder: u128 = a % modulus;
+    let mut remainder: u128 = modulus;
+    let mut old_coefficient: u128 = 1;
+    let mut coefficient: u128 = 0;
+    while remainder != 0 {
+        let quotient: u128 = old_remainder / remainder;
+        let next_remainder: u128 = old_remainder - quotient * remainder;
+        old_remainder = remainder;
+        remainder = next_remainder;
+        let next_coefficient: u128 = mod_sub_u128(
+            old_coefficient,
+            mod_mul_u128(quotient, coefficient, modulus),
+            modulus,
+        );
+        old_coefficient = coefficient;
+        coefficient = next_coefficient;
+    }
+
+    if old_remainder != 1 {
+        return None;
+    }
+    return Some(old_coefficient);
+}
+
+pub const fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    //! Computes the inverse of `a` modulo `modulus`, or `None` if they share a factor.
+    return match mod_inverse_u128(a as u128, modulus as u128) {
+        Some(inverse) => Some(inverse as u64),
+        None => None,
+    };
+}
+
+pub const fn crt(congruences: &[(u128, u128)]) -> Option<(u128, u128)> {
+    //! Solves a system of congruences `x = residue (mod modulus)`, given as `(residue, modulus)` pairs, with the
+    //! Chinese Remainder Theorem.
+    //!
+    //! The moduli need not be coprime. Returns the solution as `(x, lcm of the moduli)` with `x` reduced, or `None`
+    //! if the congruences contradict each other, a modulus is `0`, or the lcm overflows `u128`. An empty system
+    //! gives `(0, 1)`.
+    let mut residue: u128 = 0;
+    let mut modulus: u128 = 1;
+    let mut index: usize = 0;
+    while index < congruences.len() {
+        let (next_residue, next_modulus): (u128, u128) = congruences[index];
+        if next_modulus == 0 {
+            return None;
+        }
+        let next_residue: u128 = next_residue % next_modulus;
+
+        // Find `k` with `residue + modulus * k = next_residue (mod next_modulus)`.
+        let divisor: u128 = gcd_u128(modulus, next_modulus);
+        let difference: u128 = mod_sub_u128(next_residue, residue % next_modulus, next_modulus);
+        if difference % divisor != 0 {
+            return None;
+        }
+        let reduced_modulus: u128 = next_modulus / divisor;
+        let lcm: u128 = match (modulus / divisor).checked_mul(next_modulus) {
+            Some(lcm) => lcm,
+            None => return None,
+        };
+        let inverse: u128 = match mod_inverse_u128(modulus / divisor, reduced_modulus) {
+            Some(inverse) => inverse,
+            None => return None,
+        };
+        let k: u128 = mod_mul_u128(difference / divisor, inverse, reduced_modulus);
+        residue = mod_add_u128(residue, mod_mul_u128(modulus, k, lcm), lcm);
+        modulus = lcm;
+        index = index + 1;
+    }
+    return Some((residue, modulus));
+}
+
+/// Montgomery form modulo a fixed odd `u64`, which replaces the division in every modular multiplication with
+/// multiplications and a shift.
+///
+/// Numbers are converted in with `to_montgomery`, multiplied with `mul` or `pow`, and converted back with
+/// `from_montgomery`; `R = 2^64`, and a number `a` is stored as `a * R mod n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Montgomery64 {
+    modulus: u64,
+    inverse: u64,   // `-modulus^-1 mod 2^64`.
+    r_squared: u64, // `R^2 mod modulus`, for converting numbers in.
+}
+
+impl Montgomery64 {
+    pub const fn new(modulus: u64) -> Option<Self> {
+        //! Creates the context for an odd modulus above `1`, or `None` for any other modulus.
+        if modulus % 2 == 0 || modulus == 1 {
+            return None;
+        }
+
+        // Newton's iteration doubles the number of correct low bits each step, from 3 bits for `x = n`.
+        let mut inverse: u64 = modulus;
+        let mut step: u32 = 0;
+        while step < 5 {
+            inverse = inverse.wrapping_mul(2_u64.wrapping_sub(modulus.wrapping_mul(inverse)));
+            step = step + 1;
+        }
+
+        let r: u128 = (1_u128 << 64) % modulus as u128;
+        return Some(Montgomery64 {
+            modulus,
+            inverse: inverse.wrapping_neg(),
+            r_squared: (r * r % modulus as u128) as u64,
+        });
+    }
+
+    pub const fn modulus(&self) -> u64 {
+        return self.modulus;
+    }
+
+    pub const fn to_montgomery(&self, a: u64) -> u64 {
+        //! Converts a number into Montgomery form.
+        return self.reduce((a % self.modulus) as u128 * self.r_squared as u128);
+    }
+
+    pub const fn from_montgomery(&self, a: u64) -> u64 {
+        //! Converts a number out of Montgomery form.
+        return self.reduce(a as u128);
+    }
+
+    pub const fn one(&self) -> u64 {
+        //! The number `1` in Montgomery form.
+        return self.to_montgomery(1);
+    }
+
+    pub const fn mul(&self, a: u64, b: u64) -> u64 {
+        //! Multiplies two numbers in Montgomery form.
+        return self.reduce(a as u128 * b as u128);
+    }
+
+    pub const fn pow(&self, base: u64, exponent: u64) -> u64 {
+        //! Raises a number in Montgomery form to a power, by square-and-multiply.
+        let mut result: u64 = self.one();
+        let mut base: u64 = base;
+        let mut exponent: u64 = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exponent = exponent >> 1;
+        }
+        return result;
+    }
+
+    const fn reduce(&self, t: u128) -> u64 {
+        // REDC: `(t + m * n) / R` is exact for `m = t * (-n^-1) mod R`, and is below `2n` for `t < n * R`.
+        let m: u64 = (t as u64).wrapping_mul(self.inverse);
+        let (sum, overflow) = t.overflowing_add(m as u128 * self.modulus as u128);
+        let reduced: u128 = (sum >> 64) | ((overflow as u128) << 64);
+        if reduced >= self.modulus as u128 {
+            return (reduced - self.modulus as u128) as u64;
+        }
+        return reduced as u64;
+    }
+}
+
+/// Montgomery form modulo a fixed odd `u128`, the 128-bit counterpart of `Montgomery64`.
+///
+/// `R = 2^128`; products are formed in 256 bits from four 64-bit multiplications and reduced with REDC, which is
+/// far faster than the double-and-add of `mod_mul_u128` for moduli past 64 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Montgomery128 {
+    modulus: u128,
+    inverse: u128,   // `-modulus^-1 mod 2^128`.
+    r_squared: u128, // `R^2 mod modulus`, for converting numbers in.
+}
+
+impl Montgomery128 {
+    pub const fn new(modulus: u128) -> Option<Self> {
+        //! Creates the context for an odd modulus above `1`, or `None` for any other modulus.
+        if modulus % 2 == 0 || modulus == 1 {
+            return None;
+        }
+
+        // Newton's iteration, as for `Montgomery64`; six steps take the 3 correct bits of `x = n` past 128.
+        let mut inverse: u128 = modulus;
+        let mut step: u32 = 0;
+        while step < 6 {
+            inverse = inverse.wrapping_mul(2_u128.wrapping_sub(modulus.wrapping_mul(inverse)));
+            step = step + 1;
+        }
+
+        let r: u128 = modulus.wrapping_neg() % modulus;
+        return Some(Montgomery128 {
+            modulus,
+            inverse: inverse.wrapping_neg(),
+            r_squared: mod_mul_u128(r, r, modulus),
+        });
+    }
+
+    pub const fn modulus(&self) -> u128 {
+        return self.modulus;
+    }
+
+    pub const fn to_montgomery(&self, a: u128) -> u128 {
+        //! Converts a number into Montgomery form.
+        let (high, low) = wide_mul(a % self.modulus, self.r_squared);
+        return self.reduce(high, low);
+    }
+
+    pub const fn from_montgomery(&self, a: u128) -> u128 {
+        //! Converts a number out of Montgomery form.
+        return self.reduce(0, a);
+    }
+
+    pub const fn one(&self) -> u128 {
+        //! The number `1` in Montgomery form.
+        return self.to_montgomery(1);
+    }
+
+    pub const fn mul(&self, a: u128, b: u128) -> u128 {
+        //! Multiplies two numbers in Montgomery form.
+        let (high, low) = wide_mul(a, b);
+        return self.reduce(high, low);
+    }
+
+    pub const fn pow(&self, base: u128, exponent: u128) -> u128 {
+        //! Raises a number in Montgomery form to a power, by square-and-multiply.
+        let mut result: u128 = self.one();
+        let mut base: u128 = base;
+        let mut exponent: u128 = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exponent = exponent >> 1;
+        }
+        return result;
+    }
+
+    const fn reduce(&self, high: u128, low: u128) -> u128 {
+        // REDC on the 256-bit `t = high * R + low`: the low half of `t + m * n` is zero, and carries out exactly
+        // when `low` is not.
+        let m: u128 = low.wrapping_mul(self.inverse);
+        let (product_high, _) = wide_mul(m, self.modulus);
+        let (sum, overflow) = high.overflowing_add(product_high);
+        let (sum, carry) = sum.overflowing_add((low != 0) as u128);
+        if overflow || carry || sum >= self.modulus {
+            return sum.wrapping_sub(self.modulus);
+        }
+        return sum;
+    }
+}
+
+const fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    // The full 256-bit product `a * b`, as `(high, low)` halves, from 64-bit limbs.
+    let mask: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & mask);
+    let (b_high, b_low) = (b >> 64, b & mask);
+    let low_low: u128 = a_low * b_low;
+    let low_high: u128 = a_low * b_high;
+    let high_low: u128 = a_high * b_low;
+    let middle: u128 = (low_low >> 64) + (low_high & mask) + (high_low & mask);
+    let low: u128 = (low_low & mask) | (middle << 64);
+    let high: u128 = a_high * b_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    return (high, low);
+}
@@ -0,0 +1,214 @@
+//! The multiplicative group modulo `n`: orders, primitive roots and discrete logarithms.
+//!
+//! Everything here works from the factorization of `phi(n)` (or `lambda(n)`), which the factorizer finds quickly for
+//! any `u64`.
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::libs::arithmetic;
+use crate::libs::modular;
+use crate::libs::primality;
+use crate::libs::prime_int::PrimeInt;
+
+/// Orders till this are solved by a single baby-step giant-step search; larger ones are split with
+/// Pohlig-Hellman first.
+const SMALL_ORDER_LIMIT: u64 = 1 << 20;
+
+/// A baby-step giant-step search builds a table of at most this many `(power, j)` pairs (32 MiB), which covers
+/// subgroups of prime order till `2^42`; `discrete_log` gives up on larger ones instead of running out of memory.
+const BSGS_STEP_LIMIT: u64 = 1 << 21;
+
+/// The error returned when `discrete_log` gives up without searching.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiscreteLogError {
+    /// The order of `g` has this prime factor above `2^42`, whose baby-step giant-step table would need more than
+    /// `2^21` entries.
+    TooLarge { prime: u64 },
+}
+
+impl fmt::Display for DiscreteLogError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            DiscreteLogError::TooLarge { prime } => write!(
+                formatter,
+                "the order of the base has the prime factor {prime}, which is past 2^42"
+            ),
+        };
+    }
+}
+
+pub fn multiplicative_order(a: u64, modulus: u64) -> Option<u64> {
+    //! Finds the smallest `k > 0` with `a^k = 1 (mod n)`, or `None` if `a` and `n` share a factor or `n` is `0`.
+    //!
+    //! Starts from `lambda(n)`, which every order divides, and divides out each prime factor for as long as the
+    //! power stays `1`.
+    if modulus == 0 || modular::gcd_u128(a as u128, modulus as u128) != 1 {
+        return None;
+    }
+    if modulus == 1 {
+        return Some(1);
+    }
+
+    let exponent: u64 = arithmetic::carmichael(modulus);
+    let mut order: u64 = exponent;
+    for (prime, _) in exponent.factorize() {
+        while order % prime == 0 && modular::mod_pow(a, order / prime, modulus) == 1 {
+            order = order / prime;
+        }
+    }
+    return Some(order);
+}
+
+pub fn check_if_primitive_root(g: u64, modulus: u64) -> bool {
+    //! Checks whether `g` generates the whole multiplicative group modulo `n`, i.e. has order `phi(n)`.
+    //!
+    //! Only `1`, `2`, `4`, `p^k` and `2p^k` (for odd primes `p`) have primitive roots.
+    if modulus == 0 || modular::gcd_u128(g as u128, modulus as u128) != 1 {
+        return false;
+    }
+    let phi: u64 = arithmetic::totient(modulus);
+    return check_generator(g, modulus, phi, &phi.factorize());
+}
+
+pub fn find_primitive_root(modulus: u64) -> Option<u64> {
+    //! Finds the smallest primitive root modulo `n` (OEIS A046145), or `None` if there is none.
+    if modulus == 0 || !has_primitive_root(modulus) {
+        return None;
+    }
+    let phi: u64 = arithmetic::totient(modulus);
+    return find_primitive_root_from_factors(modulus, phi, &phi.factorize());
+}
+
+pub fn find_primitive_root_from_factors(
+    modulus: u64,
+    phi: u64,
+    factors: &[(u64, u32)],
+) -> Option<u64> {
+    //! Finds the smallest primitive root modulo `n`, given `phi(n)` and its factorization; for a prime `p` these are
+    //! `p - 1` and its factors, as a Pratt certificate needs anyway.
+    return (0..modulus).find(|g| {
+        modular::gcd_u128(*g as u128, modulus as u128) == 1
+            && check_generator(*g, modulus, phi, factors)
+    });
+}
+
+fn check_generator(g: u64, modulus: u64, phi: u64, factors: &[(u64, u32)]) -> bool {
+    // A unit generates the group iff `g^(phi/q) != 1` for every prime `q` dividing `phi`.
+    return factors
+        .iter()
+        .all(|(prime, _)| modular::mod_pow(g, phi / prime, modulus) != 1 % modulus);
+}
+
+fn has_primitive_root(modulus: u64) -> bool {
+    // True for 1, 2, 4, p^k and 2p^k.
+    if modulus <= 4 {
+        return true;
+    }
+    let odd: u64 = if modulus % 4 == 2 {
+        modulus / 2
+    } else {
+        modulus
+    };
+    if odd % 2 == 0 {
+        return false;
+    }
+    return odd.factorize().len() == 1;
+}
+
+pub fn discrete_log(g: u64, h: u64, modulus: u64) -> Result<Option<u64>, DiscreteLogError> {
+    //! Finds the smallest `x >= 0` with `g^x = h (mod n)`, or `Ok(None)` if there is none or `g` is not a unit.
+    //!
+    //! Small orders are searched directly with baby-step giant-step. Larger ones are split with Pohlig-Hellman
+    //! into one search per prime `q` dividing the order, each taking `O(sqrt(q))` time and memory, so this is fast
+    //! whenever the order of `g` is smooth. The memory is capped at `BSGS_STEP_LIMIT` table entries: if a prime
+    //! factor of the order is above `2^42`, this returns `DiscreteLogError::TooLarge` before searching rather than
+    //! allocate a table of `sqrt(q)` entries.
+    let order: u64 = match multiplicative_order(g, modulus) {
+        Some(order) => order,
+        None => return Ok(None),
+    };
+    let h: u64 = h % modulus;
+    let g: u64 = g % modulus;
+    let factors: Vec<(u64, u32)> = order.factorize();
+    for (prime, _) in factors.iter() {
+        if isqrt_ceil(*prime) > BSGS_STEP_LIMIT {
+            return Err(DiscreteLogError::TooLarge { prime: *prime });
+        }
+    }
+
+    let solution: Option<u64> = if order <= SMALL_ORDER_LIMIT {
+        baby_step_giant_step(g, h, order, modulus)
+    } else {
+        pohlig_hellman(g, h, order, &factors, modulus)
+    };
+    return Ok(solution.filter(|solution| modular::mod_pow(g, *solution, modulus) == h));
+}
+
+fn pohlig_hellman(g: u64, h: u64, order: u64, factors: &[(u64, u32)], modulus: u64) -> Option<u64> {
+    // Solves modulo each prime power dividing the order and joins the answers with the CRT.
+    let mut congruences: Vec<(u128, u128)> = Vec::new();
+    for (prime, exponent) in factors {
+        let digits: u64 = pohlig_hellman_prime_power(g, h, order, *prime, *exponent, modulus)?;
+        congruences.push((digits as u128, (*prime as u128).pow(*exponent)));
+    }
+    return Some(modular::crt(&congruences)?.0 as u64);
+}
+
+fn pohlig_hellman_prime_power(
+    g: u64,
+    h: u64,
+    order: u64,
+    prime: u64,
+    exponent: u32,
+    modulus: u64,
+) -> Option<u64> {
+    // Finds `x mod q^e` one base-`q` digit at a time, each from a discrete log in the subgroup of order `q`.
+    let generator: u64 = modular::mod_pow(g, order / prime, modulus); // Has order `q`.
+    let g_inverse: u64 = modular::mod_inverse(g, modulus)?;
+    let mut solution: u64 = 0;
+    let mut place: u64 = 1; // `q^k` for the current digit `k`.
+    for k in 0..exponent {
+        // `(h * g^-x)^(order / q^(k+1))` lies in the subgroup of order `q`.
+        let rest: u64 =
+            modular::mod_mul(h, modular::mod_pow(g_inverse, solution, modulus), modulus);
+        let target: u64 = modular::mod_pow(rest, order / prime.pow(k + 1), modulus);
+        let digit: u64 = baby_step_giant_step(generator, target, prime, modulus)?;
+        solution = solution + digit * place;
+        place = place * prime;
+    }
+    return Some(solution);
+}
+
+fn baby_step_giant_step(g: u64, h: u64, order: u64, modulus: u64) -> Option<u64> {
+    // Finds `x < order` with `g^x = h` by meeting `g^j` (baby steps) and `h * g^(-m i)` (giant steps) in the middle.
+    // `discrete_log` keeps `order` below `BSGS_STEP_LIMIT^2`.
+    let steps: u64 = isqrt_ceil(order);
+    let mut baby_steps: Vec<(u64, u64)> = Vec::with_capacity(steps as usize);
+    let mut power: u64 = 1 % modulus;
+    for j in 0..steps {
+        baby_steps.push((power, j));
+        power = modular::mod_mul(power, g, modulus);
+    }
+    // Keep the smallest `j` for each power, so the answer is the smallest `x`.
+    baby_steps.sort();
+    baby_steps.dedup_by_key(|(power, _)| *power);
+
+    let giant_step: u64 = modular::mod_inverse(modular::mod_pow(g, steps, modulus), modulus)?;
+    let mut gamma: u64 = h;
+    for i in 0..steps {
+        if let Ok(index) = baby_steps.binary_search_by_key(&gamma, |(power, _)| *power) {
+            return Some(i * steps + baby_steps[index].1);
+        }
+        gamma = modular::mod_mul(gamma, giant_step, modulus);
+    }
+    return None;
+}
+
+fn isqrt_ceil(num: u64) -> u64 {
+    // The smallest `m` with `m^2 >= num`.
+    let root: u64 = primality::isqrt_u128(num as u128) as u64;
+    if root * root < num {
+        return root + 1;
+    }
+    return root;
+}
@@ -0,0 +1,113 @@
+//! Functions/methods to classify numbers by the sum of their divisors.
+//!
+//! A number `n` is perfect if `sigma(n) = 2n`, abundant if `sigma(n) > 2n` and deficient if `sigma(n) < 2n`.
+//! `0` has infinitely many divisors, so it is none of these.
+use alloc::vec::Vec;
+
+use crate::libs::arithmetic;
+use crate::libs::modular;
+use crate::libs::primality;
+
+// Numbers sieved per segment by `AbundantNumbers`.
+const SEGMENT_SIZE: u64 = 1 << 16;
+
+pub fn check_if_perfect(num: u64) -> bool {
+    //! Checks to see if a given number is a perfect number.
+    return num > 0 && arithmetic::divisor_sum(num) == 2 * num as u128;
+}
+
+pub fn check_if_abundant(num: u64) -> bool {
+    //! Checks to see if a given number is an abundant number.
+    return num > 0 && arithmetic::divisor_sum(num) > 2 * num as u128;
+}
+
+pub fn check_if_deficient(num: u64) -> bool {
+    //! Checks to see if a given number is a deficient number.
+    return num > 0 && arithmetic::divisor_sum(num) < 2 * num as u128;
+}
+
+pub fn abundancy(num: u64) -> (u128, u64) {
+    //! Finds the abundancy index `sigma(n) / n` of a given number, as a fraction in lowest terms.
+    //!
+    //! Returns `(0, 1)` for `0`.
+    if num == 0 {
+        return (0, 1);
+    }
+
+    let sum: u128 = arithmetic::divisor_sum(num);
+    let divisor: u128 = modular::gcd_u128(sum, num as u128);
+    return (sum / divisor, (num as u128 / divisor) as u64);
+}
+
+pub fn find_perfect_till(num: u64) -> Vec<u64> {
+    //! Finds all the even perfect numbers till a given number.
+    //!
+    //! Every even perfect number is `2^(p-1) * (2^p - 1)` for a Mersenne prime `2^p - 1` (Euclid-Euler), so only
+    //! the Mersenne numbers are tested. No odd perfect number is known; any would be far beyond `u64`.
+    let mut perfect_numbers: Vec<u64> = Vec::new();
+    for exponent in 2..64_u32 {
+        let mersenne: u64 = (1_u64 << exponent) - 1;
+        let perfect: u64 = match (1_u64 << (exponent - 1)).checked_mul(mersenne) {
+            Some(perfect) if perfect <= num => perfect,
+            _ => break,
+        };
+        if primality::check_if_prime_mr(mersenne) {
+            perfect_numbers.push(perfect);
+        }
+    }
+    return perfect_numbers;
+}
+
+/// Iterator over the abundant numbers in a range, in increasing order.
+///
+/// Sieves the divisor sums one segment at a time (see `arithmetic::divisor_sum_segment`), so memory use stays
+/// constant however long the range is.
+#[derive(Debug, Clone)]
+pub struct AbundantNumbers {
+    next: u64, // First number not yet sieved.
+    high: u64,
+    done: bool,
+    buffer: Vec<u64>, // Abundant numbers from the current segment, in reverse order.
+}
+
+impl AbundantNumbers {
+    pub fn new(low: u64, high: u64) -> Self {
+        //! Creates an iterator over the abundant numbers from `low` till `high`, inclusive.
+        return AbundantNumbers {
+            next: core::cmp::max(low, 1),
+            high,
+            done: low > high || high == 0,
+            buffer: Vec::new(),
+        };
+    }
+
+    fn sieve_next_segment(&mut self) {
+        // Fills the buffer with the abundant numbers of the next segment.
+        let low: u64 = self.next;
+        let high: u64 = core::cmp::min(self.high, low.saturating_add(SEGMENT_SIZE - 1));
+        let sums: Vec<u128> = arithmetic::divisor_sum_segment(low, high);
+        for (offset, sum) in sums.iter().enumerate().rev() {
+            let num: u64 = low + offset as u64;
+            if *sum > 2 * num as u128 {
+                self.buffer.push(num);
+            }
+        }
+
+        if high == self.high {
+            self.done = true;
+        } else {
+            self.next = high + 1;
+        }
+    }
+}
+
+impl Iterator for AbundantNumbers {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.buffer.is_empty() && !self.done {
+            self.sieve_next_segment();
+        }
+        return self.buffer.pop();
+    }
+}
@@ -0,0 +1,159 @@
+//! Integer roots, perfect powers, and squarefree and powerful numbers.
+//!
+//! Roots are found bit by bit with exact integer arithmetic, so they never suffer from floating-point rounding; the
+//! perfect-power check also lets the factorizer split `p^k` without running Pollard's rho on it.
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::libs::primality;
+#[cfg(feature = "alloc")]
+use crate::libs::prime_int::PrimeInt;
+#[cfg(feature = "alloc")]
+use crate::libs::sieve;
+
+pub fn integer_nth_root_u128(num: u128, k: u32) -> u128 {
+    //! Computes the integer `k`-th root, `floor(num^(1/k))`, for `k >= 1`.
+    if k == 0 {
+        panic!("The 0-th root of `{num}` is not defined.", num = num);
+    }
+    if k == 1 || num < 2 {
+        return num;
+    }
+    if k == 2 {
+        return primality::isqrt_u128(num);
+    }
+
+    // The root has at most `ceil(bits / k)` bits; set them from the top while the power stays within `num`.
+    let bits: u32 = 128 - num.leading_zeros();
+    let mut root: u128 = 0;
+    let mut bit: u32 = (bits + k - 1) / k;
+    while bit > 0 {
+        bit = bit - 1;
+        let candidate: u128 = root | (1 << bit);
+        match candidate.checked_pow(k) {
+            Some(power) if power <= num => root = candidate,
+            _ => {}
+        }
+    }
+    return root;
+}
+
+pub fn integer_nth_root(num: u64, k: u32) -> u64 {
+    //! Computes the integer `k`-th root, `floor(num^(1/k))`, for `k >= 1`.
+    return integer_nth_root_u128(num as u128, k) as u64;
+}
+
+pub fn check_if_perfect_power_u128(num: u128) -> Option<(u128, u32)> {
+    //! Checks whether a number is a perfect power `b^k` with `k >= 2`, returning `(b, k)` with the largest such `k`
+    //! (and so the smallest `b`), or `None` if it is not one.
+    //!
+    //! `0` and `1` are powers of themselves with any exponent, so they give `None` as well.
+    if num < 4 {
+        return None;
+    }
+    let bits: u32 = 128 - num.leading_zeros();
+    for k in (2..bits).rev() {
+        let root: u128 = integer_nth_root_u128(num, k);
+        if root.pow(k) == num {
+            return Some((root, k));
+        }
+    }
+    return None;
+}
+
+pub fn check_if_perfect_power(num: u64) -> Option<(u64, u32)> {
+    //! Checks whether a number is a perfect power `b^k` with `k >= 2`, returning `(b, k)` with the largest such `k`,
+    //! or `None` if it is not one (`0` and `1` included).
+    return check_if_perfect_power_u128(num as u128)
+        .map(|(base, exponent)| (base as u64, exponent));
+}
+
+#[cfg(feature = "alloc")]
+pub fn check_if_squarefree(num: u64) -> bool {
+    //! Checks whether no square of a prime divides a given number; `1` is squarefree and `0` is not.
+    if num == 0 {
+        return false;
+    }
+    return num.factorize().iter().all(|(_, exponent)| *exponent == 1);
+}
+
+#[cfg(feature = "alloc")]
+pub fn find_radical(num: u64) -> u64 {
+    //! Finds the radical of a given number, the product of its distinct prime factors; `rad(1) = 1` and
+    //! `rad(0) = 0`.
+    if num == 0 {
+        return 0;
+    }
+    return num.factorize().iter().map(|(prime, _)| *prime).product();
+}
+
+#[cfg(feature = "alloc")]
+pub fn check_if_powerful(num: u64) -> bool {
+    //! Checks whether the square of every prime factor of a given number divides it too; `1` is powerful and `0`
+    //! is not.
+    if num == 0 {
+        return false;
+    }
+    return num.factorize().iter().all(|(_, exponent)| *exponent >= 2);
+}
+
+#[cfg(feature = "alloc")]
+pub fn squarefree_flags(low: u64, high: u64) -> Vec<bool> {
+    //! Sieves the range from `low` till `high`, returning whether each number is squarefree, indexed by `n - low`.
+    //!
+    //! Divides every number of the range by the primes till `min(sqrt(high), sieve::BASE_LIMIT)`, crossing it off
+    //! when one divides it twice. Below `sieve::BASE_LIMIT^2` that settles every number; above it, a cofactor left
+    //! over can only hold a square of a larger prime when it is at least `sieve::BASE_LIMIT^2`, and those few are
+    //! checked with the factorizer.
+    if low > high {
+        return Vec::new();
+    }
+    let length: usize = (high - low) as usize + 1;
+    let mut flags: Vec<bool> = vec![true; length];
+    let mut cofactors: Vec<u64> = (low..=high).collect();
+    if low == 0 {
+        flags[0] = false;
+    }
+
+    for prime in sieve::base_primes(high) {
+        // First positive multiple of `p` in the range; `0` was crossed off above.
+        let quotient: u64 = low / prime + if low % prime == 0 { 0 } else { 1 };
+        let mut multiple: u64 = match core::cmp::max(quotient, 1).checked_mul(prime) {
+            Some(multiple) => multiple,
+            None => continue,
+        };
+        while multiple <= high {
+            let index: usize = (multiple - low) as usize;
+            cofactors[index] = cofactors[index] / prime;
+            if cofactors[index] % prime == 0 {
+                flags[index] = false;
+            }
+            multiple = match multiple.checked_add(prime) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+    }
+
+    let limit: u128 = sieve::BASE_LIMIT as u128 * sieve::BASE_LIMIT as u128;
+    for index in 0..length {
+        if flags[index] && cofactors[index] as u128 >= limit {
+            flags[index] = check_if_squarefree(cofactors[index]);
+        }
+    }
+    return flags;
+}
+
+#[cfg(feature = "alloc")]
+pub fn find_squarefree_in_range(low: u64, high: u64) -> Vec<u64> {
+    //! Finds the squarefree numbers from `low` till `high`, inclusive, with `squarefree_flags`.
+    let squarefree: Vec<u64> = squarefree_flags(low, high)
+        .iter()
+        .enumerate()
+        .filter(|(_, flag)| **flag)
+        .map(|(index, _)| low + index as u64)
+        .collect();
+    return squarefree;
+}
@@ -0,0 +1,307 @@
+//! Allocation-free primality tests.
+//!
+//! Nothing in this module needs an allocator, so it is available even with `default-features = false`.
+//!
+//! Each integer width has its own test, picked to be the fastest one that is still exact for that width:
+//!
+//! 1. `u8`, `u16` - A compile-time bitmap of every prime below `2^16`.
+//! 2. `u32` - Miller-Rabin with the bases `2, 7, 61`.
+//! 3. `u64` - Miller-Rabin with seven bases.
+//! 4. `u128` - Baillie-PSW; no counterexample is known.
+use crate::libs::constants;
+use crate::libs::modular;
+
+// Bases for which the strong probable-prime test is deterministic for every `u32`.
+// Source: Jaeschke, "On strong pseudoprimes to several bases" (1993)
+const MR_BASES_U32: [u64; 3] = [2, 7, 61];
+// Bases for which the strong probable-prime test is deterministic for every `u64`.
+// Source: https://miller-rabin.appspot.com/ (Jim Sinclair, 2011)
+const MR_BASES_U64: [u64; 7] = [2, 325, 9_375, 28_178, 450_775, 9_780_504, 1_795_265_022];
+
+/// Bitmap of the primes below `2^16`; bit `n % 64` of word `n / 64` is set if `n` is prime.
+pub const PRIME_BITMAP_U16: [u64; 1_024] = prime_bitmap_u16();
+
+const fn prime_bitmap_u16() -> [u64; 1_024] {
+    // Sieve of Eratosthenes, run at compile time.
+    let mut bitmap: [u64; 1_024] = [u64::MAX; 1_024];
+    bitmap[0] = bitmap[0] & !0b11; // 0 and 1 are not prime.
+
+    let mut i: usize = 2;
+    while i * i < 65_536 {
+        if (bitmap[i / 64] >> (i % 64)) & 1 == 1 {
+            let mut j: usize = i * i;
+            while j < 65_536 {
+                bitmap[j / 64] = bitmap[j / 64] & !(1 << (j % 64));
+                j = j + i;
+            }
+        }
+        i = i + 1;
+    }
+
+    return bitmap;
+}
+
+pub const fn check_if_prime_u16(num: u16) -> bool {
+    //! Checks to see if a given 16-bit number is a prime number by looking it up in `PRIME_BITMAP_U16`.
+    let num: usize = num as usize;
+    return (PRIME_BITMAP_U16[num / 64] >> (num % 64)) & 1 == 1;
+}
+
+pub const fn check_if_prime_u32(num: u32) -> bool {
+    //! Checks to see if a given 32-bit number is a prime number using Miller-Rabin with the bases `2, 7, 61`.
+    if num <= u16::MAX as u32 {
+        return check_if_prime_u16(num as u16);
+    } else if num % 2 == 0 {
+        return false;
+    }
+
+    let mut i: usize = 0;
+    while i < MR_BASES_U32.len() {
+        if !is_strong_probable_prime(num as u64, MR_BASES_U32[i]) {
+            return false;
+        }
+        i = i + 1;
+    }
+
+    return true;
+}
+
+pub const fn is_strong_probable_prime(num: u64, base: u64) -> bool {
+    //! Runs a single round of the Miller-Rabin test on an odd `num > 2` for the given `base`.
+    //!
+    //! The squarings are done in Montgomery form (see `modular::Montgomery64`), which avoids a 128-bit division
+    //! per step.
+    let context: modular::Montgomery64 = match modular::Montgomery64::new(num) {
+        Some(context) => context,
+        None => return num == 2,
+    };
+
+    let mut d: u64 = num - 1;
+    let mut s: u32 = 0;
+    while d % 2 == 0 {
+        d = d / 2;
+        s = s + 1;
+    }
+
+    let base: u64 = base % num;
+    if base == 0 {
+        // The base is a multiple of `num`, which tells us nothing.
+        return true;
+    }
+
+    let one: u64 = context.one();
+    let minus_one: u64 = context.to_montgomery(num - 1);
+    let mut x: u64 = context.pow(context.to_montgomery(base), d);
+    if x == one || x == minus_one {
+        return true;
+    }
+
+    let mut round: u32 = 1;
+    while round < s {
+        x = context.mul(x, x);
+        if x == minus_one {
+            return true;
+        }
+        round = round + 1;
+    }
+
+    return false;
+}
+
+pub const fn check_if_prime_mr(num: u64) -> bool {
+    //! Checks to see if a given number is a prime number using a deterministic Miller-Rabin test.
+    if num <= u32::MAX as u64 {
+        return check_if_prime_u32(num as u32);
+    } else if num % 2 == 0 {
+        return false;
+    }
+
+    // `constants::KNOWN_PRIMES` is generated from this function, so it cannot be used for trial division here.
+    let mut i: usize = 0;
+    while i < MR_BASES_U64.len() {
+        if !is_strong_probable_prime(num, MR_BASES_U64[i]) {
+            return false;
+        }
+        i = i + 1;
+    }
+
+    return true;
+}
+
+pub const fn isqrt_u128(num: u128) -> u128 {
+    //! Computes the integer square root, `floor(sqrt(num))`, using Newton's method.
+    if num < 2 {
+        return num;
+    }
+
+    if num <= u64::MAX as u128 {
+        // The same iteration with `u64` divisions, which are several times cheaper than `u128` ones.
+        let num: u64 = num as u64;
+        let mut x: u64 = 1 << ((64 - num.leading_zeros() + 1) / 2);
+        loop {
+            let y: u64 = (x + num / x) / 2;
+            if y >= x {
+                return x as u128;
+            }
+            x = y;
+        }
+    }
+
+    // Start from a power of two that is guaranteed to be above the root.
+    let mut x: u128 = 1 << ((128 - num.leading_zeros() + 1) / 2);
+    loop {
+        let y: u128 = (x + num / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+const fn half_mod(x: u128, modulus: u128) -> u128 {
+    // Computes `x / 2 % modulus` for an odd modulus; `(x + modulus) / 2` is split up so it cannot overflow.
+    if x % 2 == 0 {
+        return x / 2;
+    }
+    return x / 2 + modulus / 2 + 1;
+}
+
+pub const fn is_strong_probable_prime_u128(num: u128, base: u128) -> bool {
+    //! Runs a single round of the Miller-Rabin test on an odd `num > 2` for the given `base`.
+    let mut d: u128 = num - 1;
+    let mut s: u32 = 0;
+    while d % 2 == 0 {
+        d = d / 2;
+        s = s + 1;
+    }
+
+    let base: u128 = base % num;
+    if base == 0 {
+        return true;
+    }
+
+    let mut x: u128 = modular::mod_pow_u128(base, d, num);
+    if x == 1 || x == num - 1 {
+        return true;
+    }
+
+    let mut round: u32 = 1;
+    while round < s {
+        x = modular::mod_mul_u128(x, x, num);
+        if x == num - 1 {
+            return true;
+        }
+        round = round + 1;
+    }
+
+    return false;
+}
+
+pub const fn is_strong_lucas_probable_prime(num: u128) -> bool {
+    //! Runs the strong Lucas probable-prime test on an odd `num > 2`, with parameters chosen by Selfridge's method.
+    let root: u128 = isqrt_u128(num);
+    if root * root == num {
+        // No suitable `D` exists for perfect squares.
+        return false;
+    }
+
+    // Find the first `D` in `5, -7, 9, -11, ...` for which the Jacobi symbol `(D/num)` is `-1`.
+    let mut d_abs: u128 = 5;
+    let mut d_negative: bool = false;
+    let mut d: u128; // `D % num`
+    loop {
+        d = if d_negative {
+            num - (d_abs % num)
+        } else {
+            d_abs % num
+        };
+        let jacobi: i8 = modular::jacobi_u128(d, num);
+        if jacobi == -1 {
+            break;
+        } else if jacobi == 0 && d_abs % num != 0 {
+            // `D` shares a factor with `num`.
+            return false;
+        }
+        d_abs = d_abs + 2;
+        d_negative = !d_negative;
+    }
+
+    // P = 1 and Q = (1 - D) / 4
+    let q: u128 = if d_negative {
+        ((d_abs + 1) / 4) % num
+    } else {
+        modular::mod_sub_u128(0, ((d_abs - 1) / 4) % num, num)
+    };
+
+    // num + 1 = k * 2^s, with k odd
+    let mut k: u128 = num / 2 + 1; // (num + 1) / 2, without overflowing
+    let mut s: u32 = 1;
+    while k % 2 == 0 {
+        k = k / 2;
+        s = s + 1;
+    }
+
+    // Walk the bits of `k` from the top to compute U_k, V_k and Q^k.
+    let mut u: u128 = 1;
+    let mut v: u128 = 1;
+    let mut q_k: u128 = q;
+    let mut bit: u32 = 128 - k.leading_zeros() - 1;
+    while bit > 0 {
+        bit = bit - 1;
+        // Doubling: U_2j = U_j V_j, V_2j = V_j^2 - 2 Q^j
+        u = modular::mod_mul_u128(u, v, num);
+        v = modular::mod_sub_u128(
+            modular::mod_mul_u128(v, v, num),
+            modular::mod_add_u128(q_k, q_k, num),
+            num,
+        );
+        q_k = modular::mod_mul_u128(q_k, q_k, num);
+        if (k >> bit) & 1 == 1 {
+            // Increment: U_j+1 = (U_j + V_j) / 2, V_j+1 = (D U_j + V_j) / 2
+            let next_u: u128 = half_mod(modular::mod_add_u128(u, v, num), num);
+            v = half_mod(
+                modular::mod_add_u128(modular::mod_mul_u128(d, u, num), v, num),
+                num,
+            );
+            u = next_u;
+            q_k = modular::mod_mul_u128(q_k, q, num);
+        }
+    }
+
+    if u == 0 || v == 0 {
+        return true;
+    }
+
+    let mut round: u32 = 1;
+    while round < s {
+        v = modular::mod_sub_u128(
+            modular::mod_mul_u128(v, v, num),
+            modular::mod_add_u128(q_k, q_k, num),
+            num,
+        );
+        if v == 0 {
+            return true;
+        }
+        q_k = modular::mod_mul_u128(q_k, q_k, num);
+        round = round + 1;
+    }
+
+    return false;
+}
+
+pub const fn check_if_prime_u128(num: u128) -> bool {
+    //! Checks to see if a given 128-bit number is a prime number using the Baillie-PSW test.
+    if num <= u64::MAX as u128 {
+        return check_if_prime_mr(num as u64);
+    }
+
+    let mut i: usize = 0;
+    while i < constants::KNOWN_PRIMES.len() {
+        if num % constants::KNOWN_PRIMES[i] as u128 == 0 {
+            return false;
+        }
+        i = i + 1;
+    }
+
+    return is_strong_probable_prime_u128(num, 2) && is_strong_lucas_probable_prime(num);
+}
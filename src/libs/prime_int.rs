@@ -0,0 +1,179 @@
+//! The `PrimeInt` trait, which lets the primality and factorization functions work on every unsigned integer width.
+use core::fmt::{Debug, Display};
+use core::hash::Hash;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::libs::factorization;
+use crate::libs::primality;
+
+mod sealed {
+    /// Keeps `PrimeInt` from being implemented outside this crate.
+    pub trait Sealed {}
+}
+
+/// An unsigned integer type that the primality and factorization functions can work on.
+///
+/// Implemented for `u8`, `u16`, `u32`, `u64`, `u128` and `usize`; each width uses the fastest exact primality test
+/// available for it (see `libs::primality`). The trait is sealed and cannot be implemented outside this crate.
+pub trait PrimeInt: sealed::Sealed + Copy + Ord + Hash + Debug + Display + Default {
+    /// Widens the number to a `u128`.
+    fn to_u128(self) -> u128;
+
+    /// Narrows a `u128` to this width; the value must fit.
+    fn from_u128(num: u128) -> Self;
+
+    /// Checks to see if the number is a prime number; never allocates.
+    fn is_prime(self) -> bool;
+
+    /// Finds the prime factorization of the number, as `(prime, exponent)` pairs in increasing order.
+    ///
+    /// `0` and `1` have no prime factors, so the list is empty for them.
+    #[cfg(feature = "alloc")]
+    fn factorize(self) -> Vec<(Self, u32)> {
+        let factors: Vec<(Self, u32)> = factorization::factorize_u128(self.to_u128())
+            .into_iter()
+            .map(|(prime, exponent)| (Self::from_u128(prime), exponent))
+            .collect();
+        return factors;
+    }
+
+    /// Lists every divisor of the number in increasing order, built from its prime factorization.
+    ///
+    /// `0` has no finite list of divisors, so the list is empty for it.
+    #[cfg(feature = "alloc")]
+    fn divisors(self) -> Vec<Self> {
+        if self.to_u128() == 0 {
+            return Vec::new();
+        }
+
+        let mut divisors: Vec<u128> = Vec::from([1]);
+        for (prime, exponent) in self.factorize() {
+            let prime: u128 = prime.to_u128();
+            let n_divisors: usize = divisors.len();
+            let mut power: u128 = 1;
+            for _ in 0..exponent {
+                power = power * prime;
+                for i in 0..n_divisors {
+                    divisors.push(divisors[i] * power);
+                }
+            }
+        }
+        divisors.sort();
+
+        let divisors: Vec<Self> = divisors.into_iter().map(Self::from_u128).collect();
+        return divisors;
+    }
+}
+
+impl sealed::Sealed for u8 {}
+impl sealed::Sealed for u16 {}
+impl sealed::Sealed for u32 {}
+impl sealed::Sealed for u64 {}
+impl sealed::Sealed for u128 {}
+impl sealed::Sealed for usize {}
+
+impl PrimeInt for u8 {
+    fn to_u128(self) -> u128 {
+        return self as u128;
+    }
+
+    fn from_u128(num: u128) -> Self {
+        return num as Self;
+    }
+
+    fn is_prime(self) -> bool {
+        return primality::check_if_prime_u16(self as u16);
+    }
+
+    #[cfg(feature = "alloc")]
+    fn factorize(self) -> Vec<(Self, u32)> {
+        let factors: Vec<(Self, u32)> = factorization::factorize_trial(self as u64)
+            .into_iter()
+            .map(|(prime, exponent)| (prime as Self, exponent))
+            .collect();
+        return factors;
+    }
+}
+
+impl PrimeInt for u16 {
+    fn to_u128(self) -> u128 {
+        return self as u128;
+    }
+
+    fn from_u128(num: u128) -> Self {
+        return num as Self;
+    }
+
+    fn is_prime(self) -> bool {
+        return primality::check_if_prime_u16(self);
+    }
+
+    #[cfg(feature = "alloc")]
+    fn factorize(self) -> Vec<(Self, u32)> {
+        let factors: Vec<(Self, u32)> = factorization::factorize_trial(self as u64)
+            .into_iter()
+            .map(|(prime, exponent)| (prime as Self, exponent))
+            .collect();
+        return factors;
+    }
+}
+
+impl PrimeInt for u32 {
+    fn to_u128(self) -> u128 {
+        return self as u128;
+    }
+
+    fn from_u128(num: u128) -> Self {
+        return num as Self;
+    }
+
+    fn is_prime(self) -> bool {
+        return primality::check_if_prime_u32(self);
+    }
+}
+
+impl PrimeInt for u64 {
+    fn to_u128(self) -> u128 {
+        return self as u128;
+    }
+
+    fn from_u128(num: u128) -> Self {
+        return num as Self;
+    }
+
+    fn is_prime(self) -> bool {
+        return primality::check_if_prime_mr(self);
+    }
+}
+
+impl PrimeInt for u128 {
+    fn to_u128(self) -> u128 {
+        return self;
+    }
+
+    fn from_u128(num: u128) -> Self {
+        return num;
+    }
+
+    fn is_prime(self) -> bool {
+        return primality::check_if_prime_u128(self);
+    }
+}
+
+impl PrimeInt for usize {
+    fn to_u128(self) -> u128 {
+        return self as u128;
+    }
+
+    fn from_u128(num: u128) -> Self {
+        return num as Self;
+    }
+
+    fn is_prime(self) -> bool {
+        // `usize` is at most 64 bits wide on every supported platform.
+        return primality::check_if_prime_mr(self as u64);
+    }
+}
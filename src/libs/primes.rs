@@ -1,39 +1,17 @@
 //! Functions/methods to check prime numbers.
 #![allow(warnings)]
-use std::collections::HashMap;
-use std::time::{Duration, SystemTime};
+use alloc::vec;
+use alloc::vec::Vec;
 
-use crate::libs::cache_map;
+use crate::libs::arithmetic;
 use crate::libs::constants;
+use crate::libs::prime_int::PrimeInt;
+use crate::libs::spf::SpfTable;
 use crate::libs::utils;
 
-fn count_factors(num: u64) -> u64 {
-    let mut factors: u64 = 2; // All natural numbers except 1 are divisible by 1 and themselves.
-    let mut divisor: u64 = 2;
-    if num == 0 {
-        panic!("0 has infinite factors...");
-    } else if num == 1 {
-        // Static known case: 1 has the factor: [1]
-        return 1;
-    } else if (num == 2) || (num == 3) {
-        // Static known case: 2 has factors: [1, 2] and 3 has two factors: [1,3]
-        return 2;
-    } else if num > 3 {
-        while divisor < ((num / 2) + 1) as u64 {
-            if num % divisor == 0 {
-                factors = factors + 1;
-            }
-            divisor = divisor + 1;
-        }
-    } else {
-        panic!(
-            "`{num}` is not a valid unsigned, 64-bit integer.",
-            num = num
-        );
-    }
-
-    return factors;
-}
+// Largest smallest-prime-factor table the anti-prime scans build (32 MB); the divisors of the numbers past it are
+// counted one at a time from their own factorizations instead.
+const SPF_TABLE_LIMIT: u64 = 1 << 24;
 
 pub fn check_if_prime(num: u64) -> (bool, Vec<u64>) {
     //! Checks to see if a given number is a prime number.
@@ -84,8 +62,6 @@ pub fn check_if_prime(num: u64) -> (bool, Vec<u64>) {
 pub fn check_if_anti_prime(num: u64) -> (bool, Vec<u64>) {
     //! Checks to see if a given number is an anti-prime number.
 
-    // This is the only function that currently requires a global variable or cache for its performance to be acceptable.
-
     let mut prime_check: bool = false; // Status check of the given number if it's a prime.
     let mut factors: Vec<u64> = Vec::new(); // Factors of the given number.
 
@@ -98,9 +74,11 @@ pub fn check_if_anti_prime(num: u64) -> (bool, Vec<u64>) {
         return (true, factors);
     }
 
-    //// Check to see if this number is a prime number or not.
-    (prime_check, factors) = check_if_prime(num);
-    let n_factors: u64 = factors.len() as u64;
+    //// Check to see if this number is a prime number or not; a prime has only the two factors `1` and itself.
+    let table: SpfTable = SpfTable::new(core::cmp::min(num, SPF_TABLE_LIMIT));
+    factors = num.divisors();
+    let n_factors: u64 = count_divisors(&table, num);
+    prime_check = n_factors == 2;
     if prime_check == true {
         return (false, factors);
     }
@@ -111,28 +89,16 @@ pub fn check_if_anti_prime(num: u64) -> (bool, Vec<u64>) {
 
     // Loop to see if the number is just a composite number or an anti-prime number.
     // An anti-prime number is defined as a number which has more factors than any natural number lesser than itself.
+    // The factors of every lower number are counted from a single smallest-prime-factor table; the first one with
+    // as many factors settles it.
     for item in prev_start..num {
-        if cache_map::contains_key(&item) == false {
-            n_previous_factors = count_factors(item);
-            cache_map::insert(item, n_previous_factors);
-            if n_previous_factors >= n_factors {
-                n_previous_highers = n_previous_highers + 1;
-            }
-            continue;
-        } else if cache_map::contains_key(&item) == true {
-            n_previous_factors = cache_map::retrieve(&item);
-            if n_previous_factors >= n_factors {
-                n_previous_highers = n_previous_highers + 1;
-            }
-            continue;
-        } else {
-            continue;
+        n_previous_factors = count_divisors(&table, item);
+        if n_previous_factors >= n_factors {
+            n_previous_highers = n_previous_highers + 1;
+            break;
         }
     }
 
-    // In case this-> fn was called independently without the find_anti_primes_till() fn being available to clear the hashtable.
-    cache_map::clean(); // Cleans the global hashtable if it gets too large.
-
     if n_previous_highers == 0 {
         return (true, factors);
     } else {
@@ -144,9 +110,6 @@ pub fn find_anti_primes_till(num: u64) -> Vec<u64> {
     //! Finds all the anti-prime numbers till a given number.
     let mut anti_primes: Vec<u64> = Vec::new();
     let knowns: [u64; constants::KNOWN_ANTIPRIMES.len()] = constants::KNOWN_ANTIPRIMES;
-    let known_primes: Vec<u64> = find_primes_till(num);
-    let mut result: bool; // True if an individual number is an anti-prime number, false if it is not.
-    let mut _factors: Vec<u64> = Vec::new(); // List of factors of the number.
 
     // If the given number is less than or equal to the last known anti-prime number, then add all numbers less than or equal to it to the return vector.
     if num <= knowns[knowns.len() - 1] {
@@ -171,30 +134,20 @@ pub fn find_anti_primes_till(num: u64) -> Vec<u64> {
     //// Benchmarking code; comment out for prod.
     // let start_time = SystemTime::now();
 
-    // IMPORTANT: DO NOT REMOVE THIS LINE OF CODE.
-    // This line of code initializes the cache map before the anti-prime number check begins.
-    // This is done to prevent the cache map from growing too large and consuming too much memory.
-    cache_map::init(); // Initialize the cache map.
-    for item in start..num + 1 {
-        if known_primes.contains(&item) {
-            continue;
-        }
-
-        //// Debug code; comment out for prod.
-        // println!("Checking {}.........{}% done", item, ((item as f32/num as f32)*100.0) as u64);
-        (result, _) = check_if_anti_prime(item);
-        if result == true {
-            anti_primes.push(item);
-        } else {
-            continue;
+    // A number is an anti-prime number when it has more factors than every number before it, so a single pass with
+    // a smallest-prime-factor table finds them all; the last known anti-prime number has the most factors so far.
+    if num >= start {
+        let table: SpfTable = SpfTable::new(core::cmp::min(num, SPF_TABLE_LIMIT));
+        let mut n_most_factors: u64 = count_divisors(&table, start - 1);
+        for item in start..num + 1 {
+            let n_factors: u64 = count_divisors(&table, item);
+            if n_factors > n_most_factors {
+                n_most_factors = n_factors;
+                anti_primes.push(item);
+            }
         }
     }
 
-    // IMPORTANT: DO NOT REMOVE THIS LINE OF CODE.
-    // This line of code clears the cache map after the anti-prime number check is complete.
-    // This is done to prevent the cache map from growing too large and consuming too much memory.
-    cache_map::clear(); // Clear the cache map.
-
     //// Benchmarking code; comment out for prod.
     // let end_time = SystemTime::now();
     // let time_taken = end_time.duration_since(start_time).unwrap();
@@ -214,30 +167,41 @@ pub fn find_primes_till(num: u64) -> Vec<u64> {
     let mut j: u64; // Inner-loop counter for the seive.
     let knowns = constants::KNOWN_PRIMES; // What am I supposed to do with this?
 
-    // Pre-declaration of Hashmap
-    let mut checked_primes: HashMap<u64, bool> = HashMap::new();
-    for i in 2..num + 1 {
-        checked_primes.insert(i, true);
+    if num < 2 {
+        return Vec::new();
     }
 
-    for i in 2..num + 1 {
-        if (checked_primes[&i] == true) {
-            j = i * 2;
-            while (j <= num) {
-                checked_primes.insert(j, false);
+    // Pre-declaration of the sieve; `checked_primes[i]` is true while `i` may still be prime.
+    let mut checked_primes: Vec<bool> = vec![true; (num + 1) as usize];
+    checked_primes[0] = false;
+    checked_primes[1] = false;
+
+    let mut i: u64 = 2;
+    while i * i <= num {
+        if checked_primes[i as usize] == true {
+            j = i * i;
+            while j <= num {
+                checked_primes[j as usize] = false;
                 j = j + i;
             }
         }
+        i = i + 1;
     }
 
     let mut results: Vec<u64> = Vec::new();
-    for (key, value) in &checked_primes {
-        if value == &true {
-            results.push(*key);
+    for (key, value) in checked_primes.iter().enumerate() {
+        if *value == true {
+            results.push(key as u64);
         }
     }
 
-    results = utils::unique_elements_vector(results);
-    results.sort();
     return results;
 }
+
+fn count_divisors(table: &SpfTable, num: u64) -> u64 {
+    // Counts the divisors of a number from the table while it is in it, and from its own factorization past it.
+    if num <= table.limit() {
+        return table.count_divisors(num);
+    }
+    return arithmetic::divisor_count(num);
+}
@@ -0,0 +1,263 @@
+//! Square roots modulo primes, prime powers and composite numbers, and quadratic residues.
+//!
+//! Roots modulo an odd prime come from Tonelli-Shanks, or from Cipolla when `p - 1` is divisible by a large power
+//! of two. They are lifted to prime powers with Hensel's lemma and combined across the prime powers of a composite
+//! modulus with the Chinese Remainder Theorem.
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::libs::factorization;
+use crate::libs::modular;
+use crate::libs::primality;
+
+pub fn sqrt_mod_prime(a: u64, prime: u64) -> Option<u64> {
+    //! Finds a square root of `a` modulo a prime, or `None` if `a` is not a square or the modulus is not prime.
+    //!
+    //! Returns the smaller of the two roots `r` and `p - r`. Tonelli-Shanks takes `O(log(p)^2 + s^2)` steps for
+    //! `p - 1 = 2^s * q` with `q` odd, and Cipolla `O(log(p))`, so Cipolla is used once `s(s - 1) > 8m + 20` for an
+    //! `m`-bit prime.
+    if !primality::check_if_prime_mr(prime) {
+        return None;
+    }
+    let a: u64 = a % prime;
+    if prime == 2 || a == 0 {
+        return Some(a);
+    }
+    if modular::legendre(a, prime) != 1 {
+        return None;
+    }
+
+    let root: u64 = if prime % 4 == 3 {
+        modular::mod_pow(a, (prime + 1) / 4, prime)
+    } else {
+        let two_adic: u64 = (prime - 1).trailing_zeros() as u64;
+        let bits: u64 = 64 - prime.leading_zeros() as u64;
+        if two_adic * (two_adic - 1) > 8 * bits + 20 {
+            cipolla(a, prime)
+        } else {
+            tonelli_shanks(a, prime)
+        }
+    };
+    return Some(core::cmp::min(root, prime - root));
+}
+
+pub fn tonelli_shanks(a: u64, prime: u64) -> u64 {
+    //! Finds a square root of a quadratic residue `a` modulo an odd prime with the Tonelli-Shanks algorithm.
+    let mut q: u64 = prime - 1;
+    let mut s: u32 = 0;
+    while q % 2 == 0 {
+        q = q / 2;
+        s = s + 1;
+    }
+
+    let mut non_residue: u64 = 2;
+    while modular::legendre(non_residue, prime) != -1 {
+        non_residue = non_residue + 1;
+    }
+
+    // Invariant: `root^2 = a * t`, where `t` has order dividing `2^m`.
+    let mut m: u32 = s;
+    let mut c: u64 = modular::mod_pow(non_residue, q, prime);
+    let mut t: u64 = modular::mod_pow(a, q, prime);
+    let mut root: u64 = modular::mod_pow(a, (q + 1) / 2, prime);
+    while t != 1 {
+        // Find the least `i` with `t^(2^i) = 1`.
+        let mut i: u32 = 0;
+        let mut power: u64 = t;
+        while power != 1 {
+            power = modular::mod_mul(power, power, prime);
+            i = i + 1;
+        }
+
+        let mut b: u64 = c;
+        for _ in 0..(m - i - 1) {
+            b = modular::mod_mul(b, b, prime);
+        }
+        m = i;
+        c = modular::mod_mul(b, b, prime);
+        t = modular::mod_mul(t, c, prime);
+        root = modular::mod_mul(root, b, prime);
+    }
+    return root;
+}
+
+pub fn cipolla(a: u64, prime: u64) -> u64 {
+    //! Finds a square root of a quadratic residue `a` modulo an odd prime with Cipolla's algorithm.
+    //!
+    //! Picks `t` with `t^2 - a` a non-residue, and computes `(t + w)^((p + 1) / 2)` in the field `F_p(w)` with
+    //! `w^2 = t^2 - a`; the result has no `w` part.
+    let mut t: u64 = 0;
+    let mut w_squared: u64 = modular::mod_sub_u128(0, a as u128, prime as u128) as u64;
+    while modular::legendre(w_squared, prime) != -1 {
+        t = t + 1;
+        w_squared = modular::mod_sub_u128(
+            modular::mod_mul(t, t, prime) as u128,
+            a as u128,
+            prime as u128,
+        ) as u64;
+    }
+
+    // `(x0 + x1 w) * (y0 + y1 w) = (x0 y0 + x1 y1 w^2) + (x0 y1 + x1 y0) w`.
+    let multiply = |x: (u64, u64), y: (u64, u64)| -> (u64, u64) {
+        let real: u128 = modular::mod_add_u128(
+            modular::mod_mul(x.0, y.0, prime) as u128,
+            modular::mod_mul(modular::mod_mul(x.1, y.1, prime), w_squared, prime) as u128,
+            prime as u128,
+        );
+        let imaginary: u128 = modular::mod_add_u128(
+            modular::mod_mul(x.0, y.1, prime) as u128,
+            modular::mod_mul(x.1, y.0, prime) as u128,
+            prime as u128,
+        );
+        return (real as u64, imaginary as u64);
+    };
+
+    let mut result: (u64, u64) = (1, 0);
+    let mut base: (u64, u64) = (t, 1);
+    let mut exponent: u64 = (prime + 1) / 2;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(result, base);
+        }
+        base = multiply(base, base);
+        exponent = exponent >> 1;
+    }
+    return result.0;
+}
+
+pub fn sqrt_mod_prime_power(a: u64, prime: u64, exponent: u32) -> Option<u64> {
+    //! Finds a square root of `a` modulo `p^k`, or `None` if there is none, the modulus is not a prime power, or
+    //! `p^k` does not fit in `u64`.
+    //!
+    //! For `a = p^e * b` with `p` not dividing `b`, a root exists only for even `e` (or `e >= k`), and is
+    //! `p^(e/2)` times a root of `b` modulo `p^(k - e)`. Roots of a unit are lifted one power of `p` at a time with
+    //! Hensel's lemma.
+    if exponent == 0 {
+        return Some(0);
+    }
+    let modulus: u64 = prime.checked_pow(exponent)?;
+    if !primality::check_if_prime_mr(prime) {
+        return None;
+    }
+
+    let mut a: u64 = a % modulus;
+    if a == 0 {
+        return Some(0);
+    }
+    let mut valuation: u32 = 0;
+    while a % prime == 0 {
+        a = a / prime;
+        valuation = valuation + 1;
+    }
+    if valuation % 2 == 1 {
+        return None;
+    }
+
+    let unit_root: u64 = sqrt_unit_mod_prime_power(a, prime, exponent - valuation)?;
+    return Some(modular::mod_mul(
+        prime.pow(valuation / 2),
+        unit_root,
+        modulus,
+    ));
+}
+
+fn sqrt_unit_mod_prime_power(a: u64, prime: u64, exponent: u32) -> Option<u64> {
+    //! Finds a square root of `a`, which `p` does not divide, modulo `p^k`.
+    let modulus: u128 = (prime as u128).pow(exponent);
+    let a: u128 = a as u128 % modulus;
+
+    if prime == 2 {
+        // Odd squares are `1` modulo `8`; roots are lifted by adding `2^(j-1)` whenever `r^2 != a (mod 2^(j+1))`.
+        let needed: u128 = core::cmp::min(modulus, 8);
+        if a % needed != 1 % needed {
+            return None;
+        }
+        let mut root: u128 = 1;
+        for j in 3..exponent {
+            let next_modulus: u128 = 1 << (j + 1);
+            if (root * root) % next_modulus != a % next_modulus {
+                root = root + (1 << (j - 1));
+            }
+        }
+        return Some((root % modulus) as u64);
+    }
+
+    // `r -> r - (r^2 - a) / (2r)` is exact one more power of `p` each step, since `2r` is a unit.
+    let mut root: u128 = sqrt_mod_prime((a % prime as u128) as u64, prime)? as u128;
+    let mut power: u128 = prime as u128;
+    for _ in 1..exponent {
+        power = power * prime as u128;
+        let error: u128 =
+            modular::mod_sub_u128(modular::mod_mul_u128(root, root, power), a % power, power);
+        let inverse: u128 = modular::mod_inverse_u128(2 * root % power, power)?;
+        root = modular::mod_sub_u128(root, modular::mod_mul_u128(error, inverse, power), power);
+    }
+    return Some(root as u64);
+}
+
+#[cfg(feature = "alloc")]
+pub fn sqrt_mod(a: u64, modulus: u64) -> Option<u64> {
+    //! Finds a square root of `a` modulo any `modulus > 0`, or `None` if there is none.
+    //!
+    //! Factorizes the modulus, finds a root modulo each prime power, and combines them with the Chinese Remainder
+    //! Theorem.
+    if modulus == 0 {
+        return None;
+    }
+    let mut congruences: Vec<(u128, u128)> = Vec::new();
+    for (prime, exponent) in factorization::factorize_u128(modulus as u128) {
+        let root: u64 = sqrt_mod_prime_power(a, prime as u64, exponent)?;
+        congruences.push((root as u128, prime.pow(exponent)));
+    }
+    return modular::crt(&congruences).map(|(root, _)| root as u64);
+}
+
+#[cfg(feature = "alloc")]
+pub fn check_if_quadratic_residue(a: u64, modulus: u64) -> bool {
+    //! Checks whether `a` is a square modulo `modulus > 0`, i.e. whether `x^2 = a (mod modulus)` has a solution.
+    //!
+    //! Odd primes are answered by the Legendre symbol alone; other moduli look for a root.
+    if modulus > 2 && modulus % 2 == 1 && primality::check_if_prime_mr(modulus) {
+        return modular::legendre(a, modulus) != -1;
+    }
+    return sqrt_mod(a, modulus).is_some();
+}
+
+/// Iterator over the non-zero quadratic residues modulo a prime, in increasing order.
+///
+/// Tests each number with the Legendre symbol, so nothing is stored; there are `(p - 1) / 2` of them for an odd
+/// prime.
+#[derive(Debug, Clone)]
+pub struct QuadraticResidues {
+    prime: u64,
+    next: u64,
+}
+
+impl QuadraticResidues {
+    pub fn new(prime: u64) -> Self {
+        //! Creates an iterator over the non-zero quadratic residues modulo a prime; it is empty if the modulus is not
+        //! prime.
+        let next: u64 = if primality::check_if_prime_mr(prime) {
+            1
+        } else {
+            prime
+        };
+        return QuadraticResidues { prime, next };
+    }
+}
+
+impl Iterator for QuadraticResidues {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.next < self.prime {
+            let candidate: u64 = self.next;
+            self.next = self.next + 1;
+            if self.prime == 2 || modular::legendre(candidate, self.prime) == 1 {
+                return Some(candidate);
+            }
+        }
+        return None;
+    }
+}
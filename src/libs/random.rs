@@ -0,0 +1,170 @@
+//! Random primes from a seedable generator, for reproducible test fixtures.
+//!
+//! The built-in `SeededRng` is xoshiro256** seeded through SplitMix64: fast and statistically sound, but not
+//! cryptographically secure. Any other generator can be used by implementing `RandomSource` for it.
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::libs::primality;
+
+/// Every range of this many numbers below `2^64` contains a prime (the largest prime gap there is `1550`), so
+/// larger ranges are sampled directly and smaller ones are counted first.
+const DIRECT_SAMPLING_LIMIT: u64 = 1 << 16;
+
+/// A source of uniformly distributed random `u64`s.
+pub trait RandomSource {
+    /// Returns the next random `u64`.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a uniformly distributed number from `low` till `high`, inclusive.
+    ///
+    /// Uses Lemire's multiply-and-reject method, so there is no modulo bias.
+    fn gen_range(&mut self, low: u64, high: u64) -> u64 {
+        if low > high {
+            panic!(
+                "`{low}..={high}` is an empty range.",
+                low = low,
+                high = high
+            );
+        }
+        let span: u64 = (high - low).wrapping_add(1);
+        if span == 0 {
+            return self.next_u64();
+        }
+        let threshold: u64 = span.wrapping_neg() % span;
+        loop {
+            let product: u128 = self.next_u64() as u128 * span as u128;
+            if product as u64 >= threshold {
+                return low + (product >> 64) as u64;
+            }
+        }
+    }
+}
+
+/// The built-in xoshiro256** generator; the same seed always gives the same sequence.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SeededRng {
+    state: [u64; 4],
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> Self {
+        //! Creates a generator from a seed, expanding it into the 256-bit state with SplitMix64.
+        let mut mix: u64 = seed;
+        let mut state: [u64; 4] = [0; 4];
+        for word in state.iter_mut() {
+            mix = mix.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z: u64 = mix;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            *word = z ^ (z >> 31);
+        }
+        return SeededRng { state };
+    }
+}
+
+impl RandomSource for SeededRng {
+    fn next_u64(&mut self) -> u64 {
+        let result: u64 = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let shifted: u64 = self.state[1] << 17;
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= shifted;
+        self.state[3] = self.state[3].rotate_left(45);
+        return result;
+    }
+}
+
+fn bit_range(bits: u32) -> (u64, u64) {
+    //! The smallest and largest numbers with exactly `bits` bits.
+    if !(2..=64).contains(&bits) {
+        panic!(
+            "There are no primes of `{bits}` bits in a `u64`.",
+            bits = bits
+        );
+    }
+    return (1_u64 << (bits - 1), u64::MAX >> (64 - bits));
+}
+
+pub fn random_prime<R: RandomSource>(bits: u32, rng: &mut R) -> u64 {
+    //! Picks a prime with exactly `bits` bits uniformly at random, for `2 <= bits <= 64`.
+    let (low, high): (u64, u64) = bit_range(bits);
+    return random_prime_in(low, high, rng).unwrap();
+}
+
+pub fn random_prime_in<R: RandomSource>(low: u64, high: u64, rng: &mut R) -> Option<u64> {
+    //! Picks a prime from `low` till `high`, inclusive, uniformly at random, or `None` if there is none.
+    //!
+    //! Large ranges are sampled until a number passes Miller-Rabin, which is uniform over the primes of the range.
+    //! Small ones may hold no prime at all, so their primes are counted first and one is picked by its index.
+    if low > high {
+        return None;
+    }
+    if high - low >= DIRECT_SAMPLING_LIMIT {
+        loop {
+            let candidate: u64 = rng.gen_range(low, high);
+            if primality::check_if_prime_mr(candidate) {
+                return Some(candidate);
+            }
+        }
+    }
+
+    let count: u64 = (low..=high)
+        .filter(|num| primality::check_if_prime_mr(*num))
+        .count() as u64;
+    if count == 0 {
+        return None;
+    }
+    let index: u64 = rng.gen_range(0, count - 1);
+    return (low..=high)
+        .filter(|num| primality::check_if_prime_mr(*num))
+        .nth(index as usize);
+}
+
+pub fn random_safe_prime<R: RandomSource>(bits: u32, rng: &mut R) -> u64 {
+    //! Picks a safe prime `p` (with `(p - 1) / 2` prime too) with exactly `bits` bits uniformly at random, for
+    //! `3 <= bits <= 64`.
+    if bits < 3 {
+        panic!("There are no safe primes of `{bits}` bits.", bits = bits);
+    }
+    let (low, high): (u64, u64) = bit_range(bits);
+    loop {
+        let candidate: u64 = rng.gen_range(low, high);
+        // Above 7, every safe prime is 11 modulo 12; the cheap checks go first.
+        if candidate > 7 && candidate % 12 != 11 {
+            continue;
+        }
+        if primality::check_if_prime_mr(candidate / 2) && primality::check_if_prime_mr(candidate) {
+            return candidate;
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn random_composite_with_factors<R: RandomSource>(
+    count: u32,
+    bits: u32,
+    rng: &mut R,
+) -> (u128, Vec<u64>) {
+    //! Builds a number from `count` random primes of exactly `bits` bits each, e.g. a semiprime for `count = 2`.
+    //!
+    //! Returns the product and its prime factors in increasing order; the product must fit in `u128`.
+    if count == 0 || count as u64 * bits as u64 > 128 {
+        panic!(
+            "`{count}` factors of `{bits}` bits do not fit in a `u128`.",
+            count = count,
+            bits = bits
+        );
+    }
+    let mut factors: Vec<u64> = Vec::new();
+    let mut product: u128 = 1;
+    for _ in 0..count {
+        let factor: u64 = random_prime(bits, rng);
+        factors.push(factor);
+        product *= factor as u128;
+    }
+    factors.sort();
+    return (product, factors);
+}
@@ -0,0 +1,260 @@
+//! Functions/methods to find record-setting numbers by divisor count and divisor sum.
+//!
+//! 1. __Highly composite__ (A002182): more divisors than any smaller number.
+//! 2. __Largely composite__ (A067128): at least as many divisors as any smaller number.
+//! 3. __Superior highly composite__ (A002201): maximises `d(n) / n^e` for some `e > 0`.
+//! 4. __Highly abundant__ (A002093): a larger divisor sum than any smaller number.
+//! 5. __Colossally abundant__ (A004490): maximises `sigma(n) / n^(1 + e)` for some `e > 0`.
+//!
+//! The highly composite, superior highly composite and colossally abundant numbers all have prime exponents that
+//! do not increase from one prime to the next, so they are found by enumerating those prime signatures rather
+//! than by scanning. The other two families are found with segmented sieves.
+use alloc::vec::Vec;
+
+use crate::libs::arithmetic;
+#[cfg(feature = "std")]
+use crate::libs::prime_int::PrimeInt;
+use crate::libs::tables;
+
+// Numbers sieved per segment when scanning.
+const SEGMENT_SIZE: u64 = 1 << 16;
+// Every product of primorials that fits in a `u64` is built from the first 15 primes; the 16th is still needed
+// as the next prime that could be multiplied in.
+const RECORD_PRIMES: [u64; 16] = tables::prime_table::<16>();
+
+pub fn find_signature_numbers_till(num: u64) -> Vec<u64> {
+    //! Finds all the numbers till a given number whose prime exponents do not increase from one prime to the next,
+    //! i.e. the products of primorials (A025487).
+    let mut signature_numbers: Vec<u64> = Vec::new();
+    enumerate_signatures(num, 1, 0, u32::MAX, &mut signature_numbers);
+    signature_numbers.sort();
+    return signature_numbers;
+}
+
+fn enumerate_signatures(
+    limit: u64,
+    current: u64,
+    prime_index: usize,
+    max_exponent: u32,
+    found: &mut Vec<u64>,
+) {
+    // Depth-first search over the exponent of each prime in turn, capped by the exponent of the previous prime.
+    found.push(current);
+    if prime_index >= RECORD_PRIMES.len() {
+        return;
+    }
+
+    let prime: u64 = RECORD_PRIMES[prime_index];
+    let mut next: u64 = current;
+    let mut exponent: u32 = 1;
+    while exponent <= max_exponent {
+        next = match next.checked_mul(prime) {
+            Some(next) if next <= limit => next,
+            _ => break,
+        };
+        enumerate_signatures(limit, next, prime_index + 1, exponent, found);
+        exponent = exponent + 1;
+    }
+}
+
+pub fn find_hcn_by_signature_till(num: u64) -> Vec<u64> {
+    //! Finds all the highly composite numbers till a given number, as the divisor-count records among the
+    //! products of primorials.
+    let mut hcn: Vec<u64> = Vec::new();
+    let mut record: u64 = 0;
+    for candidate in find_signature_numbers_till(num) {
+        let n_divisors: u64 = arithmetic::divisor_count(candidate);
+        if n_divisors > record {
+            record = n_divisors;
+            hcn.push(candidate);
+        }
+    }
+    return hcn;
+}
+
+pub fn check_if_largely_composite(num: u64) -> bool {
+    //! Checks to see if a given number has at least as many divisors as every smaller number.
+    //!
+    //! The most divisors of any smaller number belongs to the largest highly composite number below it.
+    if num == 0 {
+        return false;
+    }
+
+    let previous_record: u64 = match find_hcn_by_signature_till(num - 1).last() {
+        Some(hcn) => arithmetic::divisor_count(*hcn),
+        None => 0,
+    };
+    return arithmetic::divisor_count(num) >= previous_record;
+}
+
+pub fn find_largely_composite_till(num: u64) -> Vec<u64> {
+    //! Finds all the largely composite numbers till a given number, with a segmented divisor-count sieve.
+    let mut found: Vec<u64> = Vec::new();
+    let mut record: u64 = 0;
+    let mut low: u64 = 1;
+    while low <= num {
+        let high: u64 = core::cmp::min(num, low.saturating_add(SEGMENT_SIZE - 1));
+        for (offset, n_divisors) in arithmetic::divisor_count_segment(low, high)
+            .into_iter()
+            .enumerate()
+        {
+            if n_divisors >= record {
+                record = n_divisors;
+                found.push(low + offset as u64);
+            }
+        }
+        if high == num {
+            break;
+        }
+        low = high + 1;
+    }
+    return found;
+}
+
+pub fn check_if_highly_abundant(num: u64) -> bool {
+    //! Checks to see if a given number has a larger divisor sum than every smaller number.
+    //!
+    //! Sieves the divisor sums of every smaller number, so this is linear in `num`.
+    if num == 0 {
+        return false;
+    }
+
+    let sum: u128 = arithmetic::divisor_sum(num);
+    let mut low: u64 = 1;
+    while low < num {
+        let high: u64 = core::cmp::min(num - 1, low.saturating_add(SEGMENT_SIZE - 1));
+        if arithmetic::divisor_sum_segment(low, high)
+            .into_iter()
+            .any(|other| other >= sum)
+        {
+            return false;
+        }
+        low = high + 1;
+    }
+    return true;
+}
+
+pub fn find_highly_abundant_till(num: u64) -> Vec<u64> {
+    //! Finds all the highly abundant numbers till a given number, with a segmented divisor-sum sieve.
+    let mut found: Vec<u64> = Vec::new();
+    let mut record: u128 = 0;
+    let mut low: u64 = 1;
+    while low <= num {
+        let high: u64 = core::cmp::min(num, low.saturating_add(SEGMENT_SIZE - 1));
+        for (offset, sum) in arithmetic::divisor_sum_segment(low, high)
+            .into_iter()
+            .enumerate()
+        {
+            if sum > record {
+                record = sum;
+                found.push(low + offset as u64);
+            }
+        }
+        if high == num {
+            break;
+        }
+        low = high + 1;
+    }
+    return found;
+}
+
+#[cfg(feature = "std")]
+fn shcn_critical(prime: u64, exponent: u32) -> f64 {
+    // The `e` below which raising the exponent of `prime` from `exponent` to `exponent + 1` increases
+    // `d(n) / n^e`: `(exponent + 2) / (exponent + 1) = prime^e`.
+    let growth: f64 = 1.0 / (exponent as f64 + 1.0);
+    return growth.ln_1p() / (prime as f64).ln();
+}
+
+#[cfg(feature = "std")]
+fn ca_critical(prime: u64, exponent: u32) -> f64 {
+    // The `e` below which raising the exponent of `prime` from `exponent` to `exponent + 1` increases
+    // `sigma(n) / n^(1 + e)`: `sigma(p^(a+1)) / sigma(p^a) = p^(1 + e)`.
+    let prime_f: f64 = prime as f64;
+    let growth: f64 = (prime_f - 1.0) / (prime_f.powi(exponent as i32 + 2) - prime_f);
+    return growth.ln_1p() / prime_f.ln();
+}
+
+#[cfg(feature = "std")]
+fn check_if_parametric_record(num: u64, critical: fn(u64, u32) -> f64) -> bool {
+    // `n` maximises a multiplicative `f(n) / n^e` for some `e > 0` exactly when no single exponent can be raised
+    // or lowered to improve it. Raising any exponent must not help (`e` at least every `critical(p, a_p)`),
+    // lowering any must not help either (`e` at most every `critical(p, a_p - 1)`), and that range of `e` must
+    // not be empty.
+    if num < 2 {
+        return false;
+    }
+
+    let factors: Vec<(u64, u32)> = num.factorize();
+    let mut lowest: f64 = 0.0;
+    let mut highest: f64 = f64::INFINITY;
+    for (i, prime) in RECORD_PRIMES.iter().enumerate() {
+        let exponent: u32 = match factors.get(i) {
+            Some((factor, exponent)) if factor == prime => *exponent,
+            Some(_) => return false, // A prime was skipped, so the exponents are not non-increasing.
+            None => {
+                // The first prime that does not divide `num`.
+                lowest = lowest.max(critical(*prime, 0));
+                break;
+            }
+        };
+        if i > 0 && exponent > factors[i - 1].1 {
+            return false;
+        }
+        lowest = lowest.max(critical(*prime, exponent));
+        highest = highest.min(critical(*prime, exponent - 1));
+    }
+    return lowest < highest;
+}
+
+#[cfg(feature = "std")]
+fn find_parametric_records_till(num: u64, critical: fn(u64, u32) -> f64) -> Vec<u64> {
+    // Walks `e` downwards from infinity: each time it passes a prime's next critical value, that prime's exponent
+    // goes up by one, and the running product is the next record.
+    let mut found: Vec<u64> = Vec::new();
+    let mut exponents: [u32; RECORD_PRIMES.len()] = [0; RECORD_PRIMES.len()];
+    let mut current: u64 = 1;
+    loop {
+        let mut best: usize = 0;
+        let mut best_critical: f64 = f64::NEG_INFINITY;
+        for (i, prime) in RECORD_PRIMES.iter().enumerate() {
+            let value: f64 = critical(*prime, exponents[i]);
+            if value > best_critical {
+                best = i;
+                best_critical = value;
+            }
+        }
+
+        current = match current.checked_mul(RECORD_PRIMES[best]) {
+            Some(next) if next <= num => next,
+            _ => break,
+        };
+        exponents[best] = exponents[best] + 1;
+        found.push(current);
+    }
+    return found;
+}
+
+#[cfg(feature = "std")]
+pub fn check_if_superior_highly_composite(num: u64) -> bool {
+    //! Checks to see if a given number is a superior highly composite number.
+    return check_if_parametric_record(num, shcn_critical);
+}
+
+#[cfg(feature = "std")]
+pub fn find_superior_highly_composite_till(num: u64) -> Vec<u64> {
+    //! Finds all the superior highly composite numbers till a given number.
+    return find_parametric_records_till(num, shcn_critical);
+}
+
+#[cfg(feature = "std")]
+pub fn check_if_colossally_abundant(num: u64) -> bool {
+    //! Checks to see if a given number is a colossally abundant number.
+    return check_if_parametric_record(num, ca_critical);
+}
+
+#[cfg(feature = "std")]
+pub fn find_colossally_abundant_till(num: u64) -> Vec<u64> {
+    //! Finds all the colossally abundant numbers till a given number.
+    return find_parametric_records_till(num, ca_critical);
+}
@@ -0,0 +1,120 @@
+//! Segmented sieve of Eratosthenes over an arbitrary range of `u64`.
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::libs::primality;
+use crate::libs::primes;
+
+/// Numbers sieved at a time.
+const SEGMENT_SIZE: u64 = 1 << 16;
+
+/// Only primes till this are used to sieve; above `BASE_LIMIT^2`, the numbers left over are checked with
+/// Miller-Rabin instead, which keeps the base primes to a few hundred kilobytes anywhere in `u64`.
+pub const BASE_LIMIT: u64 = 1 << 20;
+
+pub fn base_primes(high: u64) -> Vec<u64> {
+    //! Finds the primes needed to sieve a range ending at `high`.
+    let limit: u64 = core::cmp::min(primality::isqrt_u128(high as u128) as u64, BASE_LIMIT);
+    return primes::find_primes_till(limit);
+}
+
+pub fn sieve_flags(low: u64, high: u64, base_primes: &[u64]) -> Vec<bool> {
+    //! Flags the primes from `low` till `high`, inclusive, given the primes from `base_primes(high)`; entry `i` is
+    //! true when `low + i` is prime.
+    if low > high {
+        return Vec::new();
+    }
+
+    let mut flags: Vec<bool> = vec![true; (high - low + 1) as usize];
+    for prime in base_primes.iter() {
+        let square: u64 = prime * prime;
+        if square > high {
+            break;
+        }
+        let mut multiple: u64 = if square >= low {
+            square
+        } else {
+            match ((low - 1) / prime * prime).checked_add(*prime) {
+                Some(multiple) => multiple,
+                None => continue,
+            }
+        };
+        while multiple <= high {
+            flags[(multiple - low) as usize] = false;
+            multiple = match multiple.checked_add(*prime) {
+                Some(multiple) => multiple,
+                None => break,
+            };
+        }
+    }
+
+    let fully_sieved: bool = primality::isqrt_u128(high as u128) <= BASE_LIMIT as u128;
+    for (offset, flag) in flags.iter_mut().enumerate() {
+        let num: u64 = low + offset as u64;
+        if *flag && (num < 2 || !(fully_sieved || primality::check_if_prime_mr(num))) {
+            *flag = false;
+        }
+    }
+    return flags;
+}
+
+pub fn sieve_segment(low: u64, high: u64, base_primes: &[u64]) -> Vec<u64> {
+    //! Finds the primes from `low` till `high`, inclusive, given the primes from `base_primes(high)`.
+    let mut found: Vec<u64> = Vec::new();
+    for (offset, is_prime) in sieve_flags(low, high, base_primes).iter().enumerate() {
+        if *is_prime {
+            found.push(low + offset as u64);
+        }
+    }
+    return found;
+}
+
+/// Iterator over the primes in a range, in increasing order.
+///
+/// Sieves one segment at a time, so memory use stays constant however long the range is.
+#[derive(Debug, Clone)]
+pub struct PrimesInRange {
+    next: u64, // First number not yet sieved.
+    high: u64,
+    done: bool,
+    base_primes: Vec<u64>,
+    buffer: Vec<u64>, // Primes from the current segment, in reverse order.
+}
+
+impl PrimesInRange {
+    pub fn new(low: u64, high: u64) -> Self {
+        //! Creates an iterator over the primes from `low` till `high`, inclusive.
+        return PrimesInRange {
+            next: low,
+            high,
+            done: low > high,
+            base_primes: base_primes(high),
+            buffer: Vec::new(),
+        };
+    }
+
+    fn sieve_next_segment(&mut self) {
+        // Fills the buffer with the primes of the next segment.
+        let low: u64 = self.next;
+        let high: u64 = core::cmp::min(self.high, low.saturating_add(SEGMENT_SIZE - 1));
+        self.buffer = sieve_segment(low, high, &self.base_primes);
+        self.buffer.reverse();
+
+        if high == self.high {
+            self.done = true;
+        } else {
+            self.next = high + 1;
+        }
+    }
+}
+
+impl Iterator for PrimesInRange {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        while self.buffer.is_empty() && !self.done {
+            self.sieve_next_segment();
+        }
+        return self.buffer.pop();
+    }
+}
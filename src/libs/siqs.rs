@@ -0,0 +1,686 @@
+//! The self-initializing quadratic sieve (SIQS), for composites of 25 to 42 digits whose two factors are too close in
+//! size for ECM.
+//!
+//! Looks for many `x` where `Q(x) = ((Ax + B)^2 - kN) / A` is smooth over a factor base of the primes `p` for which
+//! `kN` is a square modulo `p`, allowing one larger prime per value. Products of these relations whose exponents are
+//! all even are found with Gaussian elimination over GF(2), and each gives `X^2 = Y^2 (mod N)`, which splits `N`
+//! with probability `1/2`. Each `A` is a product of factor base primes, and serves `2^(s - 1)` polynomials whose roots
+//! are updated from one another with a single addition per prime.
+//!
+//! `N` and `kN` are `BigUint`s, so the same code serves `u128` and larger inputs, up to `SIQS_MAXIMUM_BITS`. Below that,
+//! `A` is near `sqrt(2kN) / M` and every value the sieve works with fits in `i128`; only `C`, the large prime checks
+//! and the final congruence of squares touch `N` itself.
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::libs::bigint::BigUint;
+use crate::libs::modular;
+use crate::libs::primality;
+use crate::libs::primes;
+use crate::libs::quadratic;
+use crate::libs::random::{RandomSource, SeededRng};
+
+/// Numbers below this are left to the other methods; their factor bases are too small to pick `A` from.
+const SIQS_MINIMUM: u128 = 1 << 40;
+
+/// Numbers past this many bits are left to the other methods: `B^2` stops fitting in `u128` a little past it, and
+/// SIQS takes seconds there anyway.
+pub const SIQS_MAXIMUM_BITS: u64 = 140;
+
+/// `(bits, factor base size, M)` for numbers of up to `bits` bits; the sieve interval is `[-M, M)`.
+const PARAMETERS: [(u32, usize, i64); 12] = [
+    (48, 60, 1 << 12),
+    (64, 100, 1 << 13),
+    (72, 120, 1 << 13),
+    (80, 150, 1 << 14),
+    (88, 200, 1 << 14),
+    (96, 300, 1 << 15),
+    (104, 450, 1 << 15),
+    (112, 700, 1 << 15),
+    (120, 800, 1 << 15),
+    (128, 1_000, 1 << 15),
+    (136, 1_200, 1 << 15),
+    (140, 1_400, 1 << 15),
+];
+
+/// The odd square-free multipliers `k` that are tried; the Knuth-Schroeppel function picks the one for which the
+/// small primes divide `Q(x)` most often.
+const MULTIPLIERS: [u64; 31] = [
+    1, 3, 5, 7, 11, 13, 15, 17, 19, 21, 23, 29, 31, 33, 35, 37, 39, 41, 43, 47, 51, 53, 55, 57, 59,
+    61, 65, 67, 69, 71, 73,
+];
+
+/// Factor base primes below this are not sieved with, as they cost the most and add the least; the threshold is
+/// lowered to make up for them.
+const SMALL_PRIME_LIMIT: u64 = 32;
+
+/// Bits the threshold is lowered by to make up for the primes below `SMALL_PRIME_LIMIT`.
+const SMALL_PRIME_ALLOWANCE: u32 = 4;
+
+/// A value left with a single prime cofactor below `LARGE_PRIME_MULTIPLIER` times the largest factor base prime is
+/// kept as a partial relation; two partials with the same large prime make a full one.
+const LARGE_PRIME_MULTIPLIER: u64 = 64;
+
+/// Relations collected beyond the number of factor base columns, so that there are several dependencies.
+const EXTRA_RELATIONS: usize = 32;
+
+/// Logarithms are kept in units of `1 / LOG_SCALE` bits, so that the sieve fits in `u8`.
+const LOG_SCALE: u32 = 2;
+
+/// The seed `siqs` picks the `A`s with, so that factorizations are reproducible.
+pub const DEFAULT_SEED: u64 = 0;
+
+/// The primes of the factor base, with a square root of `kN` modulo each and its logarithm; `2` comes first.
+struct FactorBase {
+    primes: Vec<u64>,
+    roots: Vec<u64>,
+    logs: Vec<u8>,
+}
+
+/// A product of `(Ax + B)^2` that is congruent to `(-1)^e0 * p1^e1 * ... * L^2` modulo `N`, as `y = Ax + B`, the
+/// columns of the primes (with repeats; `0` is `-1`, `i + 1` the `i`-th prime) and the large prime `L` (`1` if none).
+#[derive(Clone)]
+struct Relation {
+    y: BigUint,
+    columns: Vec<u32>,
+    large: u128,
+}
+
+/// A SIQS polynomial `Q(x) = Ax^2 + 2Bx + C` with `B^2 - AC = kN`.
+struct Polynomial {
+    a: u128,
+    b: i128,
+    c: i128,
+}
+
+pub fn siqs(num: u128) -> Option<u128> {
+    //! Looks for a non-trivial factor of an odd composite number that is not a perfect power with the
+    //! self-initializing quadratic sieve, or returns `None` if it is below `2^40`.
+    //!
+    //! Takes about `exp(sqrt(ln n ln ln n))` steps whatever the factors are; a 38-digit semiprime takes about a
+    //! tenth of a second.
+    let factor: BigUint = siqs_big(&BigUint::from_u128(num))?;
+    return factor.to_u128();
+}
+
+pub fn siqs_big(num: &BigUint) -> Option<BigUint> {
+    //! `siqs` for a `BigUint`, or `None` if it is below `2^40` or has more than `SIQS_MAXIMUM_BITS` bits; a 40-digit
+    //! semiprime takes about a seventh of a second.
+    if *num < BigUint::from_u128(SIQS_MINIMUM) || num.bits() > SIQS_MAXIMUM_BITS {
+        return None;
+    }
+    if !num.is_odd() {
+        return Some(BigUint::from_u128(2));
+    }
+    let bits: u32 = num.bits() as u32;
+    let mut parameters: (u32, usize, i64) = PARAMETERS[PARAMETERS.len() - 1];
+    for candidate in PARAMETERS {
+        if candidate.0 >= bits {
+            parameters = candidate;
+            break;
+        }
+    }
+    let (_, base_size, half_width): (u32, usize, i64) = parameters;
+
+    let multiplier: u64 = choose_multiplier(num);
+    let scaled: BigUint = num.mul(&BigUint::from_u128(multiplier as u128));
+    let base: FactorBase = match build_factor_base(num, &scaled, base_size) {
+        Ok(base) => base,
+        Err(factor) => return Some(factor),
+    };
+    let largest: u64 = base.primes[base.primes.len() - 1];
+    let large_bound: u128 = core::cmp::min(
+        largest as u128 * LARGE_PRIME_MULTIPLIER as u128,
+        largest as u128 * largest as u128,
+    );
+
+    // A value `Q(x)` is about `M sqrt(kN / 2)`; it is looked at once the logarithms of its sieved factors add up to
+    // all of it but a large prime and the small primes.
+    let value_log: u32 = log2_scaled(half_width as u128) + log2_scaled_big(&scaled) / 2;
+    let threshold: u8 = value_log
+        .saturating_sub(log2_scaled(large_bound))
+        .saturating_sub(SMALL_PRIME_ALLOWANCE * LOG_SCALE)
+        .saturating_sub(LOG_SCALE / 2)
+        .min(u8::MAX as u32) as u8;
+
+    let columns: usize = base.primes.len() + 1;
+    let mut relations: Vec<Relation> = Vec::new();
+    let mut partials: BTreeMap<u128, Relation> = BTreeMap::new();
+    let mut used: Vec<u128> = Vec::new();
+    let mut rng: SeededRng = SeededRng::new(DEFAULT_SEED);
+    let mut wanted: usize = columns + EXTRA_RELATIONS;
+    let mut sieve: Vec<u8> = vec![0; 2 * half_width as usize];
+
+    // Every `A` gets about `sqrt(2kN) / M`, which is about as large as the values `Q(x)` can get.
+    let target: u128 = 2 * isqrt_big(&scaled.shr(1)) / half_width as u128;
+    for _ in 0..base_size * 64 {
+        let a_factors: Vec<usize> = choose_a(&base, multiplier, target, &used, &mut rng)?;
+        let a: u128 = a_factors
+            .iter()
+            .map(|index| base.primes[*index] as u128)
+            .product();
+        used.push(a);
+
+        let found: Option<BigUint> = sieve_with_a(
+            num,
+            &scaled,
+            &base,
+            &a_factors,
+            a,
+            half_width,
+            threshold,
+            large_bound,
+            &mut sieve,
+            &mut relations,
+            &mut partials,
+        );
+        if found.is_some() {
+            return found;
+        }
+
+        if relations.len() >= wanted {
+            if let Some(factor) = find_dependency_factor(num, columns, &relations, &base) {
+                return Some(factor);
+            }
+            wanted = relations.len() + EXTRA_RELATIONS;
+        }
+    }
+    return None;
+}
+
+fn choose_multiplier(num: &BigUint) -> u64 {
+    // The Knuth-Schroeppel function: the expected contribution of the small primes to `log Q(x)` for `kN`, less half
+    // of `log k`. Logarithms are in `1 / 1024` bits.
+    let small_primes: Vec<u64> = primes::find_primes_till(300);
+    let mut best: (i64, u64) = (i64::MIN, 1);
+    for multiplier in MULTIPLIERS {
+        let mut score: i64 = -(fixed_log2(multiplier as u128) as i64) / 2;
+        score = score
+            + match modular::mod_mul(num.rem_u64(8), multiplier, 8) {
+                1 => 2 * 1_024,
+                5 => 1_024,
+                _ => 512,
+            };
+        for prime in small_primes.iter().skip(1) {
+            let log: i64 = fixed_log2(*prime as u128) as i64;
+            let residue: u64 = modular::mod_mul(num.rem_u64(*prime), multiplier, *prime);
+            if residue == 0 {
+                score = score + log / *prime as i64;
+            } else if modular::legendre(residue, *prime) == 1 {
+                score = score + 2 * log / (*prime as i64 - 1);
+            }
+        }
+        if score > best.0 {
+            best = (score, multiplier);
+        }
+    }
+    return best.1;
+}
+
+fn build_factor_base(num: &BigUint, scaled: &BigUint, size: usize) -> Result<FactorBase, BigUint> {
+    // `2` and the first `size - 1` odd primes modulo which `kN` is a square. Gives a prime that divides `N` instead.
+    let mut base: FactorBase = FactorBase {
+        primes: vec![2],
+        roots: vec![1],
+        logs: vec![LOG_SCALE as u8],
+    };
+    let mut limit: u64 = 32 * size as u64;
+    loop {
+        for prime in primes::find_primes_till(limit) {
+            if prime == 2 || prime <= base.primes[base.primes.len() - 1] {
+                continue;
+            }
+            if num.rem_u64(prime) == 0 {
+                return Err(BigUint::from_u128(prime as u128));
+            }
+            let residue: u64 = scaled.rem_u64(prime);
+            if residue != 0 && modular::legendre(residue, prime) != 1 {
+                continue;
+            }
+            base.primes.push(prime);
+            base.roots
+                .push(quadratic::sqrt_mod_prime(residue, prime).unwrap_or(0));
+            base.logs.push(log2_scaled(prime as u128) as u8);
+            if base.primes.len() == size {
+                return Ok(base);
+            }
+        }
+        limit = limit * 2;
+    }
+}
+
+fn choose_a(
+    base: &FactorBase,
+    multiplier: u64,
+    target: u128,
+    used: &[u128],
+    rng: &mut SeededRng,
+) -> Option<Vec<usize>> {
+    // Picks `s` factor base primes whose product is near `target` and has not been used yet: `s - 1` at random from
+    // around `target^(1/s)`, where `s` keeps them at about 11 bits, and the last one to bring the product closest to
+    // `target`. Primes dividing `k` are left out, as `kN` has no useful root modulo them.
+    let target_bits: u32 = 128 - target.leading_zeros();
+    let largest_bits: u32 = 64 - base.primes[base.primes.len() - 1].leading_zeros();
+    let mut count: u32 = core::cmp::max(1, (target_bits + 5) / 11);
+    while target_bits / count >= largest_bits {
+        count = count + 1;
+    }
+    let prime_bits: u32 = target_bits / count;
+    let usable = |index: usize| -> bool {
+        let prime: u64 = base.primes[index];
+        return prime >= SMALL_PRIME_LIMIT && multiplier % prime != 0;
+    };
+    let size: usize = base.primes.len();
+    let low: usize = base
+        .primes
+        .partition_point(|prime| (*prime as u128) < (1 << prime_bits) / 2);
+    let high: usize = base
+        .primes
+        .partition_point(|prime| (*prime as u128) < (1 << prime_bits) * 2);
+    let low: usize = core::cmp::max(
+        1,
+        core::cmp::min(low, size.saturating_sub(4 * count as usize)),
+    );
+    let high: usize = core::cmp::min(core::cmp::max(high, low + 4 * count as usize), size);
+
+    for _ in 0..1_000 {
+        let mut factors: Vec<usize> = Vec::new();
+        let mut product: u128 = 1;
+        while factors.len() + 1 < count as usize {
+            let index: usize = rng.gen_range(low as u64, high as u64 - 1) as usize;
+            if usable(index) && !factors.contains(&index) {
+                factors.push(index);
+                product = product * base.primes[index] as u128;
+            }
+        }
+
+        // The last prime: the one closest to `target / product`, on either side, that gives an unused `A`.
+        let middle: usize = base
+            .primes
+            .partition_point(|prime| (*prime as u128) < target / product);
+        for offset in 0..size {
+            for index in [middle.checked_sub(offset + 1), Some(middle + offset)] {
+                let index: usize = match index {
+                    Some(index) if index < size => index,
+                    _ => continue,
+                };
+                let a: u128 = product * base.primes[index] as u128;
+                if usable(index) && !factors.contains(&index) && !used.contains(&a) {
+                    factors.push(index);
+                    factors.sort();
+                    return Some(factors);
+                }
+            }
+        }
+    }
+    return None;
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sieve_with_a(
+    num: &BigUint,
+    scaled: &BigUint,
+    base: &FactorBase,
+    a_factors: &[usize],
+    a: u128,
+    half_width: i64,
+    threshold: u8,
+    large_bound: u128,
+    sieve: &mut [u8],
+    relations: &mut Vec<Relation>,
+    partials: &mut BTreeMap<u128, Relation>,
+) -> Option<BigUint> {
+    // Sieves with all `2^(s - 1)` polynomials of a given `A`, adding the relations found. Gives a factor instead if
+    // one turns up along the way.
+    //
+    // `B = B_1 +- B_2 +- ... +- B_s` with `B_j = (A / q_j) * gamma_j`, where `gamma_j = t_j (A / q_j)^-1 (mod q_j)`
+    // for a root `t_j` of `kN` modulo `q_j`, so that `B^2 = kN (mod A)`.
+    let mut terms: Vec<u128> = Vec::new();
+    for index in a_factors {
+        let prime: u64 = base.primes[*index];
+        let cofactor: u128 = a / prime as u128;
+        let inverse: u64 = modular::mod_inverse((cofactor % prime as u128) as u64, prime)?;
+        let mut gamma: u64 = modular::mod_mul(base.roots[*index], inverse, prime);
+        if gamma > prime / 2 {
+            gamma = prime - gamma;
+        }
+        terms.push(cofactor * gamma as u128);
+    }
+    let mut b: i128 = terms.iter().map(|term| *term as i128).sum();
+
+    // The roots `A^-1 (+-t - B)` of every other prime, shifted by `M` to index the sieve, and `2 B_j A^-1` for the
+    // updates; primes dividing `A` are not sieved with.
+    let size: usize = base.primes.len();
+    let mut first: Vec<u64> = vec![0; size];
+    let mut second: Vec<u64> = vec![0; size];
+    let mut steps: Vec<Vec<u64>> = vec![vec![0; size]; terms.len()];
+    let mut divides_a: Vec<bool> = vec![false; size];
+    for index in a_factors {
+        divides_a[*index] = true;
+    }
+    for index in 1..size {
+        if divides_a[index] {
+            continue;
+        }
+        let prime: u64 = base.primes[index];
+        let inverse: u64 = modular::mod_inverse((a % prime as u128) as u64, prime)?;
+        for (term, step) in terms.iter().zip(steps.iter_mut()) {
+            step[index] =
+                modular::mod_mul(2 * (term % prime as u128) as u64 % prime, inverse, prime);
+        }
+        let b_residue: u64 = b.rem_euclid(prime as i128) as u64;
+        let shift: u64 = (half_width as u64) % prime;
+        let root: u64 = base.roots[index];
+        first[index] =
+            (modular::mod_mul(inverse, (root + prime - b_residue) % prime, prime) + shift) % prime;
+        second[index] = (modular::mod_mul(inverse, (2 * prime - root - b_residue) % prime, prime)
+            + shift)
+            % prime;
+    }
+
+    // Gray code over the signs of `B_2, ..., B_s`; flipping the sign of `B_j` moves every root by `2 B_j A^-1`.
+    let mut signs: Vec<bool> = vec![true; terms.len()];
+    let polynomials: u32 = 1 << (terms.len() - 1);
+    for step in 0..polynomials {
+        if step > 0 {
+            let j: usize = step.trailing_zeros() as usize + 1;
+            let term: i128 = terms[j] as i128;
+            signs[j] = !signs[j];
+            // Going from `+B_j` to `-B_j` lowers `B` and so raises the roots, and the other way round.
+            b = if signs[j] { b + 2 * term } else { b - 2 * term };
+            for index in 1..size {
+                if divides_a[index] {
+                    continue;
+                }
+                let prime: u64 = base.primes[index];
+                let delta: u64 = if signs[j] {
+                    prime - steps[j][index]
+                } else {
+                    steps[j][index]
+                };
+                first[index] = (first[index] + delta) % prime;
+                second[index] = (second[index] + delta) % prime;
+            }
+        }
+
+        // `B^2 = kN (mod A)` and `B^2 < kN`, so `C = -(kN - B^2) / A` is exact, and about `M sqrt(kN / 2)`.
+        let b_squared: BigUint = BigUint::from_u128(b.unsigned_abs() * b.unsigned_abs());
+        let (quotient, _): (BigUint, BigUint) = scaled
+            .checked_sub(&b_squared)?
+            .div_rem(&BigUint::from_u128(a));
+        let polynomial: Polynomial = Polynomial {
+            a,
+            b,
+            c: -(quotient.to_u128()? as i128),
+        };
+
+        for cell in sieve.iter_mut() {
+            *cell = 0;
+        }
+        for index in 1..size {
+            let prime: u64 = base.primes[index];
+            if prime < SMALL_PRIME_LIMIT || divides_a[index] {
+                continue;
+            }
+            let log: u8 = base.logs[index];
+            let mut position: usize = first[index] as usize;
+            while position < sieve.len() {
+                sieve[position] = sieve[position].saturating_add(log);
+                position = position + prime as usize;
+            }
+            if second[index] != first[index] {
+                let mut position: usize = second[index] as usize;
+                while position < sieve.len() {
+                    sieve[position] = sieve[position].saturating_add(log);
+                    position = position + prime as usize;
+                }
+            }
+        }
+
+        for (position, logarithm) in sieve.iter().enumerate() {
+            if *logarithm < threshold {
+                continue;
+            }
+            let x: i128 = position as i128 - half_width as i128;
+            let relation: Relation = match check_candidate(
+                base,
+                &polynomial,
+                a_factors,
+                &divides_a,
+                (&first, &second),
+                position,
+                x,
+            ) {
+                Some(relation) => relation,
+                None => continue,
+            };
+            if relation.large == 1 {
+                relations.push(relation);
+            } else if relation.large < large_bound {
+                // `large_bound` is below `2^64`, as the factor base primes are below `2^32`.
+                if num.rem_u64(relation.large as u64) == 0 {
+                    return Some(BigUint::from_u128(relation.large));
+                }
+                match partials.get(&relation.large) {
+                    Some(other) => relations.push(Relation {
+                        y: relation.y.mul(&other.y).div_rem(num).1,
+                        columns: [relation.columns.as_slice(), other.columns.as_slice()].concat(),
+                        large: relation.large,
+                    }),
+                    None => {
+                        partials.insert(relation.large, relation);
+                    }
+                }
+            }
+        }
+    }
+    return None;
+}
+
+#[allow(clippy::too_many_arguments)]
+fn check_candidate(
+    base: &FactorBase,
+    polynomial: &Polynomial,
+    a_factors: &[usize],
+    divides_a: &[bool],
+    roots: (&[u64], &[u64]),
+    position: usize,
+    x: i128,
+) -> Option<Relation> {
+    // Divides `Q(x)` by the factor base, using the roots to tell which primes divide it; gives the relation with
+    // whatever is left as its large prime.
+    let value: i128 = polynomial.a as i128 * x * x + 2 * polynomial.b * x + polynomial.c;
+    if value == 0 {
+        return None;
+    }
+    let mut columns: Vec<u32> = a_factors.iter().map(|index| *index as u32 + 1).collect();
+    if value < 0 {
+        columns.push(0);
+    }
+    let mut rest: u128 = value.unsigned_abs();
+    while rest % 2 == 0 {
+        rest = rest / 2;
+        columns.push(1);
+    }
+    for (index, prime) in base.primes.iter().copied().enumerate().skip(1) {
+        let offset: u64 = (position as u64) % prime;
+        if !divides_a[index] && offset != roots.0[index] && offset != roots.1[index] {
+            continue;
+        }
+        while rest % prime as u128 == 0 {
+            rest = rest / prime as u128;
+            columns.push(index as u32 + 1);
+        }
+    }
+
+    let y: u128 = (polynomial.a as i128 * x + polynomial.b).unsigned_abs();
+    return Some(Relation {
+        y: BigUint::from_u128(y),
+        columns,
+        large: rest,
+    });
+}
+
+fn find_dependency_factor(
+    num: &BigUint,
+    columns: usize,
+    relations: &[Relation],
+    base: &FactorBase,
+) -> Option<BigUint> {
+    // Finds the subsets of relations whose exponents are all even and tries the congruence of squares of each.
+    let one: BigUint = BigUint::from_u128(1);
+    for dependency in find_dependencies(columns, relations) {
+        let mut x: BigUint = one.clone();
+        let mut exponents: Vec<u32> = vec![0; columns];
+        let mut y: BigUint = one.clone();
+        for index in dependency {
+            let relation: &Relation = &relations[index];
+            x = x.mul(&relation.y).div_rem(num).1;
+            for column in relation.columns.iter() {
+                exponents[*column as usize] = exponents[*column as usize] + 1;
+            }
+            y = y.mul(&BigUint::from_u128(relation.large)).div_rem(num).1;
+        }
+        for (column, exponent) in exponents.iter().enumerate().skip(1) {
+            if *exponent < 2 {
+                continue;
+            }
+            let prime: BigUint = BigUint::from_u128(base.primes[column - 1] as u128);
+            y = y.mul(&prime.pow(exponent / 2)).div_rem(num).1;
+        }
+
+        let difference: BigUint = match x.checked_sub(&y) {
+            Some(difference) => difference,
+            None => y.checked_sub(&x).unwrap(),
+        };
+        let factor: BigUint = difference.gcd(num);
+        if factor > one && factor < *num {
+            return Some(factor);
+        }
+    }
+    return None;
+}
+
+fn find_dependencies(columns: usize, relations: &[Relation]) -> Vec<Vec<usize>> {
+    // Structured Gaussian elimination over GF(2): relations with a prime no other relation has cannot be part of a
+    // dependency, so they are dropped (repeatedly, as that can leave other primes alone); the rest are reduced as
+    // packed bit rows, each carrying the set of relations it is the sum of. Rows that reduce to zero are dependencies.
+    let parity = |relation: &Relation| -> Vec<usize> {
+        let mut counts: BTreeMap<u32, u32> = BTreeMap::new();
+        for column in relation.columns.iter() {
+            *counts.entry(*column).or_insert(0) += 1;
+        }
+        return counts
+            .into_iter()
+            .filter(|(_, count)| count % 2 == 1)
+            .map(|(column, _)| column as usize)
+            .collect();
+    };
+    let odd_columns: Vec<Vec<usize>> = relations.iter().map(parity).collect();
+
+    let mut active: Vec<bool> = vec![true; relations.len()];
+    loop {
+        let mut weights: Vec<u32> = vec![0; columns];
+        for (index, odd) in odd_columns.iter().enumerate() {
+            if active[index] {
+                for column in odd {
+                    weights[*column] = weights[*column] + 1;
+                }
+            }
+        }
+        let mut changed: bool = false;
+        for (index, odd) in odd_columns.iter().enumerate() {
+            if active[index] && odd.iter().any(|column| weights[*column] == 1) {
+                active[index] = false;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    let kept: Vec<usize> = (0..relations.len())
+        .filter(|index| active[*index])
+        .collect();
+
+    let column_words: usize = (columns + 63) / 64;
+    let history_words: usize = (kept.len() + 63) / 64;
+    let mut rows: Vec<Vec<u64>> = Vec::new();
+    for (row_index, relation_index) in kept.iter().enumerate() {
+        let mut row: Vec<u64> = vec![0; column_words + history_words];
+        for column in odd_columns[*relation_index].iter() {
+            row[column / 64] |= 1 << (column % 64);
+        }
+        row[column_words + row_index / 64] |= 1 << (row_index % 64);
+        rows.push(row);
+    }
+
+    let mut is_pivot: Vec<bool> = vec![false; rows.len()];
+    for column in 0..columns {
+        let (word, bit): (usize, u64) = (column / 64, 1 << (column % 64));
+        let pivot: usize =
+            match (0..rows.len()).find(|row| !is_pivot[*row] && rows[*row][word] & bit != 0) {
+                Some(pivot) => pivot,
+                None => continue,
+            };
+        is_pivot[pivot] = true;
+        let pivot_row: Vec<u64> = rows[pivot].clone();
+        for (index, row) in rows.iter_mut().enumerate() {
+            if index != pivot && row[word] & bit != 0 {
+                for (target, source) in row.iter_mut().zip(pivot_row.iter()).skip(word) {
+                    *target ^= *source;
+                }
+            }
+        }
+    }
+
+    let mut dependencies: Vec<Vec<usize>> = Vec::new();
+    for (index, row) in rows.iter().enumerate() {
+        if is_pivot[index] {
+            continue;
+        }
+        let mut dependency: Vec<usize> = Vec::new();
+        for (row_index, relation_index) in kept.iter().enumerate() {
+            if row[column_words + row_index / 64] >> (row_index % 64) & 1 == 1 {
+                dependency.push(*relation_index);
+            }
+        }
+        dependencies.push(dependency);
+    }
+    return dependencies;
+}
+
+fn fixed_log2(num: u128) -> u32 {
+    // `log2(num)` in `1 / 1024` bits, interpolating linearly between powers of two.
+    if num == 0 {
+        return 0;
+    }
+    let bits: u32 = 127 - num.leading_zeros();
+    let fraction: u128 = if bits >= 10 {
+        (num >> (bits - 10)) - 1_024
+    } else {
+        (num << (10 - bits)) - 1_024
+    };
+    return bits * 1_024 + fraction as u32;
+}
+
+fn log2_scaled(num: u128) -> u32 {
+    // `log2(num)` in `1 / LOG_SCALE` bits, rounded.
+    return (fixed_log2(num) * LOG_SCALE + 512) / 1_024;
+}
+
+fn log2_scaled_big(num: &BigUint) -> u32 {
+    // `log2_scaled` for a `BigUint`, from its top `128` bits.
+    let shift: u64 = num.bits().saturating_sub(128);
+    let top: u128 = num.shr(shift).to_u128().unwrap();
+    return ((fixed_log2(top) + shift as u32 * 1_024) * LOG_SCALE + 512) / 1_024;
+}
+
+fn isqrt_big(num: &BigUint) -> u128 {
+    // `floor(sqrt(num))` for `num` below `2^256`, from its top `126` bits: exact while those are all of it, and off by
+    // less than one part in `2^62` otherwise, which is plenty for choosing `A`.
+    let shift: u64 = (num.bits().saturating_sub(126) + 1) / 2 * 2;
+    let top: u128 = num.shr(shift).to_u128().unwrap();
+    return primality::isqrt_u128(top) << (shift / 2);
+}
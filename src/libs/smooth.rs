@@ -0,0 +1,188 @@
+//! Smooth numbers: numbers whose prime factors are all at most some bound `B`.
+//!
+//! Smoothness is checked by trial division by the known primes, falling back to the factorizer for whatever is left.
+//! The `B`-smooth numbers till a limit are enumerated in increasing order by merging the multiples of each prime
+//! through a heap, and their count `Psi(x, y)` is estimated with the Dickman rho function.
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use crate::libs::constants;
+use crate::libs::factorization;
+use crate::libs::primality;
+use crate::libs::primes;
+
+/// Steps per unit of `u` when integrating the Dickman rho function.
+#[cfg(feature = "std")]
+const RHO_STEPS_PER_UNIT: usize = 4_096;
+
+pub fn find_smallest_prime_factor(num: u64) -> Option<u64> {
+    //! Finds the smallest prime factor of a given number, or `None` for `0` and `1`.
+    if num < 2 {
+        return None;
+    }
+    for prime in constants::KNOWN_PRIMES {
+        if num % prime == 0 {
+            return Some(prime);
+        }
+    }
+    if primality::check_if_prime_mr(num) {
+        return Some(num);
+    }
+    return factorization::factorize_u128(num as u128)
+        .first()
+        .map(|(prime, _)| *prime as u64);
+}
+
+pub fn find_largest_prime_factor(num: u64) -> Option<u64> {
+    //! Finds the largest prime factor of a given number, or `None` for `0` and `1`.
+    return factorization::factorize_u128(num as u128)
+        .last()
+        .map(|(prime, _)| *prime as u64);
+}
+
+pub fn check_if_b_smooth(num: u64, bound: u64) -> bool {
+    //! Checks whether every prime factor of a given number is at most `bound`; `1` is smooth for every bound, and
+    //! `0` for none.
+    //!
+    //! Divides out the known primes first; once all of them are at most `bound`, a cofactor that is itself at most
+    //! `bound` is smooth without being factorized.
+    if num == 0 {
+        return false;
+    }
+    let mut rest: u64 = num;
+    for prime in constants::KNOWN_PRIMES {
+        if prime > bound {
+            return rest == 1;
+        }
+        while rest % prime == 0 {
+            rest = rest / prime;
+        }
+    }
+    if rest <= bound {
+        return true;
+    }
+    return match find_largest_prime_factor(rest) {
+        Some(prime) => prime <= bound,
+        None => true,
+    };
+}
+
+/// Iterator over the `B`-smooth numbers till a limit, in increasing order, starting from `1`.
+///
+/// Every smooth number `n > 1` with largest prime factor `p_i` is generated exactly once: from `n / p_i` when that
+/// also has `p_i` as its largest prime factor, and otherwise from `n / p_i * p_(i-1)`. A min-heap of
+/// `(number, index of its largest prime)` pairs merges these two kinds of step, holding `O(Psi(limit, B))` entries
+/// at worst and usually far fewer.
+#[derive(Debug, Clone)]
+pub struct SmoothNumbers {
+    primes: Vec<u64>,
+    limit: u64,
+    heap: BinaryHeap<Reverse<(u64, usize)>>,
+    started: bool,
+}
+
+impl SmoothNumbers {
+    pub fn new(bound: u64, limit: u64) -> Self {
+        //! Creates an iterator over the numbers from `1` till `limit` whose prime factors are all at most `bound`.
+        //!
+        //! Keeps a list of the primes till `min(bound, limit)`.
+        let primes: Vec<u64> = primes::find_primes_till(core::cmp::min(bound, limit));
+        let mut heap: BinaryHeap<Reverse<(u64, usize)>> = BinaryHeap::new();
+        if let Some(first) = primes.first() {
+            heap.push(Reverse((*first, 0)));
+        }
+        return SmoothNumbers {
+            primes,
+            limit,
+            heap,
+            started: limit == 0,
+        };
+    }
+
+    fn push(&mut self, num: Option<u64>, index: usize) {
+        // Queues a number whose largest prime factor is `primes[index]`, unless it is past the limit.
+        if let Some(num) = num {
+            if num <= self.limit {
+                self.heap.push(Reverse((num, index)));
+            }
+        }
+    }
+}
+
+impl Iterator for SmoothNumbers {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if !self.started {
+            self.started = true;
+            return Some(1);
+        }
+
+        let Reverse((num, index)) = self.heap.pop()?;
+        let prime: u64 = self.primes[index];
+        self.push(num.checked_mul(prime), index);
+        if index + 1 < self.primes.len() {
+            self.push((num / prime).checked_mul(self.primes[index + 1]), index + 1);
+        }
+        return Some(num);
+    }
+}
+
+#[cfg(feature = "std")]
+pub fn dickman_rho(u: f64) -> f64 {
+    //! Evaluates the Dickman rho function, the limit of `Psi(x, x^(1/u)) / x` as `x` grows.
+    //!
+    //! `rho(u) = 1` for `u <= 1` and `1 - ln(u)` for `u <= 2`; beyond that, the delay equation
+    //! `u rho'(u) = -rho(u - 1)` is integrated with Simpson's rule on a grid of `RHO_STEPS_PER_UNIT` points per unit,
+    //! and interpolated linearly between grid points.
+    if u <= 1.0 {
+        return 1.0;
+    }
+    if u <= 2.0 {
+        return 1.0 - u.ln();
+    }
+    let step: f64 = 1.0 / RHO_STEPS_PER_UNIT as f64;
+    let last: usize = (u * RHO_STEPS_PER_UNIT as f64).ceil() as usize;
+
+    // `values[i] = rho(i * step)`, and `slope(values, i) = -rho'(i * step)`.
+    let mut values: Vec<f64> = Vec::with_capacity(last + 1);
+    for i in 0..=2 * RHO_STEPS_PER_UNIT {
+        let t: f64 = i as f64 * step;
+        values.push(if t <= 1.0 { 1.0 } else { 1.0 - t.ln() });
+    }
+    let slope = |values: &[f64], i: usize| -> f64 {
+        return values[i - RHO_STEPS_PER_UNIT] / (i as f64 * step);
+    };
+    for i in 2 * RHO_STEPS_PER_UNIT + 1..=last {
+        let change: f64 =
+            step / 3.0 * (slope(&values, i - 2) + 4.0 * slope(&values, i - 1) + slope(&values, i));
+        values.push(values[i - 2] - change);
+    }
+
+    let position: f64 = u * RHO_STEPS_PER_UNIT as f64;
+    let below: usize = position.floor() as usize;
+    if below >= last {
+        return values[last];
+    }
+    let fraction: f64 = position - below as f64;
+    return values[below] * (1.0 - fraction) + values[below + 1] * fraction;
+}
+
+#[cfg(feature = "std")]
+pub fn estimate_smooth_count(limit: u64, bound: u64) -> f64 {
+    //! Estimates `Psi(x, y)`, the count of `y`-smooth numbers from `1` till `x`, as `x rho(ln(x) / ln(y))`.
+    //!
+    //! The estimate is asymptotic in `x` for fixed `u = ln(x) / ln(y)`; it is exact for `y >= x`.
+    if limit == 0 {
+        return 0.0;
+    }
+    if bound < 2 {
+        return 1.0;
+    }
+    if bound >= limit {
+        return limit as f64;
+    }
+    let u: f64 = (limit as f64).ln() / (bound as f64).ln();
+    return limit as f64 * dickman_rho(u);
+}
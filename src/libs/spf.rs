@@ -0,0 +1,102 @@
+//! A smallest-prime-factor table, for factorizing every number till a limit in bulk.
+//!
+//! The table is built once with a sieve of Eratosthenes; after that each factorization only follows the chain
+//! `n -> n / spf(n)`, which takes `O(log n)` steps.
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The smallest prime factor of every number till a limit.
+///
+/// Only odd numbers are stored (the smallest prime factor of an even number is `2`), as `u32`s: a composite `n`
+/// has its smallest prime factor below `sqrt(n) < 2^32`, and primes are stored as `0`. A table till `10^8` takes
+/// `200` MB.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpfTable {
+    limit: u64,
+    odd_factors: Vec<u32>,
+}
+
+impl SpfTable {
+    pub fn new(limit: u64) -> Self {
+        //! Builds the table for the numbers from `0` till `limit`, inclusive.
+        let mut odd_factors: Vec<u32> = vec![0; (limit / 2 + 1) as usize];
+        let mut prime: u64 = 3;
+        while prime * prime <= limit {
+            if odd_factors[(prime / 2) as usize] == 0 {
+                // Odd multiples from `p^2` on; smaller ones have a smaller prime factor.
+                let mut multiple: u64 = prime * prime;
+                while multiple <= limit {
+                    let index: usize = (multiple / 2) as usize;
+                    if odd_factors[index] == 0 {
+                        odd_factors[index] = prime as u32;
+                    }
+                    multiple = multiple + 2 * prime;
+                }
+            }
+            prime = prime + 2;
+        }
+        return SpfTable { limit, odd_factors };
+    }
+
+    pub fn limit(&self) -> u64 {
+        //! The largest number in the table.
+        return self.limit;
+    }
+
+    pub fn smallest_prime_factor(&self, num: u64) -> Option<u64> {
+        //! Looks up the smallest prime factor of a number, or `None` for `0`, `1` and numbers past the limit.
+        if num < 2 || num > self.limit {
+            return None;
+        }
+        if num % 2 == 0 {
+            return Some(2);
+        }
+        return match self.odd_factors[(num / 2) as usize] {
+            0 => Some(num),
+            factor => Some(factor as u64),
+        };
+    }
+
+    pub fn is_prime(&self, num: u64) -> bool {
+        //! Checks whether a number in the table is prime.
+        return self.smallest_prime_factor(num) == Some(num);
+    }
+
+    pub fn factorize(&self, num: u64) -> Vec<(u64, u32)> {
+        //! Finds the prime factorization of a number in the table, as `(prime, exponent)` pairs in increasing order,
+        //! in `O(log n)` steps.
+        //!
+        //! `0` and `1` have no prime factors, so the list is empty for them. Panics if the number is past the limit.
+        if num > self.limit {
+            panic!(
+                "`{num}` is past the limit of the table, `{limit}`.",
+                num = num,
+                limit = self.limit
+            );
+        }
+        let mut factors: Vec<(u64, u32)> = Vec::new();
+        let mut rest: u64 = num;
+        while let Some(prime) = self.smallest_prime_factor(rest) {
+            match factors.last_mut() {
+                Some((last, exponent)) if *last == prime => *exponent = *exponent + 1,
+                _ => factors.push((prime, 1)),
+            }
+            rest = rest / prime;
+        }
+        return factors;
+    }
+
+    pub fn count_divisors(&self, num: u64) -> u64 {
+        //! Counts the divisors of a number in the table, `1` and itself included, from its factorization.
+        //!
+        //! Panics for `0`, which has infinitely many, and for numbers past the limit.
+        if num == 0 {
+            panic!("0 has infinite factors...");
+        }
+        return self
+            .factorize(num)
+            .iter()
+            .map(|(_, exponent)| *exponent as u64 + 1)
+            .product();
+    }
+}
@@ -0,0 +1,68 @@
+//! Compile-time generators for lookup tables.
+//!
+//! Everything here is a `const fn`, so the tables can be built into constants and checked with static assertions.
+use crate::libs::primality;
+
+pub const fn is_prime_const(num: u64) -> bool {
+    //! Checks to see if a given number is a prime number; usable in constants and static assertions.
+    return primality::check_if_prime_mr(num);
+}
+
+pub const fn prime_table<const N: usize>() -> [u64; N] {
+    //! Generates a table of the first `N` prime numbers.
+    let mut table: [u64; N] = [0; N];
+    let mut candidate: u64 = 2;
+    let mut i: usize = 0;
+    while i < N {
+        if is_prime_const(candidate) {
+            table[i] = candidate;
+            i = i + 1;
+        }
+        candidate = candidate + 1;
+    }
+    return table;
+}
+
+pub const fn count_factors_const(num: u64) -> u64 {
+    //! Counts the factors of a given number from its prime factorization, found by trial division.
+    if num == 0 {
+        panic!("0 has infinite factors...");
+    }
+
+    let mut n_factors: u64 = 1;
+    let mut rest: u64 = num;
+    let mut divisor: u64 = 2;
+    while divisor * divisor <= rest {
+        let mut exponent: u64 = 0;
+        while rest % divisor == 0 {
+            rest = rest / divisor;
+            exponent = exponent + 1;
+        }
+        n_factors = n_factors * (exponent + 1);
+        divisor = divisor + 1;
+    }
+    if rest > 1 {
+        n_factors = n_factors * 2;
+    }
+    return n_factors;
+}
+
+pub const fn hcn_table<const N: usize>() -> [u64; N] {
+    //! Generates a table of the first `N` highly-composite (anti-prime) numbers.
+    //!
+    //! Scans every number in turn, so this is only practical for the first few dozen.
+    let mut table: [u64; N] = [0; N];
+    let mut record: u64 = 0; // Highest number of factors seen so far.
+    let mut candidate: u64 = 1;
+    let mut i: usize = 0;
+    while i < N {
+        let n_factors: u64 = count_factors_const(candidate);
+        if n_factors > record {
+            record = n_factors;
+            table[i] = candidate;
+            i = i + 1;
+        }
+        candidate = candidate + 1;
+    }
+    return table;
+}
@@ -1,10 +1,9 @@
 //! General purpose utility functions.
 
 #![allow(warnings)]
+use alloc::vec::Vec;
 
-use std::process::{Command, Stdio};
-
-pub fn unique_elements_vector<T: std::fmt::Debug + std::cmp::PartialEq>(_list: Vec<T>) -> Vec<T> {
+pub fn unique_elements_vector<T: core::fmt::Debug + core::cmp::PartialEq>(_list: Vec<T>) -> Vec<T> {
     //! Find all the UNIQUE elements in a given vector of Datatype T;
     //!
     //! _where T has the following attributes:_
@@ -31,6 +30,7 @@ pub fn unique_elements_vector<T: std::fmt::Debug + std::cmp::PartialEq>(_list: V
     return unique_list;
 }
 
+#[cfg(feature = "std")]
 pub fn clear_console() {
     //! Clear the console before a new line is printed.
     println!("{}[2J", 27 as char);
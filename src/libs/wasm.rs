@@ -0,0 +1,85 @@
+//! JavaScript bindings for the `wasm32-unknown-unknown` target.
+//!
+//! Enabled by the `wasm` feature. Every binding is a thin wrapper around a plain-Rust helper in this module
+//! (the `*_vec` functions), so the logic can be tested natively while the wrappers only convert factor and
+//! prime lists into typed arrays:
+//!
+//! 1. `u64` inputs and lists map to `bigint` and `BigUint64Array`.
+//! 2. `u32` inputs and lists map to `number` and `Uint32Array`.
+//!
+//! Build with `cargo rustc --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib` and
+//! run the bindings' tests headlessly with `WASM_BINDGEN_USE_DENO=1 cargo test --target wasm32-unknown-unknown --features wasm`.
+use js_sys::{BigUint64Array, Uint32Array};
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::libs::constants;
+use crate::libs::prime_int::PrimeInt;
+use crate::libs::primes;
+
+pub fn factors_vec(num: u64) -> Vec<u64> {
+    //! Lists the factors of a given number.
+    return num.divisors();
+}
+
+pub fn factors_u32_vec(num: u32) -> Vec<u32> {
+    //! Lists the factors of a given 32-bit number.
+    return num.divisors();
+}
+
+pub fn primes_till_u32_vec(num: u32) -> Vec<u32> {
+    //! Lists all the prime numbers till a given 32-bit number.
+    let prime_numbers: Vec<u32> = primes::find_primes_till(num as u64)
+        .into_iter()
+        .map(|prime| prime as u32)
+        .collect();
+    return prime_numbers;
+}
+
+/// Returns a brief description of the library.
+#[wasm_bindgen(js_name = description)]
+pub fn description() -> String {
+    return String::from(constants::DESCRIPTION);
+}
+
+/// Checks to see if a given number is a prime number.
+#[wasm_bindgen(js_name = isPrime)]
+pub fn is_prime(num: u64) -> bool {
+    return num.is_prime();
+}
+
+/// Checks to see if a given number is a highly-composite (anti-prime) number.
+#[wasm_bindgen(js_name = isHcn)]
+pub fn is_hcn(num: u64) -> bool {
+    let (check, _) = primes::check_if_anti_prime(num);
+    return check;
+}
+
+/// Lists the factors of a given number as a `BigUint64Array`.
+#[wasm_bindgen(js_name = factors)]
+pub fn factors(num: u64) -> BigUint64Array {
+    return BigUint64Array::from(&factors_vec(num)[..]);
+}
+
+/// Lists the factors of a given 32-bit number as a `Uint32Array`.
+#[wasm_bindgen(js_name = factorsU32)]
+pub fn factors_u32(num: u32) -> Uint32Array {
+    return Uint32Array::from(&factors_u32_vec(num)[..]);
+}
+
+/// Lists all the prime numbers till a given number as a `BigUint64Array`.
+#[wasm_bindgen(js_name = getPrimes)]
+pub fn get_primes(num: u64) -> BigUint64Array {
+    return BigUint64Array::from(&primes::find_primes_till(num)[..]);
+}
+
+/// Lists all the prime numbers till a given 32-bit number as a `Uint32Array`.
+#[wasm_bindgen(js_name = getPrimesU32)]
+pub fn get_primes_u32(num: u32) -> Uint32Array {
+    return Uint32Array::from(&primes_till_u32_vec(num)[..]);
+}
+
+/// Lists all the highly composite numbers till a given number as a `BigUint64Array`.
+#[wasm_bindgen(js_name = getHcn)]
+pub fn get_hcn(num: u64) -> BigUint64Array {
+    return BigUint64Array::from(&primes::find_anti_primes_till(num)[..]);
+}
@@ -1,4 +1,57 @@
 #[cfg(test)]
+#[cfg(feature = "alloc")]
+pub mod test_arithmetic;
+#[cfg(feature = "alloc")]
+pub mod test_bigint;
+#[cfg(feature = "bignum")]
+pub mod test_bignum;
+#[cfg(feature = "alloc")]
+pub mod test_certificate;
+#[cfg(feature = "alloc")]
+pub mod test_constellations;
+#[cfg(feature = "alloc")]
+pub mod test_cunningham;
+#[cfg(feature = "alloc")]
+pub mod test_ecm;
+#[cfg(feature = "alloc")]
+pub mod test_factor64;
+#[cfg(feature = "std")]
+pub mod test_gaps;
+#[cfg(feature = "alloc")]
+pub mod test_goldbach;
+#[cfg(feature = "std")]
 pub mod test_lib;
+#[cfg(feature = "alloc")]
+pub mod test_mersenne;
+#[cfg(feature = "alloc")]
+pub mod test_modular;
+#[cfg(feature = "alloc")]
+pub mod test_multiplicative;
+#[cfg(feature = "alloc")]
+pub mod test_perfect;
+#[cfg(feature = "alloc")]
+pub mod test_powers;
+pub mod test_primality;
+#[cfg(feature = "std")]
+pub mod test_prime_int;
+#[cfg(feature = "alloc")]
 pub mod test_primes;
+#[cfg(feature = "alloc")]
+pub mod test_quadratic;
+#[cfg(feature = "alloc")]
+pub mod test_random;
+#[cfg(feature = "std")]
+pub mod test_records;
+#[cfg(feature = "alloc")]
+pub mod test_sieve;
+#[cfg(feature = "alloc")]
+pub mod test_siqs;
+#[cfg(feature = "alloc")]
+pub mod test_smooth;
+#[cfg(feature = "alloc")]
+pub mod test_spf;
+pub mod test_tables;
+#[cfg(feature = "alloc")]
 pub mod test_utils;
+#[cfg(feature = "wasm")]
+pub mod test_wasm;
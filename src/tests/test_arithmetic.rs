@@ -0,0 +1,118 @@
+//! Sub-module to test out the arithmetic functions.
+use crate::libs::arithmetic;
+
+#[test]
+pub fn test_divisor_count() {
+    //! Tests the divisor_count function.
+    assert_eq!(arithmetic::divisor_count(0), 0);
+    assert_eq!(arithmetic::divisor_count(1), 1);
+    assert_eq!(arithmetic::divisor_count(10_080), 72);
+    assert_eq!(arithmetic::divisor_count(18_446_744_073_709_551_557), 2);
+}
+
+#[test]
+pub fn test_divisor_sum() {
+    //! Tests the divisor_sum function with perfect numbers.
+    assert_eq!(arithmetic::divisor_sum(28), 56);
+    assert_eq!(arithmetic::divisor_sum(8_128), 16_256);
+    assert_eq!(arithmetic::divisor_sum(1), 1);
+}
+
+#[test]
+pub fn test_sigma_k() {
+    //! Tests the sigma_k function, including overflow.
+    assert_eq!(arithmetic::sigma_k(12, 0), Some(6));
+    assert_eq!(arithmetic::sigma_k(12, 3), Some(2_044));
+    assert_eq!(
+        arithmetic::sigma_k(1 << 40, 3),
+        Some(1_519_117_709_468_475_283_318_636_640_320_393_801)
+    );
+    assert_eq!(arithmetic::sigma_k(1 << 43, 3), None);
+}
+
+#[test]
+pub fn test_omega() {
+    //! Tests the omega and big_omega functions.
+    assert_eq!(arithmetic::omega(1), 0);
+    assert_eq!(arithmetic::omega(2_520), 4);
+    assert_eq!(arithmetic::big_omega(2_520), 7);
+    assert_eq!(arithmetic::big_omega(1 << 63), 63);
+}
+
+#[test]
+pub fn test_sieves() {
+    //! Tests every sieve against the single-value functions.
+    let limit: u64 = 1_000;
+    let counts = arithmetic::divisor_count_sieve(limit);
+    let sums = arithmetic::divisor_sum_sieve(limit);
+    let squares = arithmetic::sigma_k_sieve(limit, 2).unwrap();
+    let omegas = arithmetic::omega_sieve(limit);
+    let big_omegas = arithmetic::big_omega_sieve(limit);
+    for num in 0..=limit {
+        let i = num as usize;
+        assert_eq!(counts[i], arithmetic::divisor_count(num));
+        assert_eq!(sums[i], arithmetic::divisor_sum(num));
+        assert_eq!(Some(squares[i]), arithmetic::sigma_k(num, 2));
+        assert_eq!(omegas[i], arithmetic::omega(num));
+        assert_eq!(big_omegas[i], arithmetic::big_omega(num));
+    }
+}
+
+#[test]
+pub fn test_totient() {
+    //! Tests the totient function.
+    assert_eq!(arithmetic::totient(0), 0);
+    assert_eq!(arithmetic::totient(1), 1);
+    assert_eq!(arithmetic::totient(36), 12);
+    assert_eq!(arithmetic::totient(1_000_000_007), 1_000_000_006);
+}
+
+#[test]
+pub fn test_carmichael() {
+    //! Tests the carmichael function, including powers of 2.
+    assert_eq!(arithmetic::carmichael(1), 1);
+    assert_eq!(arithmetic::carmichael(8), 2);
+    assert_eq!(arithmetic::carmichael(15), 4);
+    assert_eq!(arithmetic::carmichael(561), 80); // Carmichael number: lambda(n) divides n - 1.
+    assert_eq!(arithmetic::carmichael(5_040), 12);
+}
+
+#[test]
+pub fn test_mobius() {
+    //! Tests the mobius function.
+    assert_eq!(arithmetic::mobius(1), 1);
+    assert_eq!(arithmetic::mobius(30), -1);
+    assert_eq!(arithmetic::mobius(35), 1);
+    assert_eq!(arithmetic::mobius(12), 0);
+}
+
+#[test]
+pub fn test_multiplicative_sieves() {
+    //! Tests the linear sieves against the single-value functions.
+    let limit: u64 = 2_000;
+    let totients = arithmetic::totient_sieve(limit);
+    let lambdas = arithmetic::carmichael_sieve(limit);
+    let mus = arithmetic::mobius_sieve(limit);
+    for num in 0..=limit {
+        let i = num as usize;
+        assert_eq!(totients[i], arithmetic::totient(num));
+        assert_eq!(lambdas[i], arithmetic::carmichael(num));
+        assert_eq!(mus[i], arithmetic::mobius(num));
+    }
+}
+
+#[test]
+pub fn test_totient_summatory() {
+    //! Tests the totient_summatory function against a direct sum and known values (OEIS A002088, A064018).
+    let totients = arithmetic::totient_sieve(5_000);
+    let mut sum: u128 = 0;
+    for num in 0..=5_000_u64 {
+        sum = sum + totients[num as usize] as u128;
+        assert_eq!(arithmetic::totient_summatory(num), sum);
+    }
+    assert_eq!(arithmetic::totient_summatory(1_000_000), 303_963_552_392);
+    assert_eq!(
+        arithmetic::totient_summatory(1_000_000_000),
+        303_963_551_173_008_414
+    );
+}
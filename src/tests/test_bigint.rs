@@ -0,0 +1,158 @@
+//! Sub-module to test out the arbitrary-precision integers.
+use crate::libs::bigint::{BigUint, ParseBigUintError};
+use crate::libs::random::{RandomSource, SeededRng};
+
+#[test]
+pub fn test_small_values() {
+    //! Tests the arithmetic against `u128`.
+    let a: BigUint = BigUint::from_u128(0xFFFF_FFFF_FFFF_FFFF_1234);
+    let b: BigUint = BigUint::from_u128(0xFEDC_BA98_7654_3210);
+    assert_eq!(
+        a.add(&b).to_u128(),
+        Some(0xFFFF_FFFF_FFFF_FFFF_1234 + 0xFEDC_BA98_7654_3210)
+    );
+    assert_eq!(
+        a.checked_sub(&b).unwrap().to_u128(),
+        Some(0xFFFF_FFFF_FFFF_FFFF_1234 - 0xFEDC_BA98_7654_3210)
+    );
+    assert_eq!(b.checked_sub(&a), None);
+    assert_eq!(
+        b.square().to_u128(),
+        Some(0xFEDC_BA98_7654_3210 * 0xFEDC_BA98_7654_3210)
+    );
+    assert_eq!(a.shl(10).shr(10), a);
+    assert_eq!(a.shr(70).to_u128(), Some(0xFFFF_FFFF_FFFF_FFFF_1234 >> 70));
+    assert_eq!(a.low_bits(12).to_u128(), Some(0x234));
+    assert_eq!(a.bits(), 80);
+    assert_eq!(BigUint::zero().bits(), 0);
+    assert_eq!(BigUint::from_u128(0).is_zero(), true);
+}
+
+#[test]
+pub fn test_karatsuba() {
+    //! Tests long products, above the Karatsuba threshold, against `(2^n - 1)^2 = 2^2n - 2^(n+1) + 1`.
+    for bits in [64_u32, 1000, 2047, 4423, 10_000] {
+        let num: BigUint = BigUint::power_of_two_minus_one(bits);
+        let one: BigUint = BigUint::from_u128(1);
+        let expected: BigUint = one
+            .shl(2 * bits as u64)
+            .add(&one)
+            .checked_sub(&one.shl(bits as u64 + 1))
+            .unwrap();
+        assert_eq!(num.square(), expected);
+        assert_eq!(num.mul(&num), expected);
+    }
+
+    // Uneven lengths, with every limb different.
+    let a: BigUint = BigUint::from_limbs(
+        (1..=90_u64)
+            .map(|x| x.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+            .collect(),
+    );
+    let b: BigUint = BigUint::from_limbs(
+        (1..=40_u64)
+            .map(|x| x.wrapping_mul(0xD1B5_4A32_D192_ED03))
+            .collect(),
+    );
+    let product: BigUint = a.mul(&b);
+    let mut expected: BigUint = BigUint::zero();
+    for (index, limb) in b.limbs().iter().enumerate() {
+        expected = expected.add(
+            &a.mul(&BigUint::from_u128(*limb as u128))
+                .shl(64 * index as u64),
+        );
+    }
+    assert_eq!(product, expected);
+    assert_eq!(a.square(), a.mul(&a));
+}
+
+#[test]
+pub fn test_division() {
+    //! Tests that `div_rem` gives `a = q * b + r` with `r < b`, on limbs biased towards `0`, `1` and `2^64 - 1`,
+    //! which is where the quotient estimate needs correcting.
+    assert_eq!(
+        BigUint::from_u128(u128::MAX)
+            .div_rem(&BigUint::from_u128(0x1_0000_0000_0000_0001))
+            .0
+            .to_u128(),
+        Some(u128::MAX / 0x1_0000_0000_0000_0001)
+    );
+    assert_eq!(
+        BigUint::from_u128(5).div_rem(&BigUint::from_u128(7)),
+        (BigUint::zero(), BigUint::from_u128(5))
+    );
+
+    let special: [u64; 5] = [0, 1, u64::MAX, u64::MAX - 1, 1 << 63];
+    let mut rng: SeededRng = SeededRng::new(50);
+    let random_number = |limbs: u64, rng: &mut SeededRng| -> BigUint {
+        let limbs: Vec<u64> = (0..limbs)
+            .map(|_| match rng.gen_range(0, 9) {
+                index if index < 5 => special[index as usize],
+                _ => rng.next_u64(),
+            })
+            .collect();
+        return BigUint::from_limbs(limbs);
+    };
+    for _ in 0..2_000 {
+        let a_limbs: u64 = rng.gen_range(1, 12);
+        let b_limbs: u64 = rng.gen_range(1, 8);
+        let a: BigUint = random_number(a_limbs, &mut rng);
+        let b: BigUint = random_number(b_limbs, &mut rng);
+        if b.is_zero() {
+            continue;
+        }
+        let (quotient, remainder): (BigUint, BigUint) = a.div_rem(&b);
+        assert!(remainder < b);
+        assert_eq!(quotient.mul(&b).add(&remainder), a);
+        if b.limbs().len() == 1 {
+            assert_eq!(a.rem_u64(b.limbs()[0]), remainder.to_u128().unwrap() as u64);
+        }
+    }
+}
+
+#[test]
+pub fn test_gcd_and_pow() {
+    //! Tests `gcd`, `pow` and the bit helpers.
+    let a: BigUint = BigUint::from_u128(3).pow(100);
+    let b: BigUint = BigUint::from_u128(6).pow(40);
+    assert_eq!(a.gcd(&b), BigUint::from_u128(3).pow(40));
+    assert_eq!(a.gcd(&BigUint::zero()), a);
+    assert_eq!(BigUint::from_u128(7).pow(0), BigUint::from_u128(1));
+    assert_eq!(
+        BigUint::from_u128(10).pow(30).to_u128(),
+        Some(10_u128.pow(30))
+    );
+
+    assert_eq!(b.trailing_zeros(), 40);
+    assert_eq!(BigUint::from_u128(1).shl(200).trailing_zeros(), 200);
+    assert!(BigUint::from_u128(1).shl(200).bit(200));
+    assert!(!BigUint::from_u128(1).shl(200).bit(199));
+    assert!(a.is_odd() && !b.is_odd() && !BigUint::zero().is_odd());
+}
+
+#[test]
+pub fn test_decimal_strings() {
+    //! Tests that `Display` and `FromStr` round-trip, and agree with `u128`.
+    for num in [
+        0_u128,
+        9,
+        10,
+        1_000_000_007,
+        u64::MAX as u128 + 1,
+        u128::MAX,
+    ] {
+        let big: BigUint = BigUint::from(num);
+        assert_eq!(big.to_string(), num.to_string());
+        assert_eq!(num.to_string().parse::<BigUint>(), Ok(big));
+    }
+
+    let text: &str = "1000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000";
+    let num: BigUint = text.parse().unwrap();
+    assert_eq!(num, BigUint::from_u128(10).pow(96));
+    assert_eq!(num.to_string(), text);
+    assert_eq!(format!("{:>100}", num), format!("{:>100}", text));
+    assert_eq!("1_000".parse::<BigUint>(), Ok(BigUint::from(1_000)));
+    assert_eq!("".parse::<BigUint>(), Err(ParseBigUintError));
+    assert_eq!("12a".parse::<BigUint>(), Err(ParseBigUintError));
+    assert_eq!("-1".parse::<BigUint>(), Err(ParseBigUintError));
+}
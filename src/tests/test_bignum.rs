@@ -0,0 +1,299 @@
+//! Sub-module to test out the primality tests, prime search and factorization for arbitrary-precision integers.
+use crate::libs::bigint::BigUint;
+use crate::libs::bignum::{self, Primality};
+use crate::libs::primality;
+use crate::libs::random::SeededRng;
+use crate::libs::siqs;
+
+fn big(text: &str) -> BigUint {
+    return text.parse().unwrap();
+}
+
+fn mersenne(exponent: u32) -> BigUint {
+    return BigUint::power_of_two_minus_one(exponent);
+}
+
+#[test]
+pub fn test_is_prime_big_small() {
+    //! Tests that numbers that fit in a `u128` get the same answer as `check_if_prime_u128`.
+    for num in (0_u128..2_000).chain(u64::MAX as u128 - 500..u64::MAX as u128 + 500) {
+        assert_eq!(
+            bignum::check_if_prime_big(&BigUint::from(num)),
+            primality::check_if_prime_u128(num),
+            "{num}"
+        );
+    }
+    assert!(bignum::check_if_prime_big(&mersenne(127)));
+}
+
+#[test]
+pub fn test_is_prime_big() {
+    //! Tests Mersenne numbers, the primes just above `10^40`, and composites built to fool weaker tests.
+    for exponent in [521_u32, 607, 1_279] {
+        assert!(
+            bignum::check_if_prime_big(&mersenne(exponent)),
+            "{exponent}"
+        );
+    }
+    for exponent in [131_u32, 523, 1_277] {
+        assert!(
+            !bignum::check_if_prime_big(&mersenne(exponent)),
+            "{exponent}"
+        );
+    }
+
+    // The only primes from 10^40 till 10^40 + 300.
+    let base: BigUint = BigUint::from_u128(10).pow(40);
+    let primes: Vec<u64> = (0..300)
+        .filter(|k| bignum::check_if_prime_big(&base.add(&BigUint::from(*k as u128))))
+        .collect();
+    assert_eq!(primes, vec![121, 139]);
+
+    // A Carmichael number `(6k + 1)(12k + 1)(18k + 1)` with 48-bit factors, a Fermat pseudoprime to base 2.
+    let carmichael: BigUint = big("56448761311567219124164122639904415079073681");
+    assert!(!bignum::check_if_prime_big(&carmichael));
+    // The square of a prime, which has no Selfridge parameter for the Lucas test.
+    assert!(!bignum::check_if_prime_big(&mersenne(89).square()));
+    assert!(!bignum::check_if_prime_big(
+        &mersenne(127).mul(&mersenne(61))
+    ));
+}
+
+#[test]
+pub fn test_is_prime_big_rounds() {
+    //! Tests that the extra Miller-Rabin rounds accept primes and reject composites.
+    let mut rng: SeededRng = SeededRng::new(50);
+    assert!(bignum::check_if_prime_big_rounds(
+        &mersenne(521),
+        10,
+        &mut rng
+    ));
+    assert!(bignum::check_if_prime_big_rounds(
+        &mersenne(127),
+        10,
+        &mut rng
+    ));
+    assert!(bignum::check_if_prime_big_rounds(
+        &BigUint::from(97),
+        10,
+        &mut rng
+    ));
+    assert!(!bignum::check_if_prime_big_rounds(
+        &mersenne(523),
+        10,
+        &mut rng
+    ));
+    assert!(!bignum::check_if_prime_big_rounds(
+        &BigUint::from(91),
+        10,
+        &mut rng
+    ));
+}
+
+#[test]
+pub fn test_next_prime_big() {
+    //! Tests the next prime after powers of ten and two, checked against SymPy's `nextprime`.
+    let cases: [(BigUint, u128); 5] = [
+        (BigUint::zero(), 2),
+        (BigUint::from(u128::MAX), 51),
+        (BigUint::from_u128(1).shl(128), 51),
+        (BigUint::from_u128(1).shl(200), 235),
+        (BigUint::from_u128(10).pow(299), 669),
+    ];
+    for (start, offset) in cases {
+        let base: BigUint = if start == BigUint::from(u128::MAX) {
+            BigUint::from_u128(1).shl(128)
+        } else {
+            start.clone()
+        };
+        assert_eq!(
+            bignum::find_next_prime_big(&start),
+            base.add(&BigUint::from(offset)),
+            "{start}"
+        );
+    }
+    assert_eq!(
+        bignum::find_next_prime_big(&BigUint::from(1_000_000)),
+        BigUint::from(1_000_003)
+    );
+}
+
+#[test]
+pub fn test_integer_nth_root_big() {
+    //! Tests that the root is exact for perfect powers and rounds down otherwise.
+    let three: BigUint = BigUint::from(3);
+    for k in 1..12_u32 {
+        let power: BigUint = mersenne(100).pow(k);
+        assert_eq!(bignum::integer_nth_root_big(&power, k), mersenne(100));
+        let below: BigUint = power.checked_sub(&BigUint::from(1)).unwrap();
+        assert_eq!(
+            bignum::integer_nth_root_big(&below, k),
+            if k == 1 {
+                below.clone()
+            } else {
+                mersenne(100).checked_sub(&BigUint::from(1)).unwrap()
+            }
+        );
+    }
+    assert_eq!(
+        bignum::integer_nth_root_big(&three.pow(300), 3),
+        three.pow(100)
+    );
+    assert_eq!(
+        bignum::integer_nth_root_big(&three.pow(300).add(&three), 3),
+        three.pow(100)
+    );
+    assert_eq!(
+        bignum::integer_nth_root_big(&BigUint::zero(), 5),
+        BigUint::zero()
+    );
+}
+
+#[test]
+pub fn test_factorize_big() {
+    //! Tests factorizations that need trial division, perfect powers, rho and ECM.
+    let one: BigUint = BigUint::from(1);
+
+    // 2^256 - 1 = 3 * 5 * 17 * 257 * 641 * 65537 * 274177 * 6700417 * 67280421310721 * 59649589127497217
+    //           * 5704689200685129054721
+    let (factors, cofactor) = bignum::factorize_big(&mersenne(256), bignum::DEFAULT_ECM_DIGITS);
+    let primes: Vec<String> = factors.iter().map(|(prime, _)| prime.to_string()).collect();
+    assert_eq!(
+        primes,
+        vec![
+            "3",
+            "5",
+            "17",
+            "257",
+            "641",
+            "65537",
+            "274177",
+            "6700417",
+            "67280421310721",
+            "59649589127497217",
+            "5704689200685129054721",
+        ]
+    );
+    assert!(factors.iter().all(|(_, exponent)| *exponent == 1));
+    assert_eq!(cofactor, one);
+
+    // A perfect power of a prime above 2^64, times small primes.
+    let p: BigUint = BigUint::from(18_446_744_073_709_551_629);
+    let num: BigUint = p.pow(5).mul(&BigUint::from(3_u128.pow(5) * 1_000_003));
+    let (factors, cofactor) = bignum::factorize_big(&num, bignum::DEFAULT_ECM_DIGITS);
+    assert_eq!(
+        factors,
+        vec![
+            (BigUint::from(3), 5),
+            (BigUint::from(1_000_003), 1),
+            (p.clone(), 5)
+        ]
+    );
+    assert_eq!(cofactor, one);
+
+    // A 40-bit prime, past the bounded rho run, leaves a perfect power of a prime above 2^100 behind.
+    let q: BigUint = big("1267650600228229401496703205653");
+    let num: BigUint = q.pow(4).mul(&BigUint::from(1_099_511_627_689));
+    let (factors, cofactor) = bignum::factorize_big(&num, bignum::DEFAULT_ECM_DIGITS);
+    assert_eq!(factors, vec![(BigUint::from(1_099_511_627_689), 1), (q, 4)]);
+    assert_eq!(cofactor, one);
+
+    // The Carmichael number has three 48-bit factors, past the bounded rho run.
+    let (factors, cofactor) = bignum::factorize_big(
+        &big("56448761311567219124164122639904415079073681"),
+        bignum::DEFAULT_ECM_DIGITS,
+    );
+    let k: u128 = 35_184_372_094_605;
+    assert_eq!(
+        factors,
+        vec![
+            (BigUint::from(6 * k + 1), 1),
+            (BigUint::from(12 * k + 1), 1),
+            (BigUint::from(18 * k + 1), 1),
+        ]
+    );
+    assert_eq!(cofactor, one);
+}
+
+#[test]
+pub fn test_factorize_big_siqs() {
+    //! Tests that products of two primes of the same size past `2^128`, out of ECM's reach, are split by SIQS.
+    let one: BigUint = BigUint::from(1);
+    for (p_start, q_start) in [(1_u128 << 66, 3_u128 << 65), (1_u128 << 69, 1_u128 << 70)] {
+        let p: BigUint = bignum::find_next_prime_big(&BigUint::from(p_start));
+        let q: BigUint = bignum::find_next_prime_big(&BigUint::from(q_start));
+        let num: BigUint = p.mul(&q);
+        assert!(num.bits() > 128 && num.bits() <= siqs::SIQS_MAXIMUM_BITS);
+        assert_eq!(
+            bignum::factorize_big(&num, bignum::DEFAULT_ECM_DIGITS),
+            (vec![(p.clone(), 1), (q.clone(), 1)], one.clone())
+        );
+    }
+
+    let past_limit: BigUint = BigUint::from_u128(1).shl(siqs::SIQS_MAXIMUM_BITS).add(&one);
+    assert_eq!(siqs::siqs_big(&past_limit), None);
+}
+
+#[test]
+pub fn test_factorize_big_cofactor() {
+    //! Tests that a product of two 100-bit primes, too large for ECM at 15 digits and for SIQS, comes back as the
+    //! cofactor.
+    let p: BigUint = big("1267650600228229401496703205653");
+    let q: BigUint = big("1267650600228229401496703205707");
+    let num: BigUint = p.mul(&q).mul(&BigUint::from(1_099_511_627_689));
+    let (factors, cofactor) = bignum::factorize_big(&num, 15);
+    assert_eq!(factors, vec![(BigUint::from(1_099_511_627_689), 1)]);
+    assert_eq!(cofactor, p.mul(&q));
+
+    assert_eq!(
+        bignum::factorize_big(&BigUint::zero(), 15),
+        (vec![], BigUint::from(1))
+    );
+    assert_eq!(
+        bignum::factorize_big(&BigUint::from(1), 15),
+        (vec![], BigUint::from(1))
+    );
+}
+
+#[test]
+pub fn test_primality_trait() {
+    //! Tests that the same generic code works on fixed-width integers and on `BigUint`.
+    fn primes_after<T: Primality + Clone>(start: T, count: usize) -> Vec<T> {
+        let mut primes: Vec<T> = Vec::new();
+        let mut current: T = start;
+        while primes.len() < count {
+            current = match current.next_prime() {
+                Some(prime) => prime,
+                None => break,
+            };
+            assert!(current.check_prime());
+            primes.push(current.clone());
+        }
+        return primes;
+    }
+
+    assert_eq!(primes_after(240_u8, 5), vec![241, 251]);
+    assert_eq!(primes_after(1_000_u64, 3), vec![1_009, 1_013, 1_019]);
+    assert_eq!(
+        primes_after(BigUint::from(1_000), 3),
+        vec![
+            BigUint::from(1_009),
+            BigUint::from(1_013),
+            BigUint::from(1_019)
+        ]
+    );
+    assert_eq!(u128::MAX.next_prime(), None);
+
+    assert_eq!(360_u32.prime_factors(), (vec![(2, 3), (3, 2), (5, 1)], 1));
+    assert_eq!(
+        BigUint::from(360).prime_factors(),
+        (
+            vec![
+                (BigUint::from(2), 3),
+                (BigUint::from(3), 2),
+                (BigUint::from(5), 1)
+            ],
+            BigUint::from(1)
+        )
+    );
+}
@@ -0,0 +1,111 @@
+//! Sub-module to test out the primality certificates.
+use crate::libs::certificate::{self, Certificate};
+
+#[test]
+pub fn test_prove_prime() {
+    //! Tests that prove_prime builds certificates that verify, for primes of several sizes.
+    for num in [
+        2,
+        3,
+        65_521,
+        65_537,
+        1_000_000_007,
+        18_446_744_073_709_551_557,
+    ] {
+        let proof = certificate::prove_prime(num).unwrap();
+        assert_eq!(proof.prime(), num);
+        assert_eq!(certificate::verify_certificate(&proof), true);
+    }
+}
+
+#[test]
+pub fn test_prove_prime_composite() {
+    //! Tests that prove_prime refuses composites.
+    assert_eq!(certificate::prove_prime(0), None);
+    assert_eq!(certificate::prove_prime(1), None);
+    assert_eq!(certificate::prove_prime(3_215_031_751), None);
+}
+
+#[test]
+pub fn test_verify_certificate() {
+    //! Tests that verify_certificate rejects bad certificates.
+    let seven = Certificate::Pratt {
+        prime: 7,
+        witness: 3,
+        factors: vec![(2, 1, Certificate::Small(2)), (3, 1, Certificate::Small(3))],
+    };
+    assert_eq!(certificate::verify_certificate(&seven), true);
+
+    // 2 has order 3 modulo 7, so it is not a valid Pratt witness.
+    let bad_witness = Certificate::Pratt {
+        prime: 7,
+        witness: 2,
+        factors: vec![(2, 1, Certificate::Small(2)), (3, 1, Certificate::Small(3))],
+    };
+    assert_eq!(certificate::verify_certificate(&bad_witness), false);
+
+    // 9 is not prime.
+    assert_eq!(
+        certificate::verify_certificate(&Certificate::Small(9)),
+        false
+    );
+
+    // Pocklington needs the factored part to be above sqrt(n).
+    let too_little = Certificate::Pocklington {
+        prime: 1_000_000_007,
+        witness: 5,
+        factors: vec![(2, 1, Certificate::Small(2))],
+    };
+    assert_eq!(certificate::verify_certificate(&too_little), false);
+
+    // A sub-certificate for the wrong prime.
+    let mismatched = Certificate::Pratt {
+        prime: 7,
+        witness: 3,
+        factors: vec![(2, 1, Certificate::Small(2)), (3, 1, Certificate::Small(5))],
+    };
+    assert_eq!(certificate::verify_certificate(&mismatched), false);
+}
+
+#[test]
+pub fn test_text_format() {
+    //! Tests that certificates survive a round trip through the text format.
+    let proof = certificate::prove_prime(18_446_744_073_709_551_557).unwrap();
+    let text = proof.to_text();
+    assert_eq!(
+        text.lines().last().unwrap().split(' ').nth(1),
+        Some("18446744073709551557")
+    );
+    assert_eq!(Certificate::from_text(&text), Some(proof));
+
+    let seven = Certificate::from_text("small 2\nsmall 3\npratt 7 3 2^1 3^1").unwrap();
+    assert_eq!(seven.to_text(), "small 2\nsmall 3\npratt 7 3 2^1 3^1");
+    assert_eq!(certificate::verify_certificate(&seven), true);
+
+    // Refers to 3 before it is proved.
+    assert_eq!(Certificate::from_text("small 2\npratt 7 3 2^1 3^1"), None);
+    assert_eq!(Certificate::from_text("proof 7"), None);
+}
+
+#[test]
+pub fn test_pratt_certificate() {
+    //! Tests that pratt_certificate always gives a Pratt certificate with the smallest primitive root as witness.
+    for (num, root) in [
+        (7_u64, 3_u64),
+        (65_537, 3),
+        (1_000_000_007, 5),
+        (998_244_353, 3),
+    ] {
+        let proof = certificate::pratt_certificate(num).unwrap();
+        match &proof {
+            Certificate::Pratt { prime, witness, .. } => {
+                assert_eq!(*prime, num);
+                assert_eq!(*witness, root);
+            }
+            _ => panic!("Expected a Pratt certificate."),
+        }
+        assert_eq!(certificate::verify_certificate(&proof), true);
+    }
+    assert_eq!(certificate::pratt_certificate(2), None);
+    assert_eq!(certificate::pratt_certificate(561), None);
+}
@@ -0,0 +1,95 @@
+//! Sub-module to test out the prime constellations.
+use crate::libs::constellations::{self, PrimeTuples};
+use crate::libs::primality;
+
+#[test]
+pub fn test_check_if_admissible() {
+    //! Tests the check_if_admissible function.
+    assert_eq!(constellations::check_if_admissible(&[0]), true);
+    assert_eq!(constellations::check_if_admissible(&[0, 2]), true);
+    assert_eq!(constellations::check_if_admissible(&[0, 2, 6, 8]), true);
+    assert_eq!(
+        constellations::check_if_admissible(&[0, 4, 6, 10, 12, 16]),
+        true
+    );
+    assert_eq!(constellations::check_if_admissible(&[0, 1]), false);
+    assert_eq!(constellations::check_if_admissible(&[0, 2, 4]), false);
+    assert_eq!(constellations::check_if_admissible(&[0, 2, 6, 8, 12]), true);
+    assert_eq!(
+        constellations::check_if_admissible(&[0, 2, 6, 8, 12, 14]),
+        false
+    ); // Covers every class mod 5.
+    assert_eq!(constellations::check_if_admissible(&[2, 4]), false);
+    assert_eq!(constellations::check_if_admissible(&[0, 6, 2]), false);
+    assert_eq!(constellations::check_if_admissible(&[]), false);
+}
+
+#[test]
+pub fn test_prime_tuples() {
+    //! Tests the tuple iterators against Miller-Rabin on every candidate.
+    for pattern in [
+        [0_u64, 2].as_slice(),
+        &[0, 4],
+        &[0, 6],
+        &[0, 2, 6],
+        &[0, 4, 6],
+        &[0, 2, 6, 8],
+    ] {
+        for (low, high) in [
+            (0_u64, 200_000_u64),
+            (1_000_000, 1_200_000),
+            (1 << 41, (1 << 41) + 100_000),
+        ] {
+            let tuples: Vec<u64> = PrimeTuples::new(pattern, low, high).unwrap().collect();
+            let span: u64 = pattern[pattern.len() - 1];
+            let expected: Vec<u64> = (low..=high - span)
+                .filter(|num| {
+                    pattern
+                        .iter()
+                        .all(|offset| primality::check_if_prime_mr(num + offset))
+                })
+                .collect();
+            assert_eq!(tuples, expected);
+        }
+    }
+
+    let twins: Vec<u64> = PrimeTuples::new(&constellations::TWIN_PATTERN, 0, 50)
+        .unwrap()
+        .collect();
+    assert_eq!(twins, vec![3, 5, 11, 17, 29, 41]);
+    let quadruplets: Vec<u64> = PrimeTuples::new(&[0, 2, 6, 8], 0, 1_000).unwrap().collect();
+    assert_eq!(quadruplets, vec![5, 11, 101, 191, 821]);
+    // Members must all be in the range.
+    let twins: Vec<u64> = PrimeTuples::new(&constellations::TWIN_PATTERN, 5, 12)
+        .unwrap()
+        .collect();
+    assert_eq!(twins, vec![5]);
+    let near_max: Vec<u64> =
+        PrimeTuples::new(&constellations::TWIN_PATTERN, u64::MAX - 10, u64::MAX)
+            .unwrap()
+            .collect();
+    assert!(near_max.is_empty());
+    assert!(PrimeTuples::new(&[0, 2, 4], 0, 100).is_none());
+}
+
+#[test]
+pub fn test_count_prime_tuples() {
+    //! Tests the counting functions against known values.
+    assert_eq!(
+        constellations::count_prime_tuples(&constellations::TWIN_PATTERN, 1_000_000),
+        Some(8_169)
+    );
+    assert_eq!(
+        constellations::count_prime_tuples(&constellations::TWIN_PATTERN, 1_000),
+        Some(35)
+    );
+    assert_eq!(
+        constellations::count_prime_tuples(&constellations::TWIN_PATTERN, 4),
+        Some(0)
+    );
+    assert_eq!(
+        constellations::count_prime_tuples(&[0, 2, 6, 8], 1_000_000),
+        Some(166)
+    );
+    assert_eq!(constellations::count_prime_tuples(&[0, 1], 1_000), None);
+}
@@ -0,0 +1,83 @@
+//! Sub-module to test out the Sophie Germain primes, safe primes and Cunningham chains.
+use crate::libs::cunningham::{self, ChainKind, SafePrimes, SophieGermainPrimes};
+use crate::libs::primality;
+
+#[test]
+pub fn test_check_if_sophie_germain() {
+    //! Tests the check_if_sophie_germain and check_if_safe_prime functions.
+    assert_eq!(cunningham::check_if_sophie_germain(2), true);
+    assert_eq!(cunningham::check_if_sophie_germain(11), true);
+    assert_eq!(cunningham::check_if_sophie_germain(13), false);
+    assert_eq!(cunningham::check_if_sophie_germain(0), false);
+    assert_eq!(cunningham::check_if_safe_prime(5), true);
+    assert_eq!(cunningham::check_if_safe_prime(23), true);
+    assert_eq!(cunningham::check_if_safe_prime(3), false);
+    assert_eq!(cunningham::check_if_safe_prime(13), false);
+    assert_eq!(cunningham::check_if_safe_prime(2), false);
+    // `2 * num + 1` no longer fits in `u64` here.
+    for num in (u64::MAX - 1_000)..=u64::MAX {
+        let expected: bool = primality::check_if_prime_mr(num)
+            && primality::check_if_prime_u128(2 * num as u128 + 1);
+        assert_eq!(cunningham::check_if_sophie_germain(num), expected);
+    }
+}
+
+#[test]
+pub fn test_iterators() {
+    //! Tests the iterators against the single-value checks.
+    let sophie_germain: Vec<u64> = SophieGermainPrimes::new(0, 200).collect();
+    assert_eq!(
+        sophie_germain,
+        vec![2, 3, 5, 11, 23, 29, 41, 53, 83, 89, 113, 131, 173, 179, 191]
+    );
+    let safe: Vec<u64> = SafePrimes::new(0, 200).collect();
+    assert_eq!(safe, vec![5, 7, 11, 23, 47, 59, 83, 107, 167, 179]);
+
+    for (low, high) in [
+        (1_000_u64, 100_000_u64),
+        (1 << 40, (1 << 40) + 50_000),
+        (u64::MAX - 50_000, u64::MAX),
+    ] {
+        let expected: Vec<u64> = (low..=high)
+            .filter(|num| cunningham::check_if_sophie_germain(*num))
+            .collect();
+        assert_eq!(
+            SophieGermainPrimes::new(low, high).collect::<Vec<u64>>(),
+            expected
+        );
+        let expected: Vec<u64> = (low..=high)
+            .filter(|num| cunningham::check_if_safe_prime(*num))
+            .collect();
+        assert_eq!(SafePrimes::new(low, high).collect::<Vec<u64>>(), expected);
+    }
+    assert_eq!(SafePrimes::new(6, 6).next(), None);
+    assert_eq!(SafePrimes::new(0, 0).next(), None);
+}
+
+#[test]
+pub fn test_cunningham_chains() {
+    //! Tests the chain length and the chain finder.
+    assert_eq!(cunningham::find_chain_length(2, ChainKind::First), 5); // 2, 5, 11, 23, 47
+    assert_eq!(cunningham::find_chain_length(89, ChainKind::First), 6);
+    assert_eq!(cunningham::find_chain_length(2, ChainKind::Second), 3); // 2, 3, 5
+    assert_eq!(cunningham::find_chain_length(1_531, ChainKind::Second), 5);
+    assert_eq!(cunningham::find_chain_length(4, ChainKind::First), 0);
+
+    assert_eq!(
+        cunningham::find_cunningham_chains(0, 100, ChainKind::First, 3),
+        vec![(2, 5), (41, 3), (89, 6)]
+    );
+    assert_eq!(
+        cunningham::find_cunningham_chains(0, 2_000, ChainKind::Second, 4),
+        vec![(1_531, 5)]
+    );
+    // 5 continues the chain from 2, so it is not listed on its own.
+    assert_eq!(
+        cunningham::find_cunningham_chains(5, 5, ChainKind::First, 1),
+        vec![]
+    );
+    assert_eq!(
+        cunningham::find_cunningham_chains(3, 3, ChainKind::First, 1),
+        vec![(3, 2)]
+    );
+}
@@ -0,0 +1,100 @@
+//! Sub-module to test out the elliptic curve method.
+use crate::libs::ecm::{self, EcmParams};
+use crate::libs::factorization;
+use crate::libs::random::{self, SeededRng};
+
+#[test]
+pub fn test_ecm_params() {
+    //! Tests EcmParams::for_digits against the digit levels.
+    assert_eq!(
+        EcmParams::for_digits(10),
+        EcmParams::new(2_000, 200_000, 25, ecm::DEFAULT_SEED)
+    );
+    assert_eq!(
+        EcmParams::for_digits(20),
+        EcmParams::new(11_000, 1_100_000, 90, ecm::DEFAULT_SEED)
+    );
+    assert_eq!(EcmParams::for_digits(21).b1, 50_000);
+    assert_eq!(EcmParams::for_digits(100).b1, 250_000);
+}
+
+#[test]
+pub fn test_ecm_semiprimes() {
+    //! Tests that ECM splits products of two random primes of 40 to 60 bits, reproducibly for a given seed.
+    let mut rng: SeededRng = SeededRng::new(47);
+    for bits in [40_u32, 50, 60] {
+        let p: u64 = random::random_prime(bits, &mut rng);
+        let q: u64 = random::random_prime(bits + 4, &mut rng);
+        let num: u128 = p as u128 * q as u128;
+        let params: EcmParams = EcmParams::for_digits(20);
+        let factor: u128 = ecm::ecm(num, &params).unwrap();
+        assert!(factor == p as u128 || factor == q as u128);
+        assert_eq!(ecm::ecm(num, &params), Some(factor));
+    }
+}
+
+#[test]
+pub fn test_ecm_edge_cases() {
+    //! Tests ECM on even numbers and with bounds too small to find anything.
+    assert_eq!(ecm::ecm(2 * 1_000_003, &EcmParams::for_digits(15)), Some(2));
+    assert_eq!(ecm::ecm(2, &EcmParams::for_digits(15)), None);
+    let num: u128 = 1_152_921_504_606_846_883 * 1_152_921_504_606_846_869; // The two largest primes below 2^60.
+    assert_eq!(ecm::ecm(num, &EcmParams::new(5, 5, 2, 0)), None);
+    assert_eq!(ecm::ecm(num, &EcmParams::new(2_000, 200_000, 0, 0)), None);
+    let factor: Option<u128> = ecm::ecm(91, &EcmParams::for_digits(15));
+    assert!(factor.map_or(true, |factor| factor == 7 || factor == 13));
+}
+
+#[test]
+pub fn test_ecm_small_semiprimes() {
+    //! Tests ECM on products of two small primes, whose group orders are smooth modulo both at once, so that stage 1
+    //! has to retrace its last batch to separate them.
+    let mut rng: SeededRng = SeededRng::new(2_137);
+    for bits in [8_u32, 12, 16] {
+        for _ in 0..10 {
+            let p: u64 = random::random_prime(bits, &mut rng);
+            let q: u64 = random::random_prime(bits, &mut rng);
+            if p == q {
+                continue;
+            }
+            let factor: u128 = ecm::ecm(p as u128 * q as u128, &EcmParams::for_digits(15)).unwrap();
+            assert!(
+                factor == p as u128 || factor == q as u128,
+                "{p} * {q}: {factor}"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn test_factorize_large_semiprimes() {
+    //! Tests that the factorizer splits products of two primes too large for Pollard's rho alone.
+    let mut rng: SeededRng = SeededRng::new(2_024);
+    for bits in [56_u32, 62, 64] {
+        let p: u64 = random::random_prime(bits, &mut rng);
+        let q: u64 = random::random_prime(bits, &mut rng);
+        let mut expected: Vec<(u128, u32)> = vec![(p as u128, 1), (q as u128, 1)];
+        expected.sort();
+        if p == q {
+            expected = vec![(p as u128, 2)];
+        }
+        assert_eq!(
+            factorization::factorize_u128(p as u128 * q as u128),
+            expected
+        );
+    }
+    let (p, q): (u128, u128) = (1_152_921_504_606_846_869, 1_152_921_504_606_846_883);
+    assert_eq!(factorization::factorize_u128(p * q), vec![(p, 1), (q, 1)]);
+    let (num, factors) = random::random_composite_with_factors(3, 42, &mut rng);
+    let found: Vec<u128> = factorization::factorize_u128(num)
+        .iter()
+        .flat_map(|(prime, exponent)| core::iter::repeat(*prime).take(*exponent as usize))
+        .collect();
+    assert_eq!(
+        found,
+        factors
+            .iter()
+            .map(|factor| *factor as u128)
+            .collect::<Vec<u128>>()
+    );
+}
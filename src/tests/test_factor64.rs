@@ -0,0 +1,146 @@
+//! Sub-module to test out SQUFOF, Hart's one-line factorization and the factoring strategies.
+use crate::libs::factor64;
+use crate::libs::factorization::{self, FactorStrategy};
+use crate::libs::random::{self, SeededRng};
+
+const STRATEGIES: [FactorStrategy; 6] = [
+    FactorStrategy::Auto,
+    FactorStrategy::PollardRho,
+    FactorStrategy::Squfof,
+    FactorStrategy::HartOneLine,
+    FactorStrategy::Ecm,
+    FactorStrategy::Siqs,
+];
+
+#[test]
+pub fn test_squfof_small() {
+    //! Tests that every factor SQUFOF finds for the odd composites till 20,000 is a proper divisor.
+    let mut found: u32 = 0;
+    for num in (9_u64..20_000).step_by(2) {
+        if factorization::factorize_trial(num).len() == 1
+            && factorization::factorize_trial(num)[0].1 == 1
+        {
+            assert_eq!(factor64::squfof(num), None);
+            continue;
+        }
+        if let Some(factor) = factor64::squfof(num) {
+            assert!(
+                factor > 1 && factor < num && num % factor == 0,
+                "{num}: {factor}"
+            );
+            found = found + 1;
+        }
+    }
+    assert!(found > 7_000);
+}
+
+#[test]
+pub fn test_squfof_semiprimes() {
+    //! Tests SQUFOF on products of two random primes of 16 to 32 bits.
+    let mut rng: SeededRng = SeededRng::new(48);
+    for bits in [16_u32, 20, 24, 28, 31, 32] {
+        for _ in 0..20 {
+            let p: u64 = random::random_prime(bits, &mut rng);
+            let q: u64 = random::random_prime(bits, &mut rng);
+            let factor: u64 = factor64::squfof(p * q).unwrap();
+            assert!(factor == p || factor == q, "{p} * {q}: {factor}");
+        }
+    }
+}
+
+#[test]
+pub fn test_hart_one_line() {
+    //! Tests Hart's one-line factorization on small composites and on products of two random primes.
+    for num in (9_u64..5_000).step_by(2) {
+        if let Some(factor) = factor64::hart_one_line(num, 1_000) {
+            assert!(
+                factor > 1 && factor < num && num % factor == 0,
+                "{num}: {factor}"
+            );
+        }
+    }
+    assert_eq!(factor64::hart_one_line(1_000_003, 10_000), None);
+    assert_eq!(
+        factor64::hart_one_line(1_000_003 * 1_000_003, 1),
+        Some(1_000_003)
+    );
+    assert_eq!(factor64::hart_one_line(2 * 1_000_003, 1), Some(2));
+
+    let mut rng: SeededRng = SeededRng::new(480);
+    for bits in [12_u32, 16, 20] {
+        for _ in 0..20 {
+            let p: u64 = random::random_prime(bits, &mut rng);
+            let q: u64 = random::random_prime(bits + 2, &mut rng);
+            let factor: u64 = factor64::hart_one_line(p * q, 1_000_000).unwrap();
+            assert!(factor == p || factor == q, "{p} * {q}: {factor}");
+        }
+    }
+}
+
+#[test]
+pub fn test_factor64_edge_cases() {
+    //! Tests both methods on numbers below 4, even numbers and squares.
+    for num in 0..4 {
+        assert_eq!(factor64::squfof(num), None);
+        assert_eq!(factor64::hart_one_line(num, 100), None);
+    }
+    assert_eq!(factor64::squfof(1 << 40), Some(2));
+    assert_eq!(
+        factor64::squfof(4_294_967_291 * 4_294_967_291),
+        Some(4_294_967_291)
+    );
+}
+
+#[test]
+pub fn test_factorize_with() {
+    //! Tests that every strategy gives the same factorization as the default one.
+    let mut rng: SeededRng = SeededRng::new(2_048);
+    let mut numbers: Vec<u128> = vec![
+        0,
+        1,
+        2,
+        91,
+        1_001,
+        65_536,
+        999_999_000_001 * 3,
+        3_u128.pow(40),
+    ];
+    for bits in [20_u32, 32, 40, 48, 56, 62, 64] {
+        let p: u64 = random::random_prime(bits / 2, &mut rng);
+        let q: u64 = random::random_prime(bits - bits / 2, &mut rng);
+        numbers.push(p as u128 * q as u128);
+    }
+
+    for num in numbers {
+        let expected: Vec<(u128, u32)> = factorization::factorize_u128(num);
+        let product: u128 = expected
+            .iter()
+            .map(|(prime, exponent)| prime.pow(*exponent))
+            .product();
+        assert_eq!(product, if num == 0 { 1 } else { num });
+        for strategy in STRATEGIES {
+            assert_eq!(
+                factorization::factorize_with(num, strategy),
+                expected,
+                "{num} with {strategy:?}"
+            );
+        }
+    }
+}
+
+#[test]
+pub fn test_factorize_with_large() {
+    //! Tests that the ECM strategy splits a product of two 60-bit primes, which rho alone would take too long on.
+    let (p, q): (u128, u128) = (1_152_921_504_606_846_869, 1_152_921_504_606_846_883);
+    assert_eq!(
+        factorization::factorize_with(3 * p * q, FactorStrategy::Ecm),
+        vec![(3, 1), (p, 1), (q, 1)]
+    );
+}
+
+#[test]
+pub fn test_factorize_unbalanced() {
+    //! Tests a 106-bit number with a 40-bit factor, which the ECM curves before SIQS are there to find.
+    let (p, q): (u128, u128) = (1_099_511_615_401, 77_371_252_455_336_267_181_096_487);
+    assert_eq!(factorization::factorize_u128(p * q), vec![(p, 1), (q, 1)]);
+}
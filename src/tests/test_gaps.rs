@@ -0,0 +1,85 @@
+//! Sub-module to test out the prime gaps.
+use crate::libs::gaps::{self, GapStats, PrimeGaps};
+use crate::libs::{primality, primes};
+
+#[test]
+pub fn test_prime_gaps() {
+    //! Tests the streamed gaps against the differences of the plain sieve.
+    let primes: Vec<u64> = primes::find_primes_till(500_000)
+        .into_iter()
+        .filter(|prime| *prime >= 1_000)
+        .collect();
+    let expected: Vec<(u64, u64)> = primes
+        .windows(2)
+        .map(|pair| (pair[0], pair[1] - pair[0]))
+        .collect();
+    assert_eq!(
+        PrimeGaps::new(1_000, 500_000).collect::<Vec<(u64, u64)>>(),
+        expected
+    );
+
+    assert_eq!(
+        PrimeGaps::new(0, 10).collect::<Vec<(u64, u64)>>(),
+        vec![(2, 1), (3, 2), (5, 2)]
+    );
+    assert_eq!(PrimeGaps::new(24, 28).next(), None);
+    assert_eq!(PrimeGaps::new(23, 28).next(), None);
+}
+
+#[test]
+pub fn test_find_maximal_gaps_till() {
+    //! Tests the find_maximal_gaps_till function against OEIS A002386 and A005250.
+    let expected: Vec<(u64, u64)> = vec![
+        (2, 1),
+        (3, 2),
+        (7, 4),
+        (23, 6),
+        (89, 8),
+        (113, 14),
+        (523, 18),
+        (887, 20),
+        (1_129, 22),
+        (1_327, 34),
+        (9_551, 36),
+        (15_683, 44),
+        (19_609, 52),
+        (31_397, 72),
+        (155_921, 86),
+        (360_653, 96),
+        (370_261, 112),
+        (492_113, 114),
+    ];
+    assert_eq!(gaps::find_maximal_gaps_till(1_000_000), expected);
+    assert_eq!(gaps::find_maximal_gaps_till(1_000)[6], (523, 18));
+    assert_eq!(gaps::find_maximal_gaps_till(2), vec![]);
+}
+
+#[test]
+pub fn test_gap_stats() {
+    //! Tests the gap statistics near 10^12 against Miller-Rabin.
+    let low: u64 = 1_000_000_000_000;
+    let high: u64 = low + 100_000;
+    let primes: Vec<u64> = (low..=high)
+        .filter(|num| primality::check_if_prime_mr(*num))
+        .collect();
+
+    let stats: GapStats = GapStats::new(low, high);
+    assert_eq!(stats.count(), primes.len() as u64 - 1);
+    assert_eq!(stats.histogram().values().sum::<u64>(), stats.count());
+    assert_eq!(stats.histogram().keys().all(|gap| gap % 2 == 0), true);
+    let mean: f64 = (primes[primes.len() - 1] - primes[0]) as f64 / stats.count() as f64;
+    assert!((stats.mean() - mean).abs() < 1e-9);
+
+    let widest: (u64, u64) = primes
+        .windows(2)
+        .map(|pair| (pair[0], pair[1] - pair[0]))
+        .fold((0, 0), |best, gap| if gap.1 > best.1 { gap } else { best });
+    assert_eq!(stats.max_gap(), Some(widest));
+
+    let (prime, gap, merit) = stats.max_merit().unwrap();
+    assert_eq!(merit, gaps::merit(prime, gap));
+    assert!(merit >= gaps::merit(widest.0, widest.1));
+
+    assert_eq!(GapStats::new(24, 28), GapStats::default());
+    assert_eq!(GapStats::default().mean(), 0.0);
+}
@@ -0,0 +1,59 @@
+//! Sub-module to test out the Goldbach partitions.
+use crate::libs::{goldbach, primality};
+
+#[test]
+pub fn test_find_goldbach_partitions() {
+    //! Tests the find_goldbach_partitions and count_goldbach_partitions functions against Miller-Rabin.
+    assert_eq!(
+        goldbach::find_goldbach_partitions(100),
+        vec![(3, 97), (11, 89), (17, 83), (29, 71), (41, 59), (47, 53)]
+    );
+    assert_eq!(goldbach::find_goldbach_partitions(4), vec![(2, 2)]);
+    assert_eq!(goldbach::find_goldbach_partitions(2), vec![]);
+    assert_eq!(goldbach::find_goldbach_partitions(101), vec![]);
+
+    for num in [1_000_u64, 65_536, 1_000_000] {
+        let expected: u64 = (2..=num / 2)
+            .filter(|small| {
+                primality::check_if_prime_mr(*small) && primality::check_if_prime_mr(num - small)
+            })
+            .count() as u64;
+        assert_eq!(goldbach::count_goldbach_partitions(num), expected);
+        assert_eq!(
+            goldbach::find_goldbach_partitions(num).len() as u64,
+            expected
+        );
+    }
+    assert_eq!(goldbach::count_goldbach_partitions(3), 0);
+}
+
+#[test]
+pub fn test_verify_goldbach_range() {
+    //! Tests the verify_goldbach_range function against a direct search for the minimal prime.
+    for (low, high) in [(0_u64, 200_000_u64), (1_000_000_000_001, 1_000_000_100_000)] {
+        let minimal_primes: Vec<(u64, u64)> = goldbach::verify_goldbach_range(low, high).unwrap();
+        let first: u64 = core::cmp::max(low + low % 2, 4);
+        assert_eq!(minimal_primes.len() as u64, (high - first) / 2 + 1);
+        for (num, prime) in minimal_primes.iter().step_by(97) {
+            let expected: u64 = (2..)
+                .find(|small| {
+                    primality::check_if_prime_mr(*small)
+                        && primality::check_if_prime_mr(num - small)
+                })
+                .unwrap();
+            assert_eq!(*prime, expected);
+        }
+    }
+
+    assert_eq!(
+        goldbach::verify_goldbach_range(4, 12),
+        Ok(vec![(4, 2), (6, 3), (8, 3), (10, 3), (12, 5)])
+    );
+    assert_eq!(goldbach::verify_goldbach_range(0, 3), Ok(vec![]));
+    assert_eq!(
+        goldbach::verify_goldbach_range(u64::MAX - 10, u64::MAX)
+            .unwrap()
+            .len(),
+        5
+    );
+}
@@ -1,5 +1,7 @@
 //! Sub-module to test out the functions defined in the main library file.
 
+use crate::divisors;
+use crate::factorize;
 use crate::get_hcn;
 use crate::get_primes;
 use crate::is_hcn;
@@ -96,11 +98,6 @@ pub fn test_get_hcn_2() {
     assert_eq!(anti_primes, vec![1, 2, 4, 6, 12]);
 }
 
-// prithoo: This test passes, but takes 64 seconds or so to run. So, it is ignored.
-// if I let this run, pretty soon I will owe Github $1000 for exceeding the free tier.
-/// Uncomment the `#[ignore]` attribute to run this test __IN LOCAL TESTING ONLY__ !
-///
-#[ignore]
 #[test]
 pub fn test_get_hcn_3() {
     //! Tests the get_hcn function with a non-anti-prime number, 16'540.
@@ -114,3 +111,26 @@ pub fn test_get_hcn_3() {
         ]
     );
 }
+
+#[test]
+pub fn test_is_prime_generic() {
+    //! Tests the is_prime function with other integer widths.
+    assert_eq!(is_prime(13_u8), (true, vec![1, 13]));
+    assert_eq!(is_prime(1_u32), (false, vec![1]));
+    assert_eq!(is_prime(0_u128), (false, vec![]));
+    // 0.2 took a `u64` and reported `0` and `1` as prime.
+    assert_eq!(is_prime(0_u64), (false, vec![]));
+    assert_eq!(is_prime(1_u64), (false, vec![1]));
+}
+
+#[test]
+pub fn test_factorize() {
+    //! Tests the factorize function.
+    assert_eq!(factorize(360_u32), vec![(2, 3), (3, 2), (5, 1)]);
+}
+
+#[test]
+pub fn test_divisors() {
+    //! Tests the divisors function.
+    assert_eq!(divisors(28_usize), vec![1, 2, 4, 7, 14, 28]);
+}
@@ -0,0 +1,27 @@
+//! Sub-module to test out the Lucas-Lehmer test.
+use crate::libs::{mersenne, primality};
+
+const MERSENNE_EXPONENTS: [u32; 20] = [
+    2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127, 521, 607, 1279, 2203, 2281, 3217, 4253, 4423,
+];
+
+#[test]
+pub fn test_check_if_mersenne_prime() {
+    //! Tests the Lucas-Lehmer test against Miller-Rabin for every exponent that fits in `u64`.
+    for exponent in 0..64_u32 {
+        let expected: bool = exponent >= 2 && primality::check_if_prime_mr((1_u64 << exponent) - 1);
+        assert_eq!(mersenne::check_if_mersenne_prime(exponent), expected);
+    }
+    assert_eq!(mersenne::check_if_mersenne_prime(521), true);
+    assert_eq!(mersenne::check_if_mersenne_prime(523), false);
+}
+
+#[test]
+pub fn test_find_mersenne_exponents_till() {
+    //! Tests that the first 20 Mersenne primes are found.
+    assert_eq!(
+        mersenne::find_mersenne_exponents_till(4423),
+        MERSENNE_EXPONENTS.to_vec()
+    );
+    assert!(mersenne::find_mersenne_exponents_till(1).is_empty());
+}
@@ -0,0 +1,136 @@
+//! Sub-module to test out the modular arithmetic toolkit.
+use crate::libs::modular::{self, Montgomery128, Montgomery64};
+
+#[test]
+pub fn test_montgomery64() {
+    //! Tests Montgomery multiplication and exponentiation against the widening versions.
+    for modulus in [
+        3_u64,
+        1_000_000_007,
+        4_294_967_291,
+        (1 << 63) + 1,
+        u64::MAX - 58,
+        u64::MAX,
+    ] {
+        let context: Montgomery64 = Montgomery64::new(modulus).unwrap();
+        assert_eq!(context.modulus(), modulus);
+        for (a, b) in [
+            (0_u64, 5_u64),
+            (1, 1),
+            (2, modulus - 1),
+            (modulus - 1, modulus - 1),
+            (u64::MAX, 12_345_678_901),
+        ] {
+            let product: u64 = context.mul(context.to_montgomery(a), context.to_montgomery(b));
+            assert_eq!(
+                context.from_montgomery(product),
+                modular::mod_mul(a % modulus, b % modulus, modulus)
+            );
+            let power: u64 = context.pow(context.to_montgomery(a), b);
+            assert_eq!(
+                context.from_montgomery(power),
+                modular::mod_pow(a, b, modulus)
+            );
+        }
+        assert_eq!(context.from_montgomery(context.one()), 1 % modulus);
+    }
+    assert_eq!(Montgomery64::new(1), None);
+    assert_eq!(Montgomery64::new(10), None);
+}
+
+#[test]
+pub fn test_montgomery128() {
+    //! Tests 128-bit Montgomery multiplication and exponentiation against the double-and-add versions.
+    for modulus in [
+        3_u128,
+        u64::MAX as u128 - 58,
+        (1 << 64) + 1,
+        170_141_183_460_469_231_731_687_303_715_884_105_727, // 2^127 - 1
+        (1 << 127) + 1,
+        u128::MAX - 158,
+        u128::MAX,
+    ] {
+        let context: Montgomery128 = Montgomery128::new(modulus).unwrap();
+        assert_eq!(context.modulus(), modulus);
+        for (a, b) in [
+            (0_u128, 5_u128),
+            (1, 1),
+            (2, modulus - 1),
+            (modulus - 1, modulus - 1),
+            (u128::MAX, 123_456_789_012_345_678_901_234_567),
+        ] {
+            let product: u128 = context.mul(context.to_montgomery(a), context.to_montgomery(b));
+            assert_eq!(
+                context.from_montgomery(product),
+                modular::mod_mul_u128(a, b, modulus)
+            );
+            let power: u128 = context.pow(context.to_montgomery(a), b);
+            assert_eq!(
+                context.from_montgomery(power),
+                modular::mod_pow_u128(a, b, modulus)
+            );
+        }
+        assert_eq!(context.from_montgomery(context.one()), 1 % modulus);
+    }
+    assert_eq!(Montgomery128::new(1), None);
+    assert_eq!(Montgomery128::new(1 << 100), None);
+}
+
+#[test]
+pub fn test_mod_inverse() {
+    //! Tests the mod_inverse and mod_inverse_u128 functions.
+    assert_eq!(modular::mod_inverse(3, 7), Some(5));
+    assert_eq!(modular::mod_inverse(10, 17), Some(12));
+    assert_eq!(modular::mod_inverse(6, 9), None);
+    assert_eq!(modular::mod_inverse(0, 7), None);
+    assert_eq!(modular::mod_inverse(5, 1), Some(0));
+    assert_eq!(modular::mod_inverse(5, 0), None);
+    let prime: u64 = u64::MAX - 58; // The largest prime below 2^64.
+    for a in [2_u64, 3, 12_345, prime - 1, u64::MAX] {
+        let inverse: u64 = modular::mod_inverse(a, prime).unwrap();
+        assert_eq!(modular::mod_mul(a, inverse, prime), 1);
+    }
+    assert_eq!(modular::mod_inverse(3, u64::MAX), None); // 2^64 - 1 is divisible by 3.
+    let modulus: u128 = 170_141_183_460_469_231_731_687_303_715_884_105_727; // 2^127 - 1
+    let inverse: u128 = modular::mod_inverse_u128(u128::MAX - 5, modulus).unwrap();
+    assert_eq!(modular::mod_mul_u128(u128::MAX - 5, inverse, modulus), 1);
+}
+
+#[test]
+pub fn test_crt() {
+    //! Tests the Chinese Remainder Theorem solver.
+    assert_eq!(modular::crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(modular::crt(&[(1, 4), (3, 6)]), Some((9, 12))); // Moduli sharing a factor.
+    assert_eq!(modular::crt(&[(1, 4), (2, 6)]), None);
+    assert_eq!(modular::crt(&[(5, 3)]), Some((2, 3)));
+    assert_eq!(modular::crt(&[]), Some((0, 1)));
+    assert_eq!(modular::crt(&[(1, 0)]), None);
+    assert_eq!(modular::crt(&[(1, u128::MAX), (1, 2)]), None);
+
+    let moduli: [u128; 3] = [u64::MAX as u128, (1 << 31) - 1, 1_000_000_007];
+    let x: u128 = 123_456_789_012_345_678_901_234_567_890_123;
+    let congruences: Vec<(u128, u128)> = moduli
+        .iter()
+        .map(|modulus| (x % modulus, *modulus))
+        .collect();
+    let (solution, lcm) = modular::crt(&congruences).unwrap();
+    assert_eq!(lcm, moduli[0] * moduli[1] * moduli[2]);
+    assert_eq!(solution, x % lcm);
+}
+
+#[test]
+pub fn test_jacobi() {
+    //! Tests the jacobi and legendre functions against Euler's criterion.
+    let prime: u64 = 1_000_003;
+    for a in 0..500_u64 {
+        let expected: i8 = match modular::mod_pow(a, (prime - 1) / 2, prime) {
+            0 => 0,
+            1 => 1,
+            _ => -1,
+        };
+        assert_eq!(modular::legendre(a, prime), expected);
+    }
+    assert_eq!(modular::jacobi(2, 15), 1); // 2 is not a square modulo 15, but (2/3)(2/5) = 1.
+    assert_eq!(modular::jacobi(5, 15), 0);
+    assert_eq!(modular::jacobi(7, 15), -1);
+}
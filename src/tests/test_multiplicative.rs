@@ -0,0 +1,148 @@
+//! Sub-module to test out the multiplicative orders, primitive roots and discrete logarithms.
+use crate::libs::modular;
+use crate::libs::multiplicative::{self, DiscreteLogError};
+
+fn brute_force_order(a: u64, modulus: u64) -> Option<u64> {
+    // The first `k > 0` with `a^k = 1`, by repeated multiplication.
+    if modular::gcd_u128(a as u128, modulus as u128) != 1 {
+        return None;
+    }
+    let mut power: u64 = a % modulus;
+    let mut order: u64 = 1;
+    while power != 1 % modulus {
+        power = modular::mod_mul(power, a, modulus);
+        order += 1;
+    }
+    return Some(order);
+}
+
+#[test]
+pub fn test_multiplicative_order() {
+    //! Tests the multiplicative_order function against repeated multiplication.
+    for modulus in 1..150_u64 {
+        for a in 0..modulus + 3 {
+            assert_eq!(
+                multiplicative::multiplicative_order(a, modulus),
+                brute_force_order(a, modulus)
+            );
+        }
+    }
+    assert_eq!(multiplicative::multiplicative_order(5, 0), None);
+    assert_eq!(
+        multiplicative::multiplicative_order(2, 1_000_000_007),
+        Some(500_000_003)
+    );
+    assert_eq!(multiplicative::multiplicative_order(10, 49), Some(42));
+}
+
+#[test]
+pub fn test_primitive_root() {
+    //! Tests find_primitive_root against OEIS A046145 and check_if_primitive_root against the order.
+    let expected: [u64; 30] = [
+        0, 1, 2, 3, 2, 5, 3, 0, 2, 3, 2, 0, 2, 3, 0, 0, 3, 5, 2, 0, 0, 7, 5, 0, 2, 7, 2, 0, 2, 0,
+    ];
+    for (index, root) in expected.iter().enumerate() {
+        let modulus: u64 = index as u64 + 1;
+        let found: Option<u64> = multiplicative::find_primitive_root(modulus);
+        assert_eq!(
+            found,
+            if *root == 0 && modulus > 1 {
+                None
+            } else {
+                Some(*root)
+            }
+        );
+    }
+
+    for modulus in 1..300_u64 {
+        let phi: u64 = (0..modulus)
+            .filter(|a| modular::gcd_u128(*a as u128, modulus as u128) == 1)
+            .count() as u64;
+        for g in 0..modulus {
+            let expected: bool = brute_force_order(g, modulus) == Some(phi);
+            assert_eq!(
+                multiplicative::check_if_primitive_root(g, modulus),
+                expected
+            );
+        }
+    }
+
+    assert_eq!(multiplicative::find_primitive_root(1_000_000_007), Some(5));
+    assert_eq!(multiplicative::find_primitive_root(998_244_353), Some(3));
+    let prime: u64 = u64::MAX - 58;
+    let root: u64 = multiplicative::find_primitive_root(prime).unwrap();
+    assert_eq!(
+        multiplicative::multiplicative_order(root, prime),
+        Some(prime - 1)
+    );
+    assert_eq!(
+        multiplicative::find_primitive_root(2 * 3_u64.pow(20)).is_some(),
+        true
+    );
+    assert_eq!(multiplicative::find_primitive_root(0), None);
+}
+
+#[test]
+pub fn test_discrete_log() {
+    //! Tests discrete_log against brute force for small moduli, and round trips for large ones.
+    for modulus in 1..80_u64 {
+        for g in 0..modulus {
+            for h in 0..modulus {
+                let expected: Option<u64> = match brute_force_order(g, modulus) {
+                    Some(order) => {
+                        (0..order).find(|x| modular::mod_pow(g, *x, modulus) == h % modulus)
+                    }
+                    None => None,
+                };
+                assert_eq!(multiplicative::discrete_log(g, h, modulus), Ok(expected));
+            }
+        }
+    }
+
+    // Pohlig-Hellman: p - 1 = 2^23 * 7 * 17 and 2^64 - 2^32 = 2^32 * 3 * 5 * 17 * 257 * 65537.
+    for prime in [998_244_353_u64, 18_446_744_069_414_584_321] {
+        let g: u64 = multiplicative::find_primitive_root(prime).unwrap();
+        for x in [0_u64, 1, 12_345_678, prime - 2] {
+            let h: u64 = modular::mod_pow(g, x, prime);
+            assert_eq!(multiplicative::discrete_log(g, h, prime), Ok(Some(x)));
+        }
+    }
+
+    // A composite modulus, and a target outside the subgroup generated by `g`.
+    let modulus: u64 = 1_000_000_007 * 3;
+    let h: u64 = modular::mod_pow(5, 987_654, modulus);
+    let x: u64 = multiplicative::discrete_log(5, h, modulus)
+        .unwrap()
+        .unwrap();
+    assert_eq!(modular::mod_pow(5, x, modulus), h);
+    assert!(x <= 987_654);
+    assert_eq!(multiplicative::discrete_log(4, 5, 1_000_000_007), Ok(None)); // 4 only generates squares, and 5 is a primitive root.
+}
+
+#[test]
+pub fn test_discrete_log_large_prime_order() {
+    //! Tests safe primes `p = 2q + 1`, whose groups have a subgroup of prime order `q`: solved for `q` just below the
+    //! `2^42` limit, and `DiscreteLogError::TooLarge` for `q` just above it and near `2^63`, instead of an allocation
+    //! of tens of gigabytes. Also tells an unsolvable target apart from one that was not searched.
+    let q: u64 = 4_398_046_510_733; // 2^42 - 371
+    let prime: u64 = 2 * q + 1;
+    let g: u64 = multiplicative::find_primitive_root(prime).unwrap();
+    for x in [4_321_098_765_432_u64, prime - 2] {
+        let h: u64 = modular::mod_pow(g, x, prime);
+        assert_eq!(multiplicative::discrete_log(g, h, prime), Ok(Some(x)));
+    }
+    // `g^2` only generates the subgroup of order `q`, which `g` is not in.
+    let square: u64 = modular::mod_mul(g, g, prime);
+    assert_eq!(multiplicative::discrete_log(square, g, prime), Ok(None));
+
+    let q: u64 = 4_398_046_511_483; // 2^42 + 379
+    assert_eq!(
+        multiplicative::discrete_log(5, 7, 2 * q + 1),
+        Err(DiscreteLogError::TooLarge { prime: q })
+    );
+    let prime: u64 = 18_446_744_073_709_550_147;
+    assert!(matches!(
+        multiplicative::discrete_log(5, 123_456_789, prime),
+        Err(DiscreteLogError::TooLarge { .. })
+    ));
+}
@@ -0,0 +1,83 @@
+//! Sub-module to test out the classification by divisor sum.
+use crate::libs::arithmetic;
+use crate::libs::perfect;
+
+#[test]
+pub fn test_check_if_perfect() {
+    //! Tests the check_if_perfect function.
+    assert_eq!(perfect::check_if_perfect(0), false);
+    assert_eq!(perfect::check_if_perfect(6), true);
+    assert_eq!(perfect::check_if_perfect(8_128), true);
+    assert_eq!(perfect::check_if_perfect(8_127), false);
+}
+
+#[test]
+pub fn test_check_if_abundant() {
+    //! Tests the check_if_abundant and check_if_deficient functions.
+    assert_eq!(perfect::check_if_abundant(12), true);
+    assert_eq!(perfect::check_if_abundant(945), true); // Smallest odd abundant number.
+    assert_eq!(perfect::check_if_abundant(28), false);
+    assert_eq!(perfect::check_if_deficient(28), false);
+    assert_eq!(perfect::check_if_deficient(1), true);
+    assert_eq!(perfect::check_if_deficient(97), true);
+}
+
+#[test]
+pub fn test_abundancy() {
+    //! Tests the abundancy function.
+    assert_eq!(perfect::abundancy(0), (0, 1));
+    assert_eq!(perfect::abundancy(28), (2, 1));
+    assert_eq!(perfect::abundancy(12), (7, 3));
+    assert_eq!(perfect::abundancy(120), (3, 1)); // Multiply perfect.
+}
+
+#[test]
+pub fn test_find_perfect_till() {
+    //! Tests the find_perfect_till function with every even perfect number in u64.
+    assert!(perfect::find_perfect_till(5).is_empty());
+    assert_eq!(perfect::find_perfect_till(10_000), vec![6, 28, 496, 8_128]);
+    assert_eq!(
+        perfect::find_perfect_till(u64::MAX),
+        vec![
+            6,
+            28,
+            496,
+            8_128,
+            33_550_336,
+            8_589_869_056,
+            137_438_691_328,
+            2_305_843_008_139_952_128
+        ]
+    );
+}
+
+#[test]
+pub fn test_abundant_numbers() {
+    //! Tests the AbundantNumbers iterator against check_if_abundant, across several segments.
+    let abundant: Vec<u64> = perfect::AbundantNumbers::new(0, 200_000).collect();
+    let expected: Vec<u64> = (0..=200_000)
+        .filter(|num| perfect::check_if_abundant(*num))
+        .collect();
+    assert_eq!(abundant, expected);
+    assert_eq!(
+        perfect::AbundantNumbers::new(10, 40).collect::<Vec<u64>>(),
+        vec![12, 18, 20, 24, 30, 36, 40]
+    );
+    let low: u64 = 1_000_000_000_000;
+    assert_eq!(
+        perfect::AbundantNumbers::new(low, low + 100).collect::<Vec<u64>>(),
+        (low..=low + 100)
+            .filter(|num| perfect::check_if_abundant(*num))
+            .collect::<Vec<u64>>()
+    );
+}
+
+#[test]
+pub fn test_divisor_sum_segment() {
+    //! Tests the divisor_sum_segment function against divisor_sum.
+    let low: u64 = 1_000_000_000_000;
+    let sums = arithmetic::divisor_sum_segment(low, low + 100);
+    for (offset, sum) in sums.iter().enumerate() {
+        assert_eq!(*sum, arithmetic::divisor_sum(low + offset as u64));
+    }
+}
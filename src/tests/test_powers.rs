@@ -0,0 +1,138 @@
+//! Sub-module to test out the integer roots, perfect powers, and squarefree and powerful numbers.
+use crate::libs::factorization;
+use crate::libs::powers;
+
+#[test]
+pub fn test_integer_nth_root() {
+    //! Tests integer_nth_root against the defining inequality `r^k <= n < (r + 1)^k`.
+    for num in (0..5_000_u64).chain([
+        u64::MAX,
+        u64::MAX - 1,
+        1 << 63,
+        4_294_967_295 * 4_294_967_295,
+        4_294_967_296 * 4_294_967_295,
+    ]) {
+        for k in 1..=64_u32 {
+            let root: u128 = powers::integer_nth_root(num, k) as u128;
+            assert!(root.pow(k) <= num as u128);
+            assert!((root + 1)
+                .checked_pow(k)
+                .map_or(true, |power| power > num as u128));
+        }
+    }
+    assert_eq!(powers::integer_nth_root(u64::MAX, 2), (1 << 32) - 1);
+    assert_eq!(powers::integer_nth_root(1_000_000_000_000, 3), 10_000);
+    assert_eq!(powers::integer_nth_root(999_999_999_999, 3), 9_999);
+    assert_eq!(
+        powers::integer_nth_root_u128(u128::MAX, 2),
+        u64::MAX as u128
+    );
+    assert_eq!(
+        powers::integer_nth_root_u128(3_u128.pow(80), 5),
+        3_u128.pow(16)
+    );
+    assert_eq!(
+        powers::integer_nth_root_u128(3_u128.pow(80) - 1, 5),
+        3_u128.pow(16) - 1
+    );
+}
+
+#[test]
+#[should_panic]
+pub fn test_integer_nth_root_zero() {
+    //! Tests that integer_nth_root panics for the 0-th root.
+    powers::integer_nth_root(8, 0);
+}
+
+#[test]
+pub fn test_check_if_perfect_power() {
+    //! Tests check_if_perfect_power against the largest exponent from brute force.
+    for num in 0..20_000_u64 {
+        let expected: Option<(u64, u32)> = (2..=14_u32)
+            .rev()
+            .filter_map(|k| {
+                (2..=num)
+                    .find(|base| base.pow(k) >= num)
+                    .map(|base| (base, k))
+            })
+            .find(|(base, k)| base.pow(*k) == num);
+        assert_eq!(powers::check_if_perfect_power(num), expected);
+    }
+    assert_eq!(powers::check_if_perfect_power(1 << 63), Some((2, 63)));
+    assert_eq!(powers::check_if_perfect_power(6_u64.pow(24)), Some((6, 24)));
+    assert_eq!(
+        powers::check_if_perfect_power(4_294_967_291 * 4_294_967_291),
+        Some((4_294_967_291, 2))
+    );
+    assert_eq!(
+        powers::check_if_perfect_power(4_294_967_291 * 4_294_967_279),
+        None
+    );
+    assert_eq!(
+        powers::check_if_perfect_power_u128(1 << 127),
+        Some((2, 127))
+    );
+    assert_eq!(powers::check_if_perfect_power_u128(u128::MAX), None);
+}
+
+#[test]
+pub fn test_squarefree_radical_powerful() {
+    //! Tests check_if_squarefree, find_radical and check_if_powerful against the factorization.
+    for num in 1..10_000_u64 {
+        let factors: Vec<(u128, u32)> = factorization::factorize_u128(num as u128);
+        let radical: u64 = factors.iter().map(|(prime, _)| *prime as u64).product();
+        assert_eq!(powers::find_radical(num), radical);
+        assert_eq!(powers::check_if_squarefree(num), radical == num);
+        assert_eq!(
+            powers::check_if_powerful(num),
+            factors.iter().all(|(_, exponent)| *exponent >= 2)
+        );
+    }
+    assert_eq!(powers::find_radical(0), 0);
+    assert_eq!(powers::check_if_squarefree(0), false);
+    assert_eq!(powers::check_if_powerful(0), false);
+    assert_eq!(powers::find_radical(1 << 40), 2);
+    assert_eq!(powers::check_if_powerful(1_000_003 * 1_000_003 * 8), true);
+}
+
+#[test]
+pub fn test_squarefree_sieve() {
+    //! Tests find_squarefree_in_range against check_if_squarefree, and counts against OEIS A013928.
+    for (low, high) in [
+        (0_u64, 0_u64),
+        (0, 1),
+        (0, 2_000),
+        (1_000_000, 1_002_000),
+        (10, 5),
+    ] {
+        let expected: Vec<u64> = (low..=high)
+            .filter(|num| powers::check_if_squarefree(*num))
+            .collect();
+        assert_eq!(powers::find_squarefree_in_range(low, high), expected);
+    }
+    let high: u64 = u64::MAX;
+    let low: u64 = high - 1_000;
+    let expected: Vec<u64> = (low..=high)
+        .filter(|num| powers::check_if_squarefree(*num))
+        .collect();
+    assert_eq!(powers::find_squarefree_in_range(low, high), expected);
+    // There are 60_794 squarefree numbers below 10^5, and 607_926 below 10^6.
+    assert_eq!(powers::find_squarefree_in_range(1, 99_999).len(), 60_794);
+    assert_eq!(powers::find_squarefree_in_range(1, 999_999).len(), 607_926);
+}
+
+#[test]
+pub fn test_factorize_perfect_powers() {
+    //! Tests that the factorizer handles perfect powers of large primes.
+    let prime: u128 = 4_294_967_291;
+    assert_eq!(
+        factorization::factorize_u128(prime.pow(3)),
+        vec![(prime, 3)]
+    );
+    let semiprime: u128 = 1_000_003 * 999_983;
+    assert_eq!(
+        factorization::factorize_u128(semiprime.pow(3)),
+        vec![(999_983, 3), (1_000_003, 3)]
+    );
+    assert_eq!(factorization::factorize_u128(1 << 127), vec![(2, 127)]);
+}
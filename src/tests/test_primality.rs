@@ -0,0 +1,55 @@
+//! Sub-module to test out the allocation-free primality tests.
+use crate::libs::constants;
+use crate::libs::primality;
+
+#[test]
+pub fn test_check_if_prime_mr() {
+    //! Tests the check_if_prime_mr function against the known primes.
+    for num in 0..50 {
+        assert_eq!(
+            primality::check_if_prime_mr(num),
+            constants::KNOWN_PRIMES.contains(&num)
+        );
+    }
+}
+
+#[test]
+pub fn test_check_if_prime_mr_2() {
+    //! Tests the check_if_prime_mr function with large primes and strong pseudoprimes.
+    assert_eq!(
+        primality::check_if_prime_mr(18_446_744_073_709_551_557),
+        true
+    ); // Largest u64 prime.
+    assert_eq!(primality::check_if_prime_mr(1_000_000_007), true);
+    assert_eq!(primality::check_if_prime_mr(2_047), false); // Strong pseudoprime to base 2.
+    assert_eq!(primality::check_if_prime_mr(3_215_031_751), false); // Strong pseudoprime to bases 2, 3, 5, 7.
+    assert_eq!(primality::check_if_prime_mr(u64::MAX), false);
+}
+
+#[test]
+pub fn test_isqrt_u128() {
+    //! Tests isqrt_u128 around squares, on both sides of `2^64`.
+    for root in [
+        0_u128,
+        1,
+        2,
+        3,
+        1_000,
+        65_535,
+        65_536,
+        4_294_967_295,
+        4_294_967_296,
+        1 << 40,
+        u64::MAX as u128,
+    ] {
+        let square: u128 = root * root;
+        assert_eq!(primality::isqrt_u128(square), root);
+        if root > 0 {
+            assert_eq!(primality::isqrt_u128(square - 1), root - 1);
+        }
+        assert_eq!(primality::isqrt_u128(square + 2 * root), root);
+    }
+    assert_eq!(primality::isqrt_u128(u64::MAX as u128), 4_294_967_295);
+    assert_eq!(primality::isqrt_u128(u64::MAX as u128 + 1), 4_294_967_296);
+    assert_eq!(primality::isqrt_u128(u128::MAX), u64::MAX as u128);
+}
@@ -0,0 +1,82 @@
+//! Sub-module to test out the `PrimeInt` trait over every integer width.
+use crate::libs::prime_int::PrimeInt;
+use crate::libs::primes;
+
+#[test]
+pub fn test_is_prime_u8() {
+    //! Tests the u8 lookup table against the sieve.
+    let known_primes = primes::find_primes_till(u8::MAX as u64);
+    for num in 0..=u8::MAX {
+        assert_eq!(num.is_prime(), known_primes.contains(&(num as u64)));
+    }
+}
+
+#[test]
+pub fn test_is_prime_u16() {
+    //! Tests the u16 lookup table against the sieve.
+    let known_primes = primes::find_primes_till(u16::MAX as u64);
+    let n_primes = (0..=u16::MAX).filter(|num| num.is_prime()).count();
+    assert_eq!(n_primes, known_primes.len());
+    assert_eq!(65_521_u16.is_prime(), true); // Largest u16 prime.
+}
+
+#[test]
+pub fn test_is_prime_u32() {
+    //! Tests the u32 Miller-Rabin test with a prime and strong pseudoprimes.
+    assert_eq!(4_294_967_291_u32.is_prime(), true); // Largest u32 prime.
+    assert_eq!(3_215_031_751_u32.is_prime(), false); // Strong pseudoprime to bases 2, 3, 5, 7.
+    assert_eq!(2_047_u32.is_prime(), false); // Strong pseudoprime to base 2.
+}
+
+#[test]
+pub fn test_is_prime_u128() {
+    //! Tests the u128 Baillie-PSW test.
+    assert_eq!(((1_u128 << 127) - 1).is_prime(), true); // Mersenne prime M127.
+    assert_eq!(((1_u128 << 89) - 1).is_prime(), true); // Mersenne prime M89.
+    assert_eq!(((1_u128 << 61) - 1).is_prime(), true); // Mersenne prime M61.
+    assert_eq!(
+        (((1_u128 << 61) - 1) * ((1_u128 << 31) - 1)).is_prime(),
+        false
+    );
+    assert_eq!(u128::MAX.is_prime(), false);
+    assert_eq!(5_459_u128.is_prime(), false); // Strong Lucas pseudoprime.
+}
+
+#[test]
+pub fn test_factorize() {
+    //! Tests factorize over several widths.
+    assert_eq!(0_u8.factorize(), vec![]);
+    assert_eq!(1_u16.factorize(), vec![]);
+    assert_eq!(360_u32.factorize(), vec![(2, 3), (3, 2), (5, 1)]);
+    assert_eq!(
+        600_851_475_143_u64.factorize(),
+        vec![(71, 1), (839, 1), (1_471, 1), (6_857, 1)]
+    );
+    assert_eq!(
+        18_446_744_073_709_551_615_u64.factorize(),
+        vec![
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65_537, 1),
+            (6_700_417, 1)
+        ]
+    );
+    assert_eq!(
+        (4_294_967_291_u128 * 4_294_967_279 * 4_294_967_279).factorize(),
+        vec![(4_294_967_279, 2), (4_294_967_291, 1)]
+    );
+    assert_eq!(1_000_usize.factorize(), vec![(2, 3), (5, 3)]);
+}
+
+#[test]
+pub fn test_divisors() {
+    //! Tests divisors over several widths.
+    assert!(0_u64.divisors().is_empty());
+    assert_eq!(1_u64.divisors(), vec![1]);
+    assert_eq!(12_u8.divisors(), vec![1, 2, 3, 4, 6, 12]);
+    assert_eq!(97_u128.divisors(), vec![1, 97]);
+    assert_eq!(5_040_u16.divisors().len(), 60);
+}
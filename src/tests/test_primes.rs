@@ -1,5 +1,6 @@
 use crate::libs::constants;
 use crate::libs::primes;
+use crate::libs::tables;
 
 #[test]
 pub fn test_is_prime() {
@@ -73,6 +74,18 @@ pub fn test_check_if_anti_prime_2() {
     assert_eq!(factors, vec![1, 2, 3, 4, 6, 12]);
 }
 
+#[test]
+pub fn test_check_if_anti_prime_large() {
+    //! Tests the check_if_anti_prime function past the smallest-prime-factor table limit, with 10^11 and 10^11 + 3.
+    let (check, factors) = primes::check_if_anti_prime(100_000_000_000);
+    assert_eq!(check, false);
+    assert_eq!(factors.len(), 144);
+
+    let (check, factors) = primes::check_if_anti_prime(100_000_000_003);
+    assert_eq!(check, false);
+    assert_eq!(factors, vec![1, 100_000_000_003]);
+}
+
 #[test]
 pub fn test_find_anti_primes_till() {
     //! Tests the find_anti_primes_till function.
@@ -90,11 +103,6 @@ pub fn test_find_anti_primes_till_2() {
     assert_eq!(anti_primes, vec![1, 2, 4, 6, 12]);
 }
 
-// prithoo: This test passes, but takes 64 seconds or so to run. So, it is ignored.
-// if I let this run, pretty soon I will owe Github $1000 for exceeding the free tier.
-/// Uncomment the `#[ignore]` attribute to run this test __IN LOCAL TESTING ONLY__ !
-///
-#[ignore]
 #[test]
 pub fn test_find_anti_primes_till_3() {
     //! Tests the find_anti_primes_till function with a non-anti-prime number, 16'540.
@@ -108,3 +116,11 @@ pub fn test_find_anti_primes_till_3() {
         ]
     );
 }
+
+#[test]
+pub fn test_find_anti_primes_till_4() {
+    //! Tests the find_anti_primes_till function against the const-generated table of the first 30 anti-primes.
+    let table: [u64; 30] = tables::hcn_table::<30>();
+    let anti_primes = primes::find_anti_primes_till(table[29]);
+    assert_eq!(anti_primes, table);
+}
@@ -0,0 +1,116 @@
+//! Sub-module to test out the modular square roots.
+use crate::libs::quadratic::{self, QuadraticResidues};
+use crate::libs::{modular, primality};
+
+#[test]
+pub fn test_sqrt_mod_prime() {
+    //! Tests sqrt_mod_prime, Tonelli-Shanks and Cipolla on primes with small and large powers of two in `p - 1`.
+    let primes: [u64; 6] = [
+        3,
+        13,
+        1_000_000_007,
+        998_244_353,                // 119 * 2^23 + 1
+        18_446_744_069_414_584_321, // 2^64 - 2^32 + 1
+        u64::MAX - 58,
+    ];
+    for prime in primes {
+        for a in (0..2_000_u64).chain(prime.saturating_sub(50)..prime) {
+            let root: Option<u64> = quadratic::sqrt_mod_prime(a, prime);
+            let residue: bool = a % prime == 0 || modular::legendre(a, prime) == 1;
+            assert_eq!(root.is_some(), residue);
+            if let Some(root) = root {
+                assert_eq!(modular::mod_mul(root, root, prime), a % prime);
+                assert!(root <= prime - root || root == 0);
+            }
+            if a % prime != 0 && residue && prime > 2 {
+                let root: u64 = quadratic::tonelli_shanks(a, prime);
+                assert_eq!(modular::mod_mul(root, root, prime), a % prime);
+                let root: u64 = quadratic::cipolla(a % prime, prime);
+                assert_eq!(modular::mod_mul(root, root, prime), a % prime);
+            }
+        }
+    }
+    assert_eq!(quadratic::sqrt_mod_prime(2, 7), Some(3));
+    assert_eq!(quadratic::sqrt_mod_prime(3, 7), None);
+    assert_eq!(quadratic::sqrt_mod_prime(1, 2), Some(1));
+    assert_eq!(quadratic::sqrt_mod_prime(4, 15), None); // Not prime.
+}
+
+#[test]
+pub fn test_sqrt_mod_prime_power() {
+    //! Tests Hensel lifting against brute force for small prime powers.
+    for (prime, exponent) in [
+        (2_u64, 1_u32),
+        (2, 2),
+        (2, 3),
+        (2, 7),
+        (3, 4),
+        (5, 3),
+        (7, 3),
+        (11, 2),
+    ] {
+        let modulus: u64 = prime.pow(exponent);
+        for a in 0..modulus {
+            let expected: bool = (0..modulus).any(|x| x * x % modulus == a);
+            let root: Option<u64> = quadratic::sqrt_mod_prime_power(a, prime, exponent);
+            assert_eq!(root.is_some(), expected, "{} mod {}", a, modulus);
+            if let Some(root) = root {
+                assert_eq!(root * root % modulus, a);
+            }
+        }
+    }
+
+    let modulus: u64 = 1_000_000_007_u64.pow(2);
+    let root: u64 = quadratic::sqrt_mod_prime_power(2, 1_000_000_007, 2).unwrap();
+    assert_eq!(modular::mod_mul(root, root, modulus), 2);
+    let root: u64 = quadratic::sqrt_mod_prime_power(17, 2, 63).unwrap();
+    assert_eq!(modular::mod_mul(root, root, 1 << 63), 17);
+    assert_eq!(quadratic::sqrt_mod_prime_power(2, 2, 64), None); // 2^64 does not fit.
+    assert_eq!(quadratic::sqrt_mod_prime_power(4, 9, 2), None); // Not a prime power.
+}
+
+#[test]
+pub fn test_sqrt_mod() {
+    //! Tests composite moduli against brute force.
+    for modulus in [1_u64, 12, 15, 24, 45, 60, 77, 100, 360, 1_001] {
+        for a in 0..modulus {
+            let expected: bool = (0..modulus).any(|x| x * x % modulus == a);
+            let root: Option<u64> = quadratic::sqrt_mod(a, modulus);
+            assert_eq!(root.is_some(), expected);
+            assert_eq!(quadratic::check_if_quadratic_residue(a, modulus), expected);
+            if let Some(root) = root {
+                assert_eq!(root * root % modulus, a);
+            }
+        }
+    }
+
+    let modulus: u64 = 1_000_000_007 * 998_244_353;
+    let root: u64 = quadratic::sqrt_mod(123_456_789_u64 * 123_456_789 % modulus, modulus).unwrap();
+    assert_eq!(
+        modular::mod_mul(root, root, modulus),
+        123_456_789_u64 * 123_456_789 % modulus
+    );
+    assert_eq!(quadratic::sqrt_mod(5, 0), None);
+    assert_eq!(
+        quadratic::check_if_quadratic_residue(3, 1_000_000_007),
+        modular::legendre(3, 1_000_000_007) == 1
+    );
+}
+
+#[test]
+pub fn test_quadratic_residues() {
+    //! Tests the QuadraticResidues iterator.
+    assert_eq!(
+        QuadraticResidues::new(11).collect::<Vec<u64>>(),
+        vec![1, 3, 4, 5, 9]
+    );
+    assert_eq!(QuadraticResidues::new(2).collect::<Vec<u64>>(), vec![1]);
+    assert_eq!(QuadraticResidues::new(10).next(), None);
+    let prime: u64 = 10_007;
+    let residues: Vec<u64> = QuadraticResidues::new(prime).collect();
+    assert_eq!(residues.len() as u64, (prime - 1) / 2);
+    assert!(residues
+        .iter()
+        .all(|a| quadratic::sqrt_mod_prime(*a, prime).is_some()));
+    assert_eq!(primality::check_if_prime_mr(prime), true);
+}
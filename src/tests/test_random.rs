@@ -0,0 +1,100 @@
+//! Sub-module to test out the random primes.
+use crate::libs::cunningham;
+use crate::libs::primality;
+use crate::libs::random::{self, RandomSource, SeededRng};
+
+#[test]
+pub fn test_seeded_rng() {
+    //! Tests the generator against the reference xoshiro256** outputs, seeded with SplitMix64.
+    let mut rng: SeededRng = SeededRng::new(0);
+    assert_eq!(rng.next_u64(), 0x99EC_5F36_CB75_F2B4);
+    assert_eq!(rng.next_u64(), 0xBF6E_1F78_4956_452A);
+    assert_eq!(rng.next_u64(), 0x1A5F_849D_4933_E6E0);
+
+    let mut first: SeededRng = SeededRng::new(42);
+    let mut second: SeededRng = SeededRng::new(42);
+    for _ in 0..100 {
+        assert_eq!(first.next_u64(), second.next_u64());
+    }
+}
+
+#[test]
+pub fn test_gen_range() {
+    //! Tests that gen_range stays in range and hits every value of a small range about equally often.
+    let mut rng: SeededRng = SeededRng::new(7);
+    let mut counts: [u32; 6] = [0; 6];
+    for _ in 0..60_000 {
+        let value: u64 = rng.gen_range(10, 15);
+        counts[(value - 10) as usize] += 1;
+    }
+    assert!(counts.iter().all(|count| *count > 9_500 && *count < 10_500));
+
+    assert_eq!(rng.gen_range(5, 5), 5);
+    rng.gen_range(0, u64::MAX);
+}
+
+#[test]
+pub fn test_random_prime() {
+    //! Tests the random_prime, random_prime_in and random_safe_prime functions.
+    let mut rng: SeededRng = SeededRng::new(2024);
+    for bits in 2..=64_u32 {
+        let prime: u64 = random::random_prime(bits, &mut rng);
+        assert_eq!(primality::check_if_prime_mr(prime), true);
+        assert_eq!(64 - prime.leading_zeros(), bits);
+    }
+
+    for bits in 3..=64_u32 {
+        let prime: u64 = random::random_safe_prime(bits, &mut rng);
+        assert_eq!(cunningham::check_if_safe_prime(prime), true);
+        assert_eq!(64 - prime.leading_zeros(), bits);
+    }
+
+    let prime: u64 = random::random_prime_in(1_000_000, 2_000_000, &mut rng).unwrap();
+    assert!((1_000_000..=2_000_000).contains(&prime) && primality::check_if_prime_mr(prime));
+    assert_eq!(random::random_prime_in(24, 28, &mut rng), None);
+    assert_eq!(random::random_prime_in(24, 29, &mut rng), Some(29));
+    assert_eq!(random::random_prime_in(30, 20, &mut rng), None);
+
+    // Every prime of a small range is picked.
+    let mut seen: Vec<u64> = (0..200)
+        .map(|_| random::random_prime_in(100, 130, &mut rng).unwrap())
+        .collect();
+    seen.sort();
+    seen.dedup();
+    assert_eq!(seen, vec![101, 103, 107, 109, 113, 127]);
+
+    // The same seed gives the same fixture.
+    assert_eq!(
+        random::random_prime(48, &mut SeededRng::new(1)),
+        random::random_prime(48, &mut SeededRng::new(1))
+    );
+}
+
+#[test]
+pub fn test_random_composite_with_factors() {
+    //! Tests the random_composite_with_factors function.
+    let mut rng: SeededRng = SeededRng::new(99);
+    let (semiprime, factors) = random::random_composite_with_factors(2, 64, &mut rng);
+    assert_eq!(factors.len(), 2);
+    assert_eq!(semiprime, factors[0] as u128 * factors[1] as u128);
+    assert!(factors
+        .iter()
+        .all(|factor| primality::check_if_prime_mr(*factor) && *factor >= 1 << 63));
+
+    let (product, factors) = random::random_composite_with_factors(5, 20, &mut rng);
+    assert_eq!(
+        product,
+        factors
+            .iter()
+            .map(|factor| *factor as u128)
+            .product::<u128>()
+    );
+    assert!(factors.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+#[should_panic]
+pub fn test_random_prime_invalid_bits() {
+    //! Tests that a 1-bit prime is refused.
+    random::random_prime(1, &mut SeededRng::new(0));
+}
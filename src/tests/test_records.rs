@@ -0,0 +1,135 @@
+//! Sub-module to test out the record-setting numbers.
+use crate::libs::arithmetic;
+use crate::libs::constants;
+use crate::libs::records;
+
+// First terms of OEIS A002201 (superior highly composite).
+const SHCN: [u64; 15] = [
+    2,
+    6,
+    12,
+    60,
+    120,
+    360,
+    2_520,
+    5_040,
+    55_440,
+    720_720,
+    1_441_440,
+    4_324_320,
+    21_621_600,
+    367_567_200,
+    6_983_776_800,
+];
+// First terms of OEIS A004490 (colossally abundant).
+const CA: [u64; 15] = [
+    2,
+    6,
+    12,
+    60,
+    120,
+    360,
+    2_520,
+    5_040,
+    55_440,
+    720_720,
+    1_441_440,
+    4_324_320,
+    21_621_600,
+    367_567_200,
+    6_983_776_800,
+];
+
+#[test]
+pub fn test_find_signature_numbers_till() {
+    //! Tests the find_signature_numbers_till function (OEIS A025487).
+    assert_eq!(
+        records::find_signature_numbers_till(50),
+        vec![1, 2, 4, 6, 8, 12, 16, 24, 30, 32, 36, 48]
+    );
+}
+
+#[test]
+pub fn test_find_hcn_by_signature_till() {
+    //! Tests the find_hcn_by_signature_till function against the known anti-primes.
+    assert_eq!(
+        records::find_hcn_by_signature_till(10_080),
+        constants::KNOWN_ANTIPRIMES
+    );
+    assert_eq!(
+        records::find_hcn_by_signature_till(u64::MAX).last(),
+        Some(&18_401_055_938_125_660_800)
+    );
+}
+
+#[test]
+pub fn test_largely_composite() {
+    //! Tests the largely composite numbers against a direct scan.
+    let counts = arithmetic::divisor_count_sieve(10_000);
+    let mut expected: Vec<u64> = Vec::new();
+    let mut record: u64 = 0;
+    for num in 1..=10_000_u64 {
+        if counts[num as usize] >= record {
+            record = counts[num as usize];
+            expected.push(num);
+        }
+    }
+    assert_eq!(records::find_largely_composite_till(10_000), expected);
+    for num in 1..=1_000_u64 {
+        assert_eq!(
+            records::check_if_largely_composite(num),
+            expected.contains(&num)
+        );
+    }
+}
+
+#[test]
+pub fn test_highly_abundant() {
+    //! Tests the highly abundant numbers (OEIS A002093).
+    let expected: Vec<u64> = vec![
+        1, 2, 3, 4, 6, 8, 10, 12, 16, 18, 20, 24, 30, 36, 42, 48, 60, 72, 84, 90, 96, 108, 120,
+    ];
+    assert_eq!(records::find_highly_abundant_till(120), expected);
+    for num in 1..=120_u64 {
+        assert_eq!(
+            records::check_if_highly_abundant(num),
+            expected.contains(&num)
+        );
+    }
+
+    let sums = arithmetic::divisor_sum_sieve(100_000);
+    let mut expected: Vec<u64> = Vec::new();
+    let mut record: u128 = 0;
+    for num in 1..=100_000_u64 {
+        if sums[num as usize] > record {
+            record = sums[num as usize];
+            expected.push(num);
+        }
+    }
+    assert_eq!(records::find_highly_abundant_till(100_000), expected);
+}
+
+#[test]
+pub fn test_superior_highly_composite() {
+    //! Tests the superior highly composite numbers (OEIS A002201).
+    assert_eq!(records::find_superior_highly_composite_till(SHCN[14]), SHCN);
+    for hcn in records::find_hcn_by_signature_till(10_000_000_000) {
+        assert_eq!(
+            records::check_if_superior_highly_composite(hcn),
+            SHCN.contains(&hcn)
+        );
+    }
+    assert_eq!(records::check_if_superior_highly_composite(1), false);
+}
+
+#[test]
+pub fn test_colossally_abundant() {
+    //! Tests the colossally abundant numbers (OEIS A004490).
+    assert_eq!(records::find_colossally_abundant_till(CA[14]), CA);
+    for candidate in records::find_signature_numbers_till(10_000_000_000) {
+        assert_eq!(
+            records::check_if_colossally_abundant(candidate),
+            CA.contains(&candidate)
+        );
+    }
+}
@@ -0,0 +1,36 @@
+//! Sub-module to test out the segmented sieve.
+use crate::libs::{primality, primes, sieve};
+
+#[test]
+pub fn test_primes_in_range() {
+    //! Tests the segmented sieve against the plain sieve, across several segments.
+    let primes: Vec<u64> = sieve::PrimesInRange::new(0, 300_000).collect();
+    assert_eq!(primes, primes::find_primes_till(300_000));
+
+    let primes: Vec<u64> = sieve::PrimesInRange::new(100_000, 300_000).collect();
+    let expected: Vec<u64> = primes::find_primes_till(300_000)
+        .into_iter()
+        .filter(|prime| *prime >= 100_000)
+        .collect();
+    assert_eq!(primes, expected);
+
+    assert_eq!(sieve::PrimesInRange::new(14, 16).next(), None);
+    assert_eq!(sieve::PrimesInRange::new(5, 2).next(), None);
+    assert_eq!(
+        sieve::PrimesInRange::new(2, 2).collect::<Vec<u64>>(),
+        vec![2]
+    );
+}
+
+#[test]
+pub fn test_primes_in_range_large() {
+    //! Tests ranges above the square of the sieving limit, where Miller-Rabin takes over, against Miller-Rabin.
+    for low in [1_u64 << 41, u64::MAX - 100_000] {
+        let high: u64 = low.saturating_add(100_000);
+        let primes: Vec<u64> = sieve::PrimesInRange::new(low, high).collect();
+        let expected: Vec<u64> = (low..=high)
+            .filter(|num| primality::check_if_prime_mr(*num))
+            .collect();
+        assert_eq!(primes, expected);
+    }
+}
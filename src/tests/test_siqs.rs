@@ -0,0 +1,60 @@
+//! Sub-module to test out the self-initializing quadratic sieve.
+use crate::libs::factorization::{self, FactorStrategy};
+use crate::libs::random::{self, SeededRng};
+use crate::libs::siqs;
+
+#[test]
+pub fn test_siqs_semiprimes() {
+    //! Tests that SIQS splits products of two random primes of the same size, from 44 to 128 bits.
+    let mut rng: SeededRng = SeededRng::new(49);
+    for bits in [44_u32, 52, 60, 64, 72, 80, 88, 96, 104, 112, 124, 128] {
+        let p: u64 = random::random_prime(bits / 2, &mut rng);
+        let q: u64 = random::random_prime(bits - bits / 2, &mut rng);
+        if p == q {
+            continue;
+        }
+        let factor: u128 = siqs::siqs(p as u128 * q as u128).unwrap();
+        assert!(
+            factor == p as u128 || factor == q as u128,
+            "{p} * {q}: {factor}"
+        );
+    }
+}
+
+#[test]
+pub fn test_siqs_edge_cases() {
+    //! Tests SIQS below its minimum, on even numbers, and with a factor small enough to be in the factor base.
+    assert_eq!(siqs::siqs(0), None);
+    assert_eq!(siqs::siqs(1_000_003 * 999_983), None);
+    assert_eq!(siqs::siqs(1 << 100), Some(2));
+    let prime: u128 = 1_152_921_504_606_846_883;
+    assert_eq!(siqs::siqs(53 * prime), Some(53));
+
+    // Three prime factors: any split will do.
+    let num: u128 = 4_294_967_291 * 4_294_967_279 * 1_000_003;
+    let factor: u128 = siqs::siqs(num).unwrap();
+    assert!(factor > 1 && factor < num && num % factor == 0);
+}
+
+#[test]
+pub fn test_factorize_balanced_u128() {
+    //! Tests the factorizer on products of two primes of the same size, which only SIQS splits quickly.
+    let mut rng: SeededRng = SeededRng::new(4_949);
+    for bits in [100_u32, 120] {
+        let p: u64 = random::random_prime(bits / 2, &mut rng);
+        let q: u64 = random::random_prime(bits / 2, &mut rng);
+        let mut expected: Vec<(u128, u32)> = vec![(p as u128, 1), (q as u128, 1)];
+        expected.sort();
+        if p == q {
+            expected = vec![(p as u128, 2)];
+        }
+        assert_eq!(
+            factorization::factorize_u128(p as u128 * q as u128),
+            expected
+        );
+        assert_eq!(
+            factorization::factorize_with(p as u128 * q as u128, FactorStrategy::Siqs),
+            expected
+        );
+    }
+}
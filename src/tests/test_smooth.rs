@@ -0,0 +1,123 @@
+//! Sub-module to test out the smooth-number functions.
+use crate::libs::smooth::{self, SmoothNumbers};
+
+fn brute_force_largest_factor(num: u64) -> u64 {
+    // The largest prime factor by trial division, with `1` for `num = 1`.
+    let mut rest: u64 = num;
+    let mut largest: u64 = 1;
+    let mut divisor: u64 = 2;
+    while divisor * divisor <= rest {
+        while rest % divisor == 0 {
+            largest = divisor;
+            rest = rest / divisor;
+        }
+        divisor = divisor + 1;
+    }
+    if rest > 1 {
+        largest = rest;
+    }
+    return largest;
+}
+
+#[test]
+pub fn test_prime_factor_extremes() {
+    //! Tests find_smallest_prime_factor and find_largest_prime_factor against trial division.
+    for num in 2..3_000_u64 {
+        let smallest: u64 = (2..=num).find(|d| num % d == 0).unwrap();
+        assert_eq!(smooth::find_smallest_prime_factor(num), Some(smallest));
+        assert_eq!(
+            smooth::find_largest_prime_factor(num),
+            Some(brute_force_largest_factor(num))
+        );
+    }
+    for num in [0_u64, 1] {
+        assert_eq!(smooth::find_smallest_prime_factor(num), None);
+        assert_eq!(smooth::find_largest_prime_factor(num), None);
+    }
+    let semiprime: u64 = 4_294_967_291 * 4_294_967_279;
+    assert_eq!(
+        smooth::find_smallest_prime_factor(semiprime),
+        Some(4_294_967_279)
+    );
+    assert_eq!(
+        smooth::find_largest_prime_factor(semiprime),
+        Some(4_294_967_291)
+    );
+    assert_eq!(
+        smooth::find_largest_prime_factor(u64::MAX - 58),
+        Some(u64::MAX - 58)
+    );
+}
+
+#[test]
+pub fn test_check_if_b_smooth() {
+    //! Tests check_if_b_smooth against the largest prime factor.
+    for num in 1..2_000_u64 {
+        for bound in [0_u64, 1, 2, 3, 5, 7, 46, 47, 53, 100, 1_000] {
+            let expected: bool = num == 1 || brute_force_largest_factor(num) <= bound;
+            assert_eq!(smooth::check_if_b_smooth(num, bound), expected);
+        }
+    }
+    assert_eq!(smooth::check_if_b_smooth(0, 100), false);
+    assert_eq!(smooth::check_if_b_smooth(1 << 63, 2), true);
+    assert_eq!(
+        smooth::check_if_b_smooth(1_000_003 * 999_983, 1_000_000),
+        false
+    );
+    assert_eq!(
+        smooth::check_if_b_smooth(1_000_003 * 999_983, 1_000_003),
+        true
+    );
+}
+
+#[test]
+pub fn test_smooth_numbers() {
+    //! Tests the SmoothNumbers iterator against filtering with check_if_b_smooth.
+    for bound in [0_u64, 1, 2, 3, 7, 30, 97] {
+        for limit in [0_u64, 1, 2, 50, 5_000] {
+            let expected: Vec<u64> = (1..=limit)
+                .filter(|num| *num == 1 || brute_force_largest_factor(*num) <= bound)
+                .collect();
+            assert_eq!(
+                SmoothNumbers::new(bound, limit).collect::<Vec<u64>>(),
+                expected
+            );
+        }
+    }
+    // Hamming numbers till `u64::MAX`, which must not overflow.
+    let hamming: Vec<u64> = SmoothNumbers::new(5, u64::MAX).collect();
+    assert_eq!(hamming.len(), 13_282);
+    assert!(hamming.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(&hamming[..10], &[1, 2, 3, 4, 5, 6, 8, 9, 10, 12]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+pub fn test_dickman_rho() {
+    //! Tests dickman_rho against its closed form on `[1, 2]` and known values, and the Psi(x, y) estimate against
+    //! an exact count.
+    for u in [0.0_f64, 0.5, 1.0] {
+        assert_eq!(smooth::dickman_rho(u), 1.0);
+    }
+    for u in [1.25_f64, 1.5, 2.0] {
+        let expected: f64 = 1.0 - u.ln();
+        assert!((smooth::dickman_rho(u) - expected).abs() < 1e-7);
+    }
+    for (u, expected) in [
+        (3.0_f64, 0.048_608_388_291_1),
+        (4.0, 0.004_910_925_648),
+        (5.0, 0.000_354_724_700_2),
+    ] {
+        assert!((smooth::dickman_rho(u) / expected - 1.0).abs() < 1e-5);
+    }
+
+    let limit: u64 = 1_000_000;
+    for bound in [100_u64, 1_000, 10_000] {
+        let exact: f64 = SmoothNumbers::new(bound, limit).count() as f64;
+        let estimate: f64 = smooth::estimate_smooth_count(limit, bound);
+        assert!(estimate / exact > 0.5 && estimate / exact < 2.0);
+    }
+    assert_eq!(smooth::estimate_smooth_count(0, 10), 0.0);
+    assert_eq!(smooth::estimate_smooth_count(100, 1), 1.0);
+    assert_eq!(smooth::estimate_smooth_count(100, 1_000), 100.0);
+}
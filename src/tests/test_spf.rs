@@ -0,0 +1,44 @@
+//! Sub-module to test out the smallest-prime-factor table.
+use crate::libs::factorization;
+use crate::libs::primes;
+use crate::libs::spf::SpfTable;
+use crate::libs::tables;
+
+#[test]
+pub fn test_spf_table_factorize() {
+    //! Tests SpfTable::factorize and count_divisors against trial division.
+    let limit: u64 = 100_001;
+    let table: SpfTable = SpfTable::new(limit);
+    assert_eq!(table.limit(), limit);
+    for num in 1..=limit {
+        assert_eq!(table.factorize(num), factorization::factorize_trial(num));
+        assert_eq!(table.count_divisors(num), tables::count_factors_const(num));
+    }
+    assert_eq!(table.factorize(0), vec![]);
+    assert_eq!(table.factorize(65_536), vec![(2, 16)]);
+    assert_eq!(table.factorize(99_991), vec![(99_991, 1)]);
+}
+
+#[test]
+pub fn test_spf_table_primes() {
+    //! Tests SpfTable::is_prime and smallest_prime_factor against the sieve.
+    for limit in [0_u64, 1, 2, 3, 4, 9, 10_000] {
+        let table: SpfTable = SpfTable::new(limit);
+        let primes: Vec<u64> = (0..=limit).filter(|num| table.is_prime(*num)).collect();
+        assert_eq!(primes, primes::find_primes_till(limit));
+        for num in 2..=limit {
+            let smallest: u64 = (2..=num).find(|d| num % d == 0).unwrap();
+            assert_eq!(table.smallest_prime_factor(num), Some(smallest));
+        }
+        assert_eq!(table.smallest_prime_factor(0), None);
+        assert_eq!(table.smallest_prime_factor(1), None);
+        assert_eq!(table.smallest_prime_factor(limit + 1), None);
+    }
+}
+
+#[test]
+#[should_panic]
+pub fn test_spf_table_past_limit() {
+    //! Tests that SpfTable::factorize panics for numbers past the limit.
+    SpfTable::new(100).factorize(101);
+}
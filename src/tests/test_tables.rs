@@ -0,0 +1,45 @@
+//! Sub-module to test out the compile-time table generators.
+use crate::libs::constants;
+use crate::libs::tables;
+
+const FIRST_PRIMES: [u64; 25] = tables::prime_table::<25>();
+const FIRST_ANTIPRIMES: [u64; 10] = tables::hcn_table::<10>();
+
+#[test]
+pub fn test_is_prime_const() {
+    //! Tests the is_prime_const function against the known primes.
+    for num in 0..50 {
+        assert_eq!(
+            tables::is_prime_const(num),
+            constants::KNOWN_PRIMES.contains(&num)
+        );
+    }
+}
+
+#[test]
+pub fn test_prime_table() {
+    //! Tests the prime_table function; the 25th prime is 97.
+    assert_eq!(FIRST_PRIMES[..15], constants::KNOWN_PRIMES);
+    assert_eq!(FIRST_PRIMES[24], 97);
+}
+
+#[test]
+pub fn test_hcn_table() {
+    //! Tests the hcn_table function against the known anti-primes.
+    assert_eq!(FIRST_ANTIPRIMES, constants::KNOWN_ANTIPRIMES[..10]);
+    assert_eq!(
+        constants::KNOWN_ANTIPRIMES,
+        [
+            1, 2, 4, 6, 12, 24, 36, 48, 60, 120, 180, 240, 360, 720, 840, 1_260, 1_680, 2_520,
+            5_040, 7_560, 10_080
+        ]
+    );
+}
+
+#[test]
+pub fn test_count_factors_const() {
+    //! Tests the count_factors_const function.
+    assert_eq!(tables::count_factors_const(1), 1);
+    assert_eq!(tables::count_factors_const(97), 2);
+    assert_eq!(tables::count_factors_const(10_080), 72);
+}
@@ -0,0 +1,53 @@
+//! Sub-module to test out the JavaScript bindings.
+//!
+//! The `*_vec` helpers are tested natively; the typed-array wrappers are only tested on `wasm32`.
+use crate::libs::wasm;
+
+#[test]
+pub fn test_factors_vec() {
+    //! Tests the factors_vec function with a composite number, 10.
+    assert_eq!(wasm::factors_vec(10), vec![1, 2, 5, 10]);
+}
+
+#[test]
+pub fn test_factors_u32_vec() {
+    //! Tests the factors_u32_vec function with a composite number, 12.
+    assert_eq!(wasm::factors_u32_vec(12), vec![1, 2, 3, 4, 6, 12]);
+}
+
+#[test]
+pub fn test_primes_till_u32_vec() {
+    //! Tests the primes_till_u32_vec function.
+    assert_eq!(wasm::primes_till_u32_vec(12), vec![2, 3, 5, 7, 11]);
+}
+
+#[cfg(target_arch = "wasm32")]
+mod wasm32 {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::libs::wasm;
+
+    /// Tests the factors binding with a composite number, 10.
+    #[wasm_bindgen_test]
+    pub fn test_factors() {
+        assert_eq!(wasm::factors(10).to_vec(), vec![1, 2, 5, 10]);
+    }
+
+    /// Tests the factorsU32 binding with a composite number, 12.
+    #[wasm_bindgen_test]
+    pub fn test_factors_u32() {
+        assert_eq!(wasm::factors_u32(12).to_vec(), vec![1, 2, 3, 4, 6, 12]);
+    }
+
+    /// Tests the getPrimes binding.
+    #[wasm_bindgen_test]
+    pub fn test_get_primes() {
+        assert_eq!(wasm::get_primes(12).to_vec(), vec![2, 3, 5, 7, 11]);
+    }
+
+    /// Tests the getHcn binding, which scans with a smallest-prime-factor table.
+    #[wasm_bindgen_test]
+    pub fn test_get_hcn() {
+        assert_eq!(wasm::get_hcn(16).to_vec(), vec![1, 2, 4, 6, 12]);
+    }
+}